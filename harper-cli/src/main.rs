@@ -8,7 +8,7 @@ use clap::Parser;
 use harper_comments::CommentParser;
 use harper_core::linting::{LintGroup, LintGroupConfig, Linter};
 use harper_core::parsers::Markdown;
-use harper_core::{remove_overlaps, Dictionary, Document, FstDictionary};
+use harper_core::{Dictionary, Document, FstDictionary};
 
 #[derive(Debug, Parser)]
 enum Args {
@@ -44,6 +44,7 @@ fn main() -> anyhow::Result<()> {
 
             let mut linter = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated());
             let mut lints = linter.lint(&doc);
+            doc.normalizations().restore_spans(&mut lints);
 
             if count {
                 println!("{}", lints.len());
@@ -55,8 +56,6 @@ fn main() -> anyhow::Result<()> {
                 return Ok(());
             }
 
-            remove_overlaps(&mut lints);
-
             let primary_color = Color::Magenta;
 
             let filename = file
@@ -103,9 +102,11 @@ fn main() -> anyhow::Result<()> {
                 Report::build(ReportKind::Custom("Spans", primary_color), &filename, 0);
             let mut color = primary_color;
             for token in doc.tokens() {
+                let span = doc.normalizations().original_span(token.span);
+
                 report_builder = report_builder.with_label(
-                    Label::new((&filename, token.span.into()))
-                        .with_message(format!("[{}, {})", token.span.start, token.span.end))
+                    Label::new((&filename, span.into()))
+                        .with_message(format!("[{}, {})", span.start, span.end))
                         .with_color(color),
                 );
 
@@ -1,9 +1,47 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use harper_core::linting::{LintGroup, LintGroupConfig, Linter};
-use harper_core::{Document, FstDictionary};
+use harper_core::linting::{LintGroup, LintGroupConfig, Linter, SpellCheck};
+use harper_core::{Dictionary, Document, FstDictionary, FullDictionary};
 
 static DEMO: &str = include_str!("../../demo.md");
 
+/// Counts allocations, so benchmarks can report how many a call makes.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The average number of allocations made by each of `runs` calls to `f`.
+fn allocations_per_call(runs: usize, mut f: impl FnMut()) -> f64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+
+    for _ in 0..runs {
+        f();
+    }
+
+    (ALLOCATIONS.load(Ordering::Relaxed) - before) as f64 / runs as f64
+}
+
 fn parse_demo(c: &mut Criterion) {
     c.bench_function("parse_demo", |b| {
         b.iter(|| Document::new_markdown_curated(black_box(DEMO)))
@@ -31,10 +69,144 @@ fn lint_demo_uncached(c: &mut Criterion) {
     });
 }
 
+/// Spell check roughly 50,000 words. Compare with and without the `rayon`
+/// feature.
+fn spell_check_large(c: &mut Criterion) {
+    let source = DEMO.repeat(500);
+    let dictionary = FstDictionary::curated();
+    let document = Document::new_markdown(&source, &dictionary);
+
+    c.bench_function("spell_check_large", |b| {
+        b.iter(|| SpellCheck::new(dictionary.clone()).lint(&document));
+    });
+}
+
+/// Check every word of the demo against the dictionary. Compare the batch
+/// path with individual lookups.
+fn contains_words(c: &mut Criterion) {
+    let dictionary = FstDictionary::curated();
+    let document = Document::new_markdown(DEMO, &dictionary);
+    let words: Vec<&[char]> = document
+        .tokens()
+        .filter(|t| t.kind.is_word())
+        .map(|t| document.get_span_content(t.span))
+        .collect();
+
+    c.bench_function("contains_words_batch", |b| {
+        b.iter(|| dictionary.contains_words(black_box(&words)))
+    });
+
+    c.bench_function("contains_words_individual", |b| {
+        b.iter(|| {
+            black_box(&words)
+                .iter()
+                .map(|w| dictionary.contains_word(w))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+/// Check every word of the demo, and misspelled copies of them, with and
+/// without a bloom filter in front of the word map.
+fn contains_word_bloom(c: &mut Criterion) {
+    let plain = (*FullDictionary::curated()).clone();
+    let filtered = plain.clone().with_bloom_filter();
+    let document = Document::new_markdown(DEMO, &plain);
+    let words: Vec<&[char]> = document
+        .tokens()
+        .filter(|t| t.kind.is_word())
+        .map(|t| document.get_span_content(t.span))
+        .collect();
+
+    // Reversing each word makes almost all of them misspelled.
+    let misspelled: Vec<Vec<char>> = words
+        .iter()
+        .map(|w| w.iter().rev().copied().collect())
+        .collect();
+
+    c.bench_function("contains_word_misspelled_without_bloom", |b| {
+        b.iter(|| {
+            black_box(&misspelled)
+                .iter()
+                .filter(|w| plain.contains_word(w))
+                .count()
+        })
+    });
+
+    c.bench_function("contains_word_misspelled_with_bloom", |b| {
+        b.iter(|| {
+            black_box(&misspelled)
+                .iter()
+                .filter(|w| filtered.contains_word(w))
+                .count()
+        })
+    });
+
+    c.bench_function("contains_word_without_bloom", |b| {
+        b.iter(|| {
+            black_box(&words)
+                .iter()
+                .filter(|w| plain.contains_word(w))
+                .count()
+        })
+    });
+
+    c.bench_function("contains_word_with_bloom", |b| {
+        b.iter(|| {
+            black_box(&words)
+                .iter()
+                .filter(|w| filtered.contains_word(w))
+                .count()
+        })
+    });
+}
+
+/// Fuzzy match common misspellings, allocating new results each time versus
+/// reusing an output buffer.
+/// Also prints how many allocations each approach makes per call.
+fn fuzzy_match_reuse(c: &mut Criterion) {
+    let dictionary = FstDictionary::curated();
+    let misspelled: Vec<Vec<char>> = ["hvllo", "recieve", "definately", "teh", "wrld"]
+        .iter()
+        .map(|w| w.chars().collect())
+        .collect();
+
+    let mut allocating = || {
+        for word in &misspelled {
+            black_box(dictionary.fuzzy_match(black_box(word), 2, 10));
+        }
+    };
+
+    let mut out = Vec::new();
+    let mut reusing = || {
+        for word in &misspelled {
+            dictionary.fuzzy_match_into(black_box(word), 2, 10, &mut out);
+            black_box(&out);
+        }
+    };
+
+    // Warm up the buffers before counting.
+    allocating();
+    reusing();
+
+    eprintln!(
+        "fuzzy_match: {:.1} allocations per call, fuzzy_match_into: {:.1}",
+        allocations_per_call(100, &mut allocating) / misspelled.len() as f64,
+        allocations_per_call(100, &mut reusing) / misspelled.len() as f64,
+    );
+
+    c.bench_function("fuzzy_match_allocating", |b| b.iter(&mut allocating));
+    c.bench_function("fuzzy_match_into", |b| b.iter(&mut reusing));
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     parse_demo(c);
     lint_demo(c);
     lint_demo_uncached(c);
+    spell_check_large(c);
+    contains_words(c);
+    contains_word_bloom(c);
+    fuzzy_match_reuse(c);
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,255 @@
+//! A C interface to Harper, for embedding it in editors that aren't written
+//! in Rust (e.g. Vim plugins).
+//!
+//! Requires the `cabi` feature. A static library can be built with:
+//!
+//! ```sh
+//! cargo rustc -p harper-core --release --features cabi --crate-type staticlib
+//! ```
+//!
+//! # Ownership
+//!
+//! Every array returned by [`harper_check`] is owned by Harper, and must be
+//! given back to [`harper_free_lints`] exactly once, along with the length
+//! [`harper_check`] reported.
+//! That frees the array and every string it points to, so callers must not
+//! free the [`LintFFI::suggestion`] strings themselves, or use them after the
+//! array has been freed.
+//! Freeing with the C `free` function is undefined behavior.
+//!
+//! ```c
+//! size_t len;
+//! LintFFI *lints = harper_check(text, &len);
+//!
+//! for (size_t i = 0; i < len; i++) {
+//!     // Use lints[i]...
+//! }
+//!
+//! harper_free_lints(lints, len);
+//! ```
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::linting::{LintGroup, LintGroupConfig, LintKind, Linter, Suggestion};
+use crate::{Document, FstDictionary};
+
+/// A problem found by [`harper_check`].
+#[repr(C)]
+#[derive(Debug)]
+pub struct LintFFI {
+    /// The byte offset in the checked text where the problem starts.
+    pub start: usize,
+    /// The byte offset in the checked text just past the end of the problem.
+    pub end: usize,
+    /// The kind of problem:
+    ///
+    /// | Code | Kind           |
+    /// | ---- | -------------- |
+    /// | 0    | Spelling       |
+    /// | 1    | Capitalization |
+    /// | 2    | Formatting     |
+    /// | 3    | Repetition     |
+    /// | 4    | Enhancement    |
+    /// | 5    | Readability    |
+    /// | 6    | Miscellaneous  |
+    /// | 7    | UnknownWord    |
+    pub kind: u8,
+    /// The text that should replace the problem, as a NUL-terminated UTF-8
+    /// string.
+    ///
+    /// Empty if the problem should be removed, and null if Harper has no
+    /// suggestion.
+    /// Owned by the surrounding array; see the [module docs](self).
+    pub suggestion: *mut c_char,
+}
+
+fn kind_code(kind: LintKind) -> u8 {
+    match kind {
+        LintKind::Spelling => 0,
+        LintKind::Capitalization => 1,
+        LintKind::Formatting => 2,
+        LintKind::Repetition => 3,
+        LintKind::Enhancement => 4,
+        LintKind::Readability => 5,
+        LintKind::Miscellaneous => 6,
+        LintKind::UnknownWord => 7,
+    }
+}
+
+/// Lint Markdown text with the default rules.
+///
+/// Returns an array of lints and writes its length to `out_len`.
+/// Returns null (with a length of zero) if there are no lints, or if `text`
+/// is null or isn't valid UTF-8.
+///
+/// The array must be freed with [`harper_free_lints`].
+///
+/// # Safety
+///
+/// `text` must be null or point to a NUL-terminated string, and `out_len`
+/// must point to writable memory for a `usize`.
+#[no_mangle]
+pub unsafe extern "C" fn harper_check(text: *const c_char, out_len: *mut usize) -> *mut LintFFI {
+    *out_len = 0;
+
+    if text.is_null() {
+        return ptr::null_mut();
+    }
+
+    let Ok(text) = CStr::from_ptr(text).to_str() else {
+        return ptr::null_mut();
+    };
+
+    let lints = check(text);
+
+    if lints.is_empty() {
+        return ptr::null_mut();
+    }
+
+    *out_len = lints.len();
+    Box::into_raw(lints.into_boxed_slice()).cast()
+}
+
+/// Free an array returned by [`harper_check`], along with all of its strings.
+///
+/// Does nothing if `lints` is null.
+///
+/// # Safety
+///
+/// `lints` must be null or an array returned by [`harper_check`] that hasn't
+/// been freed yet, and `len` must be the length it reported.
+#[no_mangle]
+pub unsafe extern "C" fn harper_free_lints(lints: *mut LintFFI, len: usize) {
+    if lints.is_null() {
+        return;
+    }
+
+    let lints = Box::from_raw(ptr::slice_from_raw_parts_mut(lints, len));
+
+    for lint in lints.iter() {
+        if !lint.suggestion.is_null() {
+            drop(CString::from_raw(lint.suggestion));
+        }
+    }
+}
+
+fn check(text: &str) -> Vec<LintFFI> {
+    let dictionary = FstDictionary::curated();
+    let document = Document::new_markdown(text, &dictionary);
+
+    let mut lints = LintGroup::new(LintGroupConfig::default(), dictionary).lint(&document);
+    document.normalizations().restore_spans(&mut lints);
+
+    // Lint spans count chars, so map each char index to its byte offset.
+    let byte_offsets: Vec<usize> = text.char_indices().map(|(offset, _)| offset).collect();
+    let byte_offset = |index: usize| byte_offsets.get(index).copied().unwrap_or(text.len());
+
+    lints
+        .into_iter()
+        .map(|lint| {
+            let suggestion = lint
+                .suggestions
+                .first()
+                .and_then(|suggestion| {
+                    let replacement = match suggestion {
+                        Suggestion::ReplaceWith(chars) => chars.iter().collect(),
+                        Suggestion::Remove => String::new(),
+                    };
+
+                    CString::new(replacement).ok()
+                })
+                .map_or(ptr::null_mut(), CString::into_raw);
+
+            LintFFI {
+                start: byte_offset(lint.span.start),
+                end: byte_offset(lint.span.end),
+                kind: kind_code(lint.lint_kind),
+                suggestion,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+    use std::ptr;
+
+    use super::{harper_check, harper_free_lints};
+
+    fn check(text: &str) -> Vec<(usize, usize, u8, Option<String>)> {
+        let text = CString::new(text).unwrap();
+        let mut len = usize::MAX;
+
+        unsafe {
+            let lints = harper_check(text.as_ptr(), &mut len);
+
+            let found = (0..len)
+                .map(|i| {
+                    let lint = &*lints.add(i);
+                    let suggestion = (!lint.suggestion.is_null()).then(|| {
+                        CStr::from_ptr(lint.suggestion)
+                            .to_str()
+                            .unwrap()
+                            .to_string()
+                    });
+
+                    (lint.start, lint.end, lint.kind, suggestion)
+                })
+                .collect();
+
+            harper_free_lints(lints, len);
+
+            found
+        }
+    }
+
+    #[test]
+    fn finds_misspelling() {
+        let lints = check("This is a tset.");
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!((lints[0].0, lints[0].1, lints[0].2), (10, 14, 0));
+        assert!(lints[0].3.is_some());
+    }
+
+    #[test]
+    fn spans_are_byte_offsets() {
+        let lints = check("“This” is a tset.");
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!((lints[0].0, lints[0].1), (16, 20));
+    }
+
+    #[test]
+    fn spans_refer_to_decomposed_text() {
+        let lints = check("The cafe\u{301} has a tpyo.");
+
+        assert_eq!(lints.len(), 2);
+        assert_eq!((lints[0].0, lints[0].1), (4, 10));
+        assert_eq!((lints[1].0, lints[1].1), (17, 21));
+    }
+
+    #[test]
+    fn clean_text_returns_null() {
+        let text = CString::new("This is a test.").unwrap();
+        let mut len = usize::MAX;
+
+        unsafe {
+            assert!(harper_check(text.as_ptr(), &mut len).is_null());
+        }
+        assert_eq!(len, 0);
+    }
+
+    #[test]
+    fn null_text_returns_null() {
+        let mut len = usize::MAX;
+
+        unsafe {
+            assert!(harper_check(ptr::null(), &mut len).is_null());
+            harper_free_lints(ptr::null_mut(), 0);
+        }
+        assert_eq!(len, 0);
+    }
+}
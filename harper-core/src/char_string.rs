@@ -7,6 +7,12 @@ pub type CharString = SmallVec<[char; 12]>;
 pub trait CharStringExt {
     fn to_lower(&self) -> CharString;
     fn to_string(&self) -> String;
+    /// Copy the case pattern of `template` (UPPER, Title or lower) onto this
+    /// string.
+    ///
+    /// If `template` is lowercase, the string is left as-is, so proper nouns
+    /// keep their capitalization.
+    fn with_case_of(&self, template: &[char]) -> CharString;
 }
 
 impl CharStringExt for [char] {
@@ -20,4 +26,68 @@ impl CharStringExt for [char] {
     fn to_string(&self) -> String {
         self.iter().collect()
     }
+
+    fn with_case_of(&self, template: &[char]) -> CharString {
+        let mut letters = template.iter().filter(|c| c.is_alphabetic());
+
+        let Some(first) = letters.next() else {
+            return self.into();
+        };
+
+        if !first.is_uppercase() {
+            return self.into();
+        }
+
+        let mut out = CharString::with_capacity(self.len());
+
+        // A single capital letter doesn't tell us whether the word is
+        // all-caps, so treat it as title case.
+        let mut rest = letters.peekable();
+        if rest.peek().is_some() && rest.all(|c| c.is_uppercase()) {
+            out.extend(self.iter().flat_map(|c| c.to_uppercase()));
+        } else {
+            let mut chars = self.iter();
+            out.extend(chars.next().into_iter().flat_map(|c| c.to_uppercase()));
+            out.extend(chars.copied());
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharStringExt;
+
+    fn case_of(word: &str, template: &str) -> String {
+        let word: Vec<_> = word.chars().collect();
+        let template: Vec<_> = template.chars().collect();
+
+        word.with_case_of(&template).iter().collect()
+    }
+
+    #[test]
+    fn matches_lowercase() {
+        assert_eq!(case_of("the", "teh"), "the");
+    }
+
+    #[test]
+    fn keeps_proper_nouns_after_lowercase() {
+        assert_eq!(case_of("Paris", "pariss"), "Paris");
+    }
+
+    #[test]
+    fn matches_title_case() {
+        assert_eq!(case_of("the", "Teh"), "The");
+    }
+
+    #[test]
+    fn matches_all_caps() {
+        assert_eq!(case_of("the", "TEH"), "THE");
+    }
+
+    #[test]
+    fn single_capital_is_title_case() {
+        assert_eq!(case_of("it's", "I"), "It's");
+    }
 }
@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+/// A span of a [`Document`](crate::Document) along with the text around it,
+/// as produced by [`Document::context_snippet`](crate::Document::context_snippet).
+///
+/// The snippet never extends past the line the span starts on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContextSnippet {
+    /// The span and the text around it, without any line break.
+    pub text: String,
+    /// The zero-based line the span starts on.
+    pub line: usize,
+    /// How many [`char`]s into [`Self::text`] the span starts.
+    pub column: usize,
+    /// How many [`char`]s of [`Self::text`] the span covers.
+    ///
+    /// If the span continues onto later lines, only the part on the first
+    /// line is counted.
+    pub len: usize,
+    /// Whether the line continues before [`Self::text`].
+    pub truncated_start: bool,
+    /// Whether the line continues after [`Self::text`].
+    pub truncated_end: bool,
+}
+
+impl ContextSnippet {
+    /// A line of spaces and carets that points at the span when printed
+    /// below [`Self::text`].
+    ///
+    /// There is always at least one caret, so empty spans are still visible.
+    pub fn caret_line(&self) -> String {
+        " ".repeat(self.column) + &"^".repeat(self.len.max(1))
+    }
+}
+
+/// The text, followed by a line of carets pointing at the span.
+impl Display for ContextSnippet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.text)?;
+        write!(f, "{}", self.caret_line())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContextSnippet;
+
+    #[test]
+    fn carets_point_at_span() {
+        let snippet = ContextSnippet {
+            text: "This is a tset.".to_string(),
+            line: 0,
+            column: 10,
+            len: 4,
+            truncated_start: false,
+            truncated_end: false,
+        };
+
+        assert_eq!(snippet.to_string(), "This is a tset.\n          ^^^^");
+    }
+
+    #[test]
+    fn empty_spans_get_one_caret() {
+        let snippet = ContextSnippet {
+            text: "Hi".to_string(),
+            line: 0,
+            column: 2,
+            len: 0,
+            truncated_start: false,
+            truncated_end: false,
+        };
+
+        assert_eq!(snippet.caret_line(), "  ^");
+    }
+}
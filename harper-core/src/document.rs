@@ -1,22 +1,46 @@
 use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::fmt::Display;
+use std::ops::Range;
 
+use itertools::Itertools;
 use paste::paste;
+use unicode_segmentation::UnicodeSegmentation;
 
+use crate::linting::{Lint, LintDiff, Linter, Suggestion};
 use crate::parsers::{Markdown, Parser, PlainEnglish};
 use crate::patterns::{PatternExt, RepeatingPattern, SequencePattern};
 use crate::punctuation::Punctuation;
 use crate::token::NumberSuffix;
 use crate::vec_ext::VecExt;
 use crate::Span;
-use crate::{Dictionary, FatToken, FstDictionary, Lrc, Token, TokenKind, TokenStringExt};
+use crate::{
+    ContextSnippet, Dictionary, FatToken, FstDictionary, Lrc, Normalizations, Normalizer, Token,
+    TokenKind, TokenStringExt,
+};
+
+/// Abbreviated titles, which are always followed by a name, so the period
+/// after them never ends a sentence.
+const TITLES: &[&str] = &["Mr", "Mrs", "Ms", "Dr", "Prof"];
 
 /// A document containing some amount of lexed and parsed English text.
 #[derive(Debug, Clone)]
 pub struct Document {
     source: Lrc<Vec<char>>,
     tokens: Vec<Token>,
+    /// Edits made since the last call to [`Self::relint_dirty`]
+    pending_edit: Option<PendingEdit>,
+    /// What the [`Normalizer`] changed while the document was created.
+    normalizations: Normalizations,
+}
+
+/// One or more edits to a [`Document`], combined into a single replacement.
+#[derive(Debug, Clone, Copy)]
+struct PendingEdit {
+    /// The region of the source before the edits.
+    old: Span,
+    /// The region that replaced it.
+    new: Span,
 }
 
 impl Default for Document {
@@ -28,18 +52,37 @@ impl Default for Document {
 impl Document {
     /// Lexes and parses text to produce a document using a provided language
     /// parser and dictionary.
+    ///
+    /// The text is first normalized with the default [`Normalizer`], so spans
+    /// refer to the normalized text. See [`Self::normalizations`] to map them
+    /// back.
     pub fn new(text: &str, parser: &mut impl Parser, dictionary: &impl Dictionary) -> Self {
-        let source: Vec<_> = text.chars().collect();
-
-        Self::new_from_vec(Lrc::new(source), parser, dictionary)
+        Self::new_with_normalizer(text, parser, dictionary, &Normalizer::default())
     }
 
     /// Lexes and parses text to produce a document using a provided language
     /// parser and the included curated dictionary.
     pub fn new_curated(text: &str, parser: &mut impl Parser) -> Self {
-        let source: Vec<_> = text.chars().collect();
+        Self::new(text, parser, &FstDictionary::curated())
+    }
+
+    /// Normalize text with the provided [`Normalizer`], then lex and parse
+    /// it to produce a document.
+    ///
+    /// The document's source is the normalized text, and
+    /// [`Self::normalizations`] reports what was changed.
+    pub fn new_with_normalizer(
+        text: &str,
+        parser: &mut impl Parser,
+        dictionary: &impl Dictionary,
+        normalizer: &Normalizer,
+    ) -> Self {
+        let (source, normalizations) = normalizer.normalize(text);
 
-        Self::new_from_vec(Lrc::new(source), parser, &FstDictionary::curated())
+        let mut document = Self::new_from_vec(Lrc::new(source), parser, dictionary);
+        document.normalizations = normalizations;
+
+        document
     }
 
     /// Lexes and parses text to produce a document using a provided language
@@ -51,7 +94,12 @@ impl Document {
     ) -> Self {
         let tokens = parser.parse(&source);
 
-        let mut document = Self { source, tokens };
+        let mut document = Self {
+            source,
+            tokens,
+            pending_edit: None,
+            normalizations: Normalizations::default(),
+        };
         document.parse(dictionary);
 
         document
@@ -75,12 +123,30 @@ impl Document {
         Self::new(text, &mut Markdown, &FstDictionary::curated())
     }
 
-    /// Parse text to produce a document using the built-in [`PlainEnglish`]
-    /// parser and the curated dictionary.
+    /// Parse text to produce a document using the built-in [`Markdown`] parser
+    /// and a provided dictionary.
     pub fn new_markdown(text: &str, dictionary: &impl Dictionary) -> Self {
         Self::new(text, &mut Markdown, dictionary)
     }
 
+    /// Apply a suggestion to the underlying source text.
+    ///
+    /// Since the document doesn't hold on to the parser that produced it, the
+    /// parser and dictionary must be provided again so the new text can be
+    /// re-lexed.
+    pub fn apply_suggestion(
+        &mut self,
+        span: Span,
+        suggestion: &Suggestion,
+        parser: &mut impl Parser,
+        dictionary: &impl Dictionary,
+    ) {
+        suggestion.apply(span, Lrc::make_mut(&mut self.source));
+
+        self.tokens = parser.parse(&self.source);
+        self.parse(dictionary);
+    }
+
     /// Re-parse important language constructs.
     ///
     /// Should be run after every change to the underlying [`Self::source`].
@@ -91,6 +157,7 @@ impl Document {
         self.newlines_to_breaks();
         self.condense_contractions();
         self.condense_dotted_initialisms();
+        self.condense_abbreviations(dictionary);
         self.condense_number_suffixes();
         self.match_quotes();
 
@@ -176,7 +243,13 @@ impl Document {
         self.tokens.get(index).copied()
     }
 
-    /// Get an iterator over all the tokens contained in the document.
+    /// Get an iterator over all the tokens contained in the document, in
+    /// order.
+    ///
+    /// Each token's [`TokenKind`](crate::TokenKind) says whether it is a
+    /// word, number, punctuation, whitespace and so on, so linters can share
+    /// this tokenization instead of scanning the source themselves.
+    /// Contractions like "don't" are a single word.
     pub fn tokens(&self) -> impl Iterator<Item = Token> + '_ {
         self.tokens.iter().copied()
     }
@@ -186,6 +259,16 @@ impl Document {
         self.tokens().map(|token| token.to_fat(&self.source))
     }
 
+    /// Which normalizations changed the text this document was created from.
+    ///
+    /// Spans refer to the normalized text. Use
+    /// [`Normalizations::restore_spans`] to map lints back onto the original
+    /// text, and [`Normalizations::restore_style`] to make suggestions match
+    /// its punctuation.
+    pub fn normalizations(&self) -> &Normalizations {
+        &self.normalizations
+    }
+
     pub fn get_span_content(&self, span: Span) -> &[char] {
         span.get_content(&self.source)
     }
@@ -194,6 +277,23 @@ impl Document {
         String::from_iter(self.get_span_content(span))
     }
 
+    /// The user-perceived characters (grapheme clusters) within a span.
+    ///
+    /// An emoji sequence like "👨‍👩‍👧", or an "é" written as an "e" followed
+    /// by a combining accent, is several [`char`]s but a single grapheme.
+    pub fn span_graphemes(&self, span: Span) -> Vec<String> {
+        self.get_span_content_str(span)
+            .graphemes(true)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// The number of user-perceived characters within a span, for placing
+    /// underlines in UIs that count graphemes rather than [`char`]s.
+    pub fn grapheme_len(&self, span: Span) -> usize {
+        self.get_span_content_str(span).graphemes(true).count()
+    }
+
     pub fn get_full_string(&self) -> String {
         self.get_span_content_str(Span {
             start: 0,
@@ -209,6 +309,310 @@ impl Document {
         &self.source
     }
 
+    /// Get the range of bytes a span covers in the UTF-8 encoding of the
+    /// source, for use with tools that index by byte (like tree-sitter).
+    pub fn span_to_byte_range(&self, span: Span) -> Range<usize> {
+        let start: usize = self.source[..span.start].iter().map(|c| c.len_utf8()).sum();
+        let len: usize = self
+            .get_span_content(span)
+            .iter()
+            .map(|c| c.len_utf8())
+            .sum();
+
+        start..start + len
+    }
+
+    /// The inverse of [`Self::span_to_byte_range`].
+    ///
+    /// Offsets that fall inside a multi-byte character are moved forward to
+    /// the start of the next one.
+    pub fn byte_range_to_span(&self, range: Range<usize>) -> Span {
+        Span::new(
+            self.byte_offset_to_index(range.start),
+            self.byte_offset_to_index(range.end),
+        )
+    }
+
+    /// Replace the chars in `range` with `replacement`, then re-parse the
+    /// document.
+    ///
+    /// The edited region is remembered, so that [`Self::relint_dirty`] only
+    /// needs to lint the sentences it touches.
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn edit(
+        &mut self,
+        range: Range<usize>,
+        replacement: &str,
+        parser: &mut impl Parser,
+        dictionary: &impl Dictionary,
+    ) {
+        let replacement_len = replacement.chars().count();
+
+        let mut source = self.source.to_vec();
+        source.splice(range.clone(), replacement.chars());
+
+        let previous = self.pending_edit.unwrap_or(PendingEdit {
+            old: Span::new(range.start, range.end),
+            new: Span::new(range.start, range.end),
+        });
+
+        // Combine with earlier edits, so they can be handled in one pass.
+        let start = previous.new.start.min(range.start);
+        let end = previous.new.end.max(range.end);
+
+        let mut normalizations = std::mem::take(&mut self.normalizations);
+        normalizations.replace(Span::new(range.start, range.end), replacement_len);
+
+        *self = Self::new_from_vec(Lrc::new(source), parser, dictionary);
+        self.normalizations = normalizations;
+        self.pending_edit = Some(PendingEdit {
+            old: Span::new(start, end + previous.old.len() - previous.new.len()),
+            new: Span::new(start, end + replacement_len - range.len()),
+        });
+    }
+
+    /// Lint the sentences touched by [`Self::edit`]s since the last call, and
+    /// work out how that changes the lints of the previous pass.
+    ///
+    /// `previous` should be every lint from the previous pass, from before
+    /// the edits were made.
+    /// Lints before the edits are kept as they are, and those after it are
+    /// moved rather than linted again.
+    ///
+    /// Linters that look across sentence boundaries may miss problems that
+    /// span the edited sentences, so a full pass is still worthwhile now and
+    /// then.
+    pub fn relint_dirty(&mut self, linter: &mut impl Linter, previous: &[Lint]) -> LintDiff {
+        let Some(PendingEdit { old, new }) = self.pending_edit.take() else {
+            return LintDiff::default();
+        };
+
+        let dirty_tokens: Vec<Token> = self
+            .tokens
+            .iter_sentences()
+            .filter(|sentence| {
+                sentence
+                    .span()
+                    .is_some_and(|span| span.start <= new.end && new.start <= span.end)
+            })
+            .flatten()
+            .copied()
+            .collect();
+
+        let dirty = dirty_tokens.span().unwrap_or(new);
+        let dirty = dirty.merge(new);
+        let old_dirty = Span::new(dirty.start, dirty.end + old.len() - new.len());
+
+        let mut diff = LintDiff::default();
+
+        for lint in previous {
+            if lint.span.end <= old_dirty.start {
+                continue;
+            }
+
+            if lint.span.start >= old_dirty.end {
+                if old_dirty.end == dirty.end {
+                    continue;
+                }
+
+                let mut moved = lint.clone();
+                moved.span = Span::new(
+                    lint.span.start - old_dirty.end + dirty.end,
+                    lint.span.end - old_dirty.end + dirty.end,
+                );
+                diff.added.push(moved);
+            }
+
+            diff.removed.push(lint.clone());
+        }
+
+        // The dirty sentences are linted on their own, but share the full
+        // source, so the lints' spans are already in place.
+        let dirty_document = Self {
+            source: self.source.clone(),
+            tokens: dirty_tokens,
+            pending_edit: None,
+            normalizations: self.normalizations.clone(),
+        };
+        diff.added.extend(linter.lint(&dirty_document));
+
+        diff
+    }
+
+    /// A copy of this document that only keeps the tokens that lie entirely
+    /// within one of `spans`, so linters only look at those regions.
+    ///
+    /// The source is shared, so the spans of any lints stay in place.
+    pub(crate) fn restricted_to(&self, spans: &[Span]) -> Self {
+        let tokens = self
+            .tokens
+            .iter()
+            .filter(|token| {
+                spans
+                    .iter()
+                    .any(|span| span.start <= token.span.start && token.span.end <= span.end)
+            })
+            .copied()
+            .collect();
+
+        Self {
+            source: self.source.clone(),
+            tokens,
+            pending_edit: None,
+            normalizations: self.normalizations.clone(),
+        }
+    }
+
+    /// Get the text around a span, for showing it in a report.
+    ///
+    /// Up to `radius` [`char`]s are included on either side of the span, but
+    /// the snippet stops at the ends of the line the span starts on.
+    /// Spans that run past the end of the source are clamped to it.
+    pub fn context_snippet(&self, span: Span, radius: usize) -> ContextSnippet {
+        let source = &self.source;
+
+        let start = span.start.min(source.len());
+        let end = span.end.clamp(start, source.len());
+
+        let line_start = source[..start]
+            .iter()
+            .rposition(|c| *c == '\n')
+            .map_or(0, |idx| idx + 1);
+        let mut line_end = source[start..]
+            .iter()
+            .position(|c| *c == '\n')
+            .map_or(source.len(), |idx| start + idx);
+
+        // Leave the carriage return of a CRLF line ending out.
+        if line_end > start && source[line_end - 1] == '\r' {
+            line_end -= 1;
+        }
+
+        let end_in_line = end.min(line_end).max(start);
+
+        let snippet_start = start.saturating_sub(radius).max(line_start);
+        let snippet_end = end_in_line.saturating_add(radius).min(line_end);
+
+        ContextSnippet {
+            text: source[snippet_start..snippet_end].iter().collect(),
+            line: source[..start].iter().filter(|c| **c == '\n').count(),
+            column: start - snippet_start,
+            len: end_in_line - start,
+            truncated_start: snippet_start > line_start,
+            truncated_end: snippet_end < line_end,
+        }
+    }
+
+    /// Get the zero-based line and column of a char offset, with the column
+    /// counted in UTF-16 code units, as browsers and LSP clients expect.
+    ///
+    /// Astral characters (like emoji) are a single char, but two UTF-16 code
+    /// units.
+    /// Offsets past the end of the source map to the end of the source.
+    pub fn utf16_position(&self, char_offset: usize) -> (u32, u32) {
+        let before = &self.source[..char_offset.min(self.source.len())];
+        let line_start = before
+            .iter()
+            .rposition(|c| *c == '\n')
+            .map_or(0, |idx| idx + 1);
+
+        let line = before.iter().filter(|c| **c == '\n').count();
+        let column: usize = before[line_start..].iter().map(|c| c.len_utf16()).sum();
+
+        (line as u32, column as u32)
+    }
+
+    /// The inverse of [`Self::utf16_position`].
+    ///
+    /// Columns in the middle of a surrogate pair are moved forward to the
+    /// next char. Columns past the end of a line map to the end of that line,
+    /// and lines past the end of the source map to the end of the source.
+    pub fn utf16_position_to_offset(&self, line: u32, column: u32) -> usize {
+        let mut line_starts = std::iter::once(0).chain(
+            self.source
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| **c == '\n')
+                .map(|(idx, _)| idx + 1),
+        );
+
+        let Some(line_start) = line_starts.nth(line as usize) else {
+            return self.source.len();
+        };
+        let line_end = line_starts
+            .next()
+            .map_or(self.source.len(), |next| next - 1);
+
+        let mut traversed = 0;
+
+        for (idx, c) in self.source[line_start..line_end].iter().enumerate() {
+            if traversed >= column as usize {
+                return line_start + idx;
+            }
+
+            traversed += c.len_utf16();
+        }
+
+        line_end
+    }
+
+    fn byte_offset_to_index(&self, offset: usize) -> usize {
+        let mut bytes = 0;
+
+        self.source
+            .iter()
+            .take_while(|c| {
+                let before = bytes;
+                bytes += c.len_utf8();
+                before < offset
+            })
+            .count()
+    }
+
+    /// Get the span of each sentence in the document, without any leading
+    /// whitespace.
+    ///
+    /// Each span runs from the first non-whitespace character of the sentence
+    /// up to and including its terminator, if it has one.
+    /// Sentences end at periods, question marks, exclamation points and
+    /// paragraph breaks.
+    /// Decimals like "3.14" and ellipses don't end a sentence, and neither do
+    /// abbreviations like "Dr." that the dictionary contains with their
+    /// period, so extra abbreviations can be supported by adding them to the
+    /// dictionary the document is parsed with.
+    pub fn sentences(&self) -> impl Iterator<Item = Span> + '_ {
+        self.iter_sentences().filter_map(|sentence| {
+            let start = sentence.iter().position(|t| !t.kind.is_whitespace())?;
+            sentence[start..].span()
+        })
+    }
+
+    /// Get the span of each paragraph in the document.
+    ///
+    /// Paragraphs are separated by one or more blank lines, and each span
+    /// covers the text between them, without any surrounding whitespace.
+    /// Blank lines at the start or end of the document don't produce empty
+    /// paragraphs.
+    pub fn paragraphs(&self) -> impl Iterator<Item = Span> + '_ {
+        self.iter_paragraphs().filter_map(|paragraph| {
+            let is_content = |t: &Token| !t.kind.is_whitespace() && !t.kind.is_paragraph_break();
+
+            let start = paragraph.iter().position(is_content)?;
+            let end = paragraph.iter().rposition(is_content)?;
+            paragraph[start..=end].span()
+        })
+    }
+
+    /// Get the spans of all the whitespace (spaces, tabs and newlines) in the
+    /// document.
+    pub fn whitespace_spans(&self) -> impl Iterator<Item = Span> + '_ {
+        self.tokens
+            .iter()
+            .filter(|t| t.kind.is_whitespace())
+            .map(|t| t.span)
+    }
+
     pub fn get_tokens(&self) -> &[Token] {
         &self.tokens
     }
@@ -387,6 +791,53 @@ impl Document {
         self.tokens.remove_indices(to_remove);
     }
 
+    /// Merge words like "Mr" or "etc" with the period that follows them,
+    /// when the period can't be the end of a sentence.
+    ///
+    /// A word is treated as an abbreviation if the dictionary contains it with
+    /// a trailing period. Since a sentence can also end with one, the period
+    /// is only merged after a title like "Mr", or when it isn't followed by a
+    /// capitalized word, a paragraph break or the end of the text.
+    fn condense_abbreviations(&mut self, dictionary: &impl Dictionary) {
+        let mut to_condense = Vec::new();
+
+        for (idx, (a, b)) in self.tokens.iter().tuple_windows().enumerate() {
+            if !a.kind.is_word() || !b.kind.is_period() || a.span.end != b.span.start {
+                continue;
+            }
+
+            let word = a.span.get_content(&self.source);
+
+            let mut with_period = word.to_vec();
+            with_period.push('.');
+
+            if !dictionary.contains_word(&with_period) {
+                continue;
+            }
+
+            let is_title = TITLES.contains(&word.iter().collect::<String>().as_str());
+            let next = self.tokens[idx + 2..]
+                .iter()
+                .find(|token| !token.kind.is_whitespace());
+
+            if is_title || !self.starts_sentence(next) {
+                to_condense.push(idx);
+            }
+        }
+
+        self.condense_indices(&to_condense, 2);
+    }
+
+    /// Whether the token after an ambiguous period, like the one after "etc",
+    /// means the period ends the sentence.
+    fn starts_sentence(&self, next: Option<&Token>) -> bool {
+        match next {
+            None => true,
+            Some(token) if token.kind.is_word() => self.source[token.span.start].is_uppercase(),
+            Some(token) => token.kind.is_sentence_terminator(),
+        }
+    }
+
     fn uncached_ellipsis_pattern() -> Lrc<RepeatingPattern> {
         let period = SequencePattern::default().then_period();
         Lrc::new(RepeatingPattern::new(Box::new(period)))
@@ -423,42 +874,38 @@ impl Document {
 
     /// Searches for contractions and condenses them down into single
     /// tokens.
+    ///
+    /// Any number of words joined by apostrophes are condensed, so
+    /// "rock'n'roll" is a single word too.
     fn condense_contractions(&mut self) {
-        if self.tokens.len() < 3 {
-            return;
-        }
+        let mut to_remove = VecDeque::new();
+        let mut idx = 0;
 
-        // Indices of the three token stretches we are going to condense.
-        let mut replace_starts = Vec::new();
+        while idx < self.tokens.len() {
+            if !self.tokens[idx].kind.is_word() {
+                idx += 1;
+                continue;
+            }
 
-        for idx in 0..self.tokens.len() - 2 {
-            let a = self.tokens[idx];
-            let b = self.tokens[idx + 1];
-            let c = self.tokens[idx + 2];
-
-            if matches!(
-                (a.kind, b.kind, c.kind),
-                (
-                    TokenKind::Word(..),
-                    TokenKind::Punctuation(Punctuation::Apostrophe),
-                    TokenKind::Word(..)
-                )
-            ) {
-                // Ensure there is no overlapping between replacements
-                let should_replace = if let Some(last_idx) = replace_starts.last() {
-                    *last_idx < idx - 2
-                } else {
-                    true
-                };
+            let mut end = idx;
 
-                if should_replace {
-                    replace_starts.push(idx);
-                    self.tokens[idx].span.end = c.span.end;
+            while let [apostrophe, word, ..] = &self.tokens[end + 1..] {
+                if !apostrophe.kind.is_apostrophe() || !word.kind.is_word() {
+                    break;
                 }
+
+                end += 2;
             }
+
+            if end > idx {
+                self.tokens[idx].span.end = self.tokens[end].span.end;
+                to_remove.extend(idx + 1..=end);
+            }
+
+            idx = end + 1;
         }
 
-        self.condense_indices(&replace_starts, 3);
+        self.tokens.remove_indices(to_remove);
     }
 }
 
@@ -550,10 +997,18 @@ impl Display for Document {
 
 #[cfg(test)]
 mod tests {
+    use std::ops::Range;
+    use std::sync::Arc;
+
     use itertools::Itertools;
 
     use super::Document;
-    use crate::Span;
+    use crate::linting::{Lint, LintDiff, LintGroup, Linter, Suggestion};
+    use crate::parsers::{Markdown, PlainEnglish};
+    use crate::{
+        FstDictionary, FullDictionary, MergedDictionary, Normalizer, Span, TokenStringExt,
+        WordMetadata,
+    };
 
     fn assert_condensed_contractions(text: &str, final_tok_count: usize) {
         let document = Document::new_plain_english_curated(text);
@@ -565,6 +1020,162 @@ mod tests {
         assert_eq!(document.tokens.len(), final_tok_count);
     }
 
+    #[test]
+    fn curly_contraction() {
+        assert_condensed_contractions("don’t", 1);
+    }
+
+    #[test]
+    fn hyphenated_words_are_split() {
+        let document = Document::new_plain_english_curated("A well-known fact.");
+        let words = document
+            .iter_words()
+            .map(|word| document.get_span_content_str(word.span))
+            .collect_vec();
+
+        assert_eq!(words, ["A", "well", "known", "fact"]);
+    }
+
+    #[test]
+    fn unspaced_dashes_separate_words() {
+        for text in ["I came—I saw.", "I came–I saw.", "I came‐I saw."] {
+            let document = Document::new_plain_english_curated(text);
+
+            assert_eq!(document.iter_words().count(), 4, "{text}");
+        }
+    }
+
+    #[test]
+    fn family_emoji_is_one_grapheme() {
+        let document = Document::new_plain_english_curated("Hi 👨‍👩‍👧!");
+        let span = Span::new(3, 8);
+
+        assert_eq!(document.get_span_content(span).len(), 5);
+        assert_eq!(document.grapheme_len(span), 1);
+        assert_eq!(document.span_graphemes(span), vec!["👨‍👩‍👧"]);
+    }
+
+    #[test]
+    fn combining_marks_join_their_letter() {
+        let document = Document::new_with_normalizer(
+            "cafe\u{301} au lait",
+            &mut PlainEnglish,
+            &FstDictionary::curated(),
+            &Normalizer::none(),
+        );
+        let span = Span::new(0, 5);
+
+        assert_eq!(document.get_span_content(span).len(), 5);
+        assert_eq!(document.grapheme_len(span), 4);
+        assert_eq!(
+            document.span_graphemes(span),
+            vec!["c", "a", "f", "e\u{301}"]
+        );
+    }
+
+    #[test]
+    fn composes_text_by_default() {
+        let document = Document::new_plain_english_curated("cafe\u{301} au lait");
+
+        assert_eq!(document.get_full_string(), "café au lait");
+        assert!(document.normalizations().nfc);
+    }
+
+    #[test]
+    fn lint_spans_map_back_to_decomposed_text() {
+        let text = "The cafe\u{301} has a tpyo.";
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown(text, &dict);
+        let mut lints = LintGroup::new(Default::default(), dict).lint(&document);
+
+        let flagged: Vec<String> = lints
+            .iter()
+            .map(|lint| document.get_span_content_str(lint.span))
+            .collect();
+        assert_eq!(flagged, vec!["café", "tpyo"]);
+
+        document.normalizations().restore_spans(&mut lints);
+
+        let original: Vec<char> = text.chars().collect();
+        let flagged: Vec<String> = lints
+            .iter()
+            .map(|lint| lint.span.get_content(&original).iter().collect())
+            .collect();
+        assert_eq!(flagged, vec!["cafe\u{301}", "tpyo"]);
+    }
+
+    #[test]
+    fn edits_keep_offsets_in_line() {
+        let dict = FstDictionary::curated();
+        let mut document = Document::new_markdown("The cafe\u{301} has a tpyo.", &dict);
+
+        document.edit(4..8, "bar", &mut Markdown, &dict);
+
+        assert_eq!(document.get_full_string(), "The bar has a tpyo.");
+        assert_eq!(
+            document.normalizations().original_span(Span::new(14, 18)),
+            Span::new(14, 18)
+        );
+    }
+
+    #[test]
+    fn folded_quotes_are_reported() {
+        let document = Document::new_with_normalizer(
+            "They’re here.",
+            &mut PlainEnglish,
+            &FstDictionary::curated(),
+            &Normalizer::new().with_fold_quotes(true),
+        );
+
+        assert_eq!(document.get_full_string(), "They're here.");
+        assert!(document.normalizations().quotes);
+        assert!(!document.normalizations().nfc);
+    }
+
+    #[test]
+    fn tokens_are_classified() {
+        let document = Document::new_plain_english_curated("I don't have 3 cats.");
+
+        let kinds = document
+            .tokens()
+            .map(|token| {
+                if token.kind.is_word() {
+                    "word"
+                } else if token.kind.is_number() {
+                    "number"
+                } else if token.kind.is_punctuation() {
+                    "punctuation"
+                } else if token.kind.is_whitespace() {
+                    "whitespace"
+                } else {
+                    "other"
+                }
+            })
+            .collect_vec();
+
+        assert_eq!(
+            kinds,
+            [
+                "word",
+                "whitespace",
+                "word",
+                "whitespace",
+                "word",
+                "whitespace",
+                "number",
+                "whitespace",
+                "word",
+                "punctuation"
+            ]
+        );
+    }
+
+    #[test]
+    fn chained_contraction() {
+        assert_condensed_contractions("rock'n'roll", 1);
+        assert_condensed_contractions("rock'n'roll isn't", 3);
+    }
+
     #[test]
     fn simple_contraction() {
         assert_condensed_contractions("isn't", 1);
@@ -651,4 +1262,342 @@ mod tests {
     fn parses_short_ellipsis() {
         assert_token_count("..", 1);
     }
+
+    #[test]
+    fn apply_suggestion_reparses() {
+        let mut document = Document::new_markdown_curated("This is a tezzt.");
+
+        document.apply_suggestion(
+            Span::new(10, 15),
+            &Suggestion::ReplaceWith("test".chars().collect()),
+            &mut Markdown,
+            &FstDictionary::curated(),
+        );
+
+        assert_eq!(document.get_full_string(), "This is a test.");
+        assert_eq!(
+            document.get_span_content_str(document.get_tokens()[6].span),
+            "test"
+        );
+    }
+
+    fn sentence_strs(text: &str) -> Vec<String> {
+        let document = Document::new_markdown_curated(text);
+
+        document
+            .sentences()
+            .map(|span| document.get_span_content_str(span))
+            .collect()
+    }
+
+    #[test]
+    fn splits_sentences() {
+        assert_eq!(
+            sentence_strs("There were three little pigs. They built three little homes!"),
+            vec![
+                "There were three little pigs.",
+                "They built three little homes!"
+            ]
+        );
+    }
+
+    #[test]
+    fn abbreviations_do_not_end_sentences() {
+        assert_eq!(
+            sentence_strs("We need fruit, e.g. apples. Mr. Smith and Dr. Jones agree."),
+            vec![
+                "We need fruit, e.g. apples.",
+                "Mr. Smith and Dr. Jones agree."
+            ]
+        );
+    }
+
+    #[test]
+    fn decimals_and_ellipses_do_not_end_sentences() {
+        assert_eq!(
+            sentence_strs("Pi is about 3.14 or so. Well... maybe not."),
+            vec!["Pi is about 3.14 or so.", "Well... maybe not."]
+        );
+    }
+
+    #[test]
+    fn abbreviations_can_end_sentences() {
+        assert_eq!(
+            sentence_strs("We packed tents, stoves, etc. Then we left."),
+            vec!["We packed tents, stoves, etc.", "Then we left."]
+        );
+        assert_eq!(
+            sentence_strs("We packed tents, stoves, etc."),
+            vec!["We packed tents, stoves, etc."]
+        );
+    }
+
+    #[test]
+    fn abbreviations_before_lowercase_words_do_not_end_sentences() {
+        assert_eq!(
+            sentence_strs("We packed tents, stoves, etc. and then left."),
+            vec!["We packed tents, stoves, etc. and then left."]
+        );
+    }
+
+    #[test]
+    fn abbreviations_come_from_the_dictionary() {
+        let mut custom = FullDictionary::new();
+        custom.append_word_str("approx.", WordMetadata::default());
+
+        let mut dictionary = MergedDictionary::new();
+        dictionary.add_dictionary(FstDictionary::curated());
+        dictionary.add_dictionary(Arc::new(custom));
+
+        let document = Document::new_plain_english("It is approx. ten. Done.", &dictionary);
+        let sentences: Vec<_> = document
+            .sentences()
+            .map(|span| document.get_span_content_str(span))
+            .collect();
+
+        assert_eq!(sentences, vec!["It is approx. ten.", "Done."]);
+    }
+
+    fn paragraph_strs(text: &str) -> Vec<String> {
+        let document = Document::new_plain_english_curated(text);
+
+        document
+            .paragraphs()
+            .map(|span| document.get_span_content_str(span))
+            .collect()
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        assert_eq!(
+            paragraph_strs("First line.\nSame paragraph.\n\nSecond one."),
+            vec!["First line.\nSame paragraph.", "Second one."]
+        );
+    }
+
+    #[test]
+    fn collapses_blank_lines_between_paragraphs() {
+        assert_eq!(
+            paragraph_strs("\n\nOne.\n\n\n\nTwo.\n\n\n"),
+            vec!["One.", "Two."]
+        );
+    }
+
+    #[test]
+    fn blank_document_has_no_paragraphs() {
+        assert!(paragraph_strs("\n\n  \n").is_empty());
+    }
+
+    #[test]
+    fn abbreviations_are_single_words() {
+        let document = Document::new_plain_english_curated("Ask Mr. Smith.");
+
+        let words: Vec<_> = document
+            .iter_words()
+            .map(|t| document.get_span_content_str(t.span))
+            .collect();
+
+        assert_eq!(words, vec!["Ask", "Mr.", "Smith"]);
+    }
+
+    #[test]
+    fn finds_whitespace_spans() {
+        let document = Document::new_plain_english_curated("One  two\nthree");
+
+        let spans: Vec<_> = document.whitespace_spans().collect();
+
+        assert_eq!(spans, vec![Span::new(3, 5), Span::new(8, 9)]);
+    }
+
+    #[test]
+    fn span_to_byte_range_counts_multibyte_chars() {
+        let document = Document::new_plain_english_curated("“Café” is nice.");
+
+        // The quotes take three bytes each, and the "é" takes two.
+        assert_eq!(document.span_to_byte_range(Span::new(1, 5)), 3..8);
+        assert_eq!(document.span_to_byte_range(Span::new(7, 9)), 12..14);
+    }
+
+    #[test]
+    fn byte_ranges_round_trip() {
+        let text = "“Café” is nice.";
+        let document = Document::new_plain_english_curated(text);
+
+        for token in document.tokens() {
+            let range = document.span_to_byte_range(token.span);
+
+            assert_eq!(
+                &text[range.clone()],
+                document.get_span_content_str(token.span)
+            );
+            assert_eq!(document.byte_range_to_span(range), token.span);
+        }
+    }
+
+    #[test]
+    fn byte_range_inside_char_rounds_forward() {
+        let document = Document::new_plain_english_curated("é is e");
+
+        assert_eq!(document.byte_range_to_span(1..3), Span::new(1, 2));
+    }
+
+    #[test]
+    fn utf16_position_counts_surrogate_pairs() {
+        let document = Document::new_plain_english_curated("Hi 😀 there\nnaïve 😀 x");
+
+        // The emoji is one char but two UTF-16 code units.
+        assert_eq!(document.utf16_position(3), (0, 3));
+        assert_eq!(document.utf16_position(5), (0, 6));
+        assert_eq!(document.utf16_position(10), (0, 11));
+        assert_eq!(document.utf16_position(11), (1, 0));
+        // "ï" is two bytes in UTF-8, but one code unit in UTF-16.
+        assert_eq!(document.utf16_position(17), (1, 6));
+        assert_eq!(document.utf16_position(19), (1, 9));
+    }
+
+    #[test]
+    fn utf16_positions_round_trip() {
+        let document = Document::new_plain_english_curated("Hi 😀 there\nnaïve 😀 x\n\n𝒜 end");
+
+        for offset in 0..=document.get_source().len() {
+            let (line, column) = document.utf16_position(offset);
+
+            assert_eq!(document.utf16_position_to_offset(line, column), offset);
+        }
+    }
+
+    #[test]
+    fn utf16_position_to_offset_clamps() {
+        let document = Document::new_plain_english_curated("😀 a\nb");
+
+        // Column 1 is the middle of the emoji's surrogate pair.
+        assert_eq!(document.utf16_position_to_offset(0, 1), 1);
+        assert_eq!(document.utf16_position_to_offset(0, 50), 3);
+        assert_eq!(document.utf16_position_to_offset(9, 0), 5);
+    }
+
+    fn sorted_lints(mut lints: Vec<Lint>) -> Vec<Lint> {
+        lints.sort_by_key(|lint| (lint.span.start, lint.span.end, lint.message.clone()));
+        lints
+    }
+
+    fn assert_relint_matches_full(text: &str, edits: &[(Range<usize>, &str)]) {
+        let dictionary = FstDictionary::curated();
+        let mut linter = LintGroup::new(Default::default(), dictionary.clone());
+
+        let mut document = Document::new_plain_english(text, &dictionary);
+        let mut lints = linter.lint(&document);
+
+        for (range, replacement) in edits {
+            document.edit(range.clone(), replacement, &mut PlainEnglish, &dictionary);
+        }
+
+        let diff = document.relint_dirty(&mut linter, &lints);
+        diff.apply(&mut lints);
+
+        let fresh = Document::new_plain_english(&document.get_full_string(), &dictionary);
+
+        assert_eq!(sorted_lints(lints), sorted_lints(linter.lint(&fresh)));
+    }
+
+    #[test]
+    fn edit_updates_source_and_tokens() {
+        let dictionary = FstDictionary::curated();
+        let mut document = Document::new_plain_english_curated("This is a test.");
+
+        document.edit(10..14, "tset", &mut PlainEnglish, &dictionary);
+
+        assert_eq!(document.get_full_string(), "This is a tset.");
+        assert_eq!(document.tokens().filter(|t| t.kind.is_word()).count(), 4);
+    }
+
+    #[test]
+    fn relint_fixes_typo() {
+        assert_relint_matches_full(
+            "This is a tset. The dog is here. It is is fine.",
+            &[(10..14, "test")],
+        );
+    }
+
+    #[test]
+    fn relint_introduces_typo() {
+        assert_relint_matches_full(
+            "This is a test. The dog is here. It is is fine.",
+            &[(20..23, "dgo")],
+        );
+    }
+
+    #[test]
+    fn relint_moves_later_lints() {
+        assert_relint_matches_full(
+            "This is a test. The dgo is here. It is is fine.",
+            &[(0..4, "That one")],
+        );
+    }
+
+    #[test]
+    fn relint_combines_edits() {
+        assert_relint_matches_full(
+            "This is a tset. The dog is here. It is is fine.",
+            &[(10..14, "test"), (37..40, ""), (0..0, "Wow. ")],
+        );
+    }
+
+    #[test]
+    fn relint_without_edits_changes_nothing() {
+        let dictionary = FstDictionary::curated();
+        let mut linter = LintGroup::new(Default::default(), dictionary);
+        let mut document = Document::new_plain_english_curated("This is a tset.");
+
+        assert_eq!(document.relint_dirty(&mut linter, &[]), LintDiff::default());
+    }
+
+    #[test]
+    fn context_snippet_points_at_span() {
+        let document =
+            Document::new_plain_english_curated("The first line.\nThis is a tset of things.\n");
+        let snippet = document.context_snippet(Span::new(26, 30), 5);
+
+        assert_eq!(snippet.text, "is a tset of t");
+        assert_eq!(snippet.line, 1);
+        assert_eq!(snippet.column, 5);
+        assert_eq!(snippet.len, 4);
+        assert!(snippet.truncated_start && snippet.truncated_end);
+    }
+
+    #[test]
+    fn context_snippet_stops_at_line_edges() {
+        let document = Document::new_plain_english_curated("Tset here.\r\nNext line.");
+        let snippet = document.context_snippet(Span::new(0, 4), 40);
+
+        assert_eq!(snippet.text, "Tset here.");
+        assert_eq!(snippet.line, 0);
+        assert_eq!(snippet.column, 0);
+        assert!(!snippet.truncated_start && !snippet.truncated_end);
+    }
+
+    #[test]
+    fn context_snippet_clamps_spans_past_the_end() {
+        let document = Document::new_plain_english_curated("Short.");
+        let snippet = document.context_snippet(Span::new(4, 100), 2);
+
+        assert_eq!(snippet.text, "ort.");
+        assert_eq!(snippet.column, 2);
+        assert_eq!(snippet.len, 2);
+
+        let snippet = document.context_snippet(Span::new(50, 60), 2);
+
+        assert_eq!(snippet.column, 2);
+        assert_eq!(snippet.len, 0);
+    }
+
+    #[test]
+    fn context_snippet_only_covers_first_line_of_span() {
+        let document = Document::new_plain_english_curated("One two\nthree four");
+        let snippet = document.context_snippet(Span::new(4, 13), 10);
+
+        assert_eq!(snippet.text, "One two");
+        assert_eq!(snippet.column, 4);
+        assert_eq!(snippet.len, 3);
+    }
 }
@@ -155,6 +155,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn excludes_trailing_period() {
+        let address: Vec<_> = "user@host.com.".chars().collect();
+        assert_eq!(lex_email_address(&address).unwrap().next_index, 13);
+    }
+
     /// Tests that the email parser will not throw a panic under some random
     /// situations.
     #[test]
@@ -1,11 +1,11 @@
 pub fn lex_hostname(source: &[char]) -> Option<usize> {
     let mut passed_chars = 0;
 
-    for label in source.split(|c| *c == '.') {
+    'labels: for label in source.split(|c| *c == '.') {
         for c in label {
             passed_chars += 1;
             if !matches!(c, 'A'..='Z' | 'a'..='z' | '0'..='9' | '-') {
-                return Some(passed_chars - 1);
+                break 'labels;
             }
         }
 
@@ -13,10 +13,18 @@ pub fn lex_hostname(source: &[char]) -> Option<usize> {
     }
 
     if passed_chars == 0 {
-        None
-    } else {
-        Some(passed_chars - 1)
+        return None;
     }
+
+    let mut end = passed_chars - 1;
+
+    // A trailing dot is more likely the end of a sentence than part of a
+    // fully-qualified name.
+    if end > 0 && source[end - 1] == '.' {
+        end -= 1;
+    }
+
+    Some(end)
 }
 
 #[cfg(test)]
@@ -44,6 +52,12 @@ pub mod tests {
         .map(|s| s.chars().collect())
     }
 
+    #[test]
+    fn excludes_trailing_period() {
+        let source: Vec<_> = "example.com. Next".chars().collect();
+        assert_eq!(lex_hostname(&source), Some(11));
+    }
+
     #[test]
     fn can_parse_example_hostnames() {
         for domain in example_domain_parts() {
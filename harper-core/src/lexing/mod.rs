@@ -2,7 +2,7 @@ mod email_address;
 mod hostname;
 mod url;
 
-use url::lex_url;
+use url::{lex_bare_url, lex_url};
 
 use self::email_address::lex_email_address;
 use crate::char_ext::CharExt;
@@ -27,6 +27,7 @@ pub fn lex_token(source: &[char]) -> Option<FoundToken> {
         lex_number,
         lex_url,
         lex_email_address,
+        lex_bare_url,
         lex_word,
         lex_catch,
     ];
@@ -86,12 +87,24 @@ pub fn lex_number(source: &[char]) -> Option<FoundToken> {
 }
 
 fn lex_newlines(source: &[char]) -> Option<FoundToken> {
-    let count = source.iter().take_while(|c| **c == '\n').count();
+    let mut count = 0;
+    let mut next_index = 0;
+
+    // Windows line endings ("\r\n") count as a single newline.
+    loop {
+        match source[next_index..] {
+            ['\n', ..] => next_index += 1,
+            ['\r', '\n', ..] => next_index += 2,
+            _ => break,
+        }
+
+        count += 1;
+    }
 
     if count > 0 {
         Some(FoundToken {
             token: TokenKind::Newline(count),
-            next_index: count,
+            next_index,
         })
     } else {
         None
@@ -161,11 +174,21 @@ fn lex_catch(_source: &[char]) -> Option<FoundToken> {
 
 #[cfg(test)]
 mod tests {
-    use super::lex_word;
+    use super::{lex_newlines, lex_word};
+    use crate::TokenKind;
 
     #[test]
     fn lexes_cjk_as_unlintable() {
         let source: Vec<_> = "世".chars().collect();
         assert!(lex_word(&source).is_none());
     }
+
+    #[test]
+    fn lexes_crlf_as_newlines() {
+        let source: Vec<_> = "\r\n\n\r\nword".chars().collect();
+        let found = lex_newlines(&source).unwrap();
+
+        assert_eq!(found.token, TokenKind::Newline(3));
+        assert_eq!(found.next_index, 5);
+    }
 }
@@ -13,11 +13,71 @@ pub fn lex_url(source: &[char]) -> Option<FoundToken> {
     let url_end = lex_ip_schemepart(&source[sep + 1..])?;
 
     Some(FoundToken {
-        next_index: url_end + sep + 1,
+        next_index: trim_trailing_punctuation(source, url_end + sep + 1),
         token: TokenKind::Url,
     })
 }
 
+/// Lex a URL without a scheme, like `github.com/automattic/harper` or
+/// `www.example.com`.
+///
+/// To avoid catching ordinary sentences with a missing space, the hostname
+/// must either start with `www.` or be followed by a path.
+pub fn lex_bare_url(source: &[char]) -> Option<FoundToken> {
+    let host_end = lex_hostname(source)?;
+    let host = &source[..host_end];
+
+    let mut labels = host.split(|c| *c == '.');
+    let first_label = labels.next()?;
+    let tld = labels.next_back()?;
+
+    if tld.len() < 2 || !tld.iter().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let has_path = source.get(host_end) == Some(&'/');
+
+    if !has_path && first_label != ['w', 'w', 'w'] {
+        return None;
+    }
+
+    let mut cursor = host_end;
+
+    while source.get(cursor) == Some(&'/') {
+        cursor += 1;
+        cursor += lex_xchar_string(&source[cursor..]);
+    }
+
+    Some(FoundToken {
+        next_index: trim_trailing_punctuation(source, cursor),
+        token: TokenKind::Url,
+    })
+}
+
+/// Punctuation at the end of a URL almost always belongs to the surrounding
+/// sentence, so we leave it out.
+///
+/// A closing parenthesis is kept if it closes one opened within the URL, as
+/// in `https://en.wikipedia.org/wiki/Rust_(programming_language)`.
+fn trim_trailing_punctuation(source: &[char], mut end: usize) -> usize {
+    while end > 0 {
+        match source[end - 1] {
+            '.' | ',' | ';' | ':' | '!' | '?' | '\'' => end -= 1,
+            ')' if !closes_open_paren(&source[..end]) => end -= 1,
+            _ => break,
+        }
+    }
+
+    end
+}
+
+/// Whether the `)` that ends `url` matches a `(` earlier in it.
+fn closes_open_paren(url: &[char]) -> bool {
+    let count = |paren| url.iter().filter(|c| **c == paren).count();
+
+    count('(') >= count(')')
+}
+
 /// Checks whether a given char string is a valid "scheme" part of a URI.
 fn validate_scheme(source: &[char]) -> bool {
     source.iter().all(|c: &char| valid_scheme_char(*c))
@@ -194,7 +254,7 @@ fn lex_uchar(source: &[char]) -> Option<usize> {
 mod tests {
     use rand::Rng;
 
-    use super::lex_url;
+    use super::{lex_bare_url, lex_url};
 
     fn assert_consumes_full(url: &str) {
         assert_consumes_part(url, url.len());
@@ -236,6 +296,61 @@ mod tests {
         assert_consumes_full("https://github.com/nodesource/distributions#debinstall")
     }
 
+    #[test]
+    fn excludes_trailing_period() {
+        assert_consumes_part("https://example.com/foo. Next", 23);
+    }
+
+    #[test]
+    fn keeps_matched_closing_paren() {
+        assert_consumes_full("https://en.wikipedia.org/wiki/Rust_(programming_language)");
+        assert_consumes_part(
+            "https://en.wikipedia.org/wiki/Rust_(programming_language)).",
+            57,
+        );
+    }
+
+    #[test]
+    fn excludes_unmatched_closing_paren() {
+        assert_consumes_part("https://example.com/foo) and", 23);
+    }
+
+    #[test]
+    fn excludes_trailing_comma() {
+        assert_consumes_part("https://example.com, and", 19);
+    }
+
+    fn bare_url_len(url: &str) -> Option<usize> {
+        let url = url.chars().collect::<Vec<_>>();
+
+        lex_bare_url(&url).map(|f| f.next_index)
+    }
+
+    #[test]
+    fn consumes_bare_url_with_path() {
+        assert_eq!(bare_url_len("github.com/foo/bar, please"), Some(18));
+    }
+
+    #[test]
+    fn consumes_bare_www_url() {
+        assert_eq!(bare_url_len("www.example.org today."), Some(15));
+    }
+
+    #[test]
+    fn ignores_bare_hostname_without_path() {
+        assert_eq!(bare_url_len("example.com is nice"), None);
+    }
+
+    #[test]
+    fn ignores_slash_separated_words() {
+        assert_eq!(bare_url_len("and/or"), None);
+    }
+
+    #[test]
+    fn ignores_abbreviations() {
+        assert_eq!(bare_url_len("i.e./etc"), None);
+    }
+
     /// Tests that the URL parser will not throw a panic under some random
     /// situations.
     #[test]
@@ -248,6 +363,7 @@ mod tests {
             rng.try_fill(&mut buf).unwrap();
 
             lex_url(&buf);
+            lex_bare_url(&buf);
         }
     }
 }
@@ -1,13 +1,19 @@
 #![doc = include_str!("../README.md")]
 #![allow(dead_code)]
 
+#[cfg(feature = "cabi")]
+pub mod cabi;
 mod char_ext;
 mod char_string;
+mod context_snippet;
 mod document;
 pub mod language_detection;
 mod lexing;
 pub mod linting;
+#[cfg(feature = "lsp")]
+pub mod lsp;
 mod mask;
+mod normalizer;
 pub mod parsers;
 pub mod patterns;
 mod punctuation;
@@ -19,56 +25,48 @@ mod token;
 mod vec_ext;
 mod word_metadata;
 
-use std::collections::VecDeque;
-
 pub use char_string::{CharString, CharStringExt};
+pub use context_snippet::ContextSnippet;
 pub use document::Document;
 use linting::Lint;
 pub use mask::{Mask, Masker};
+pub use normalizer::{Normalizations, Normalizer};
 pub use punctuation::{Punctuation, Quote};
 pub use span::Span;
-pub use spell::{Dictionary, FstDictionary, FullDictionary, MergedDictionary};
+pub use spell::{
+    suggest_correct_spelling, suggest_correct_spelling_context, suggest_correct_spelling_str,
+    suggest_correct_spelling_with, BigramModel, CachedDictionary, Dictionary, DictionaryDiff,
+    DictionaryError, DistancePolicy, FstDictionary, FullDictionary, KeyboardLayout, Language,
+    MergedDictionary, RankingStrategy, SuggestionOptions,
+};
 pub use sync::Lrc;
 pub use title_case::{make_title_case, make_title_case_str};
 pub use token::{FatToken, Token, TokenKind, TokenStringExt};
 pub use vec_ext::VecExt;
-pub use word_metadata::{AdverbData, ConjunctionData, NounData, Tense, VerbData, WordMetadata};
+pub use word_metadata::{
+    AdjectiveData, AdverbData, ConjunctionData, NounData, PartOfSpeech, Tense, VerbData,
+    WordMetadata,
+};
 
 /// A utility function that removes overlapping lints in a vector,
 /// keeping the more important ones.
 ///
+/// This is [`linting::resolve_overlaps`] in place, so it follows the same
+/// rules.
+/// [`LintGroup`](linting::LintGroup) already resolves overlaps, so its
+/// output doesn't need another pass.
+///
 /// Note: this function will change the ordering of the lints.
+#[deprecated(note = "use `linting::resolve_overlaps` instead")]
 pub fn remove_overlaps(lints: &mut Vec<Lint>) {
-    if lints.len() < 2 {
-        return;
-    }
-
-    lints.sort_by_key(|l| l.span.start);
-
-    let mut remove_indices = VecDeque::new();
-
-    for i in 0..lints.len() - 1 {
-        let cur = &lints[i];
-        let next = &lints[i + 1];
-
-        if cur.span.overlaps_with(next.span) {
-            // Remember, lower priority means higher importance.
-            if next.priority < cur.priority {
-                remove_indices.push_back(i);
-            } else {
-                remove_indices.push_back(i + 1);
-            }
-        }
-    }
-
-    lints.remove_indices(remove_indices);
+    *lints = linting::resolve_overlaps(std::mem::take(lints));
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         linting::{LintGroup, LintGroupConfig, Linter},
-        remove_overlaps, Document, FstDictionary,
+        Document, FstDictionary,
     };
 
     #[test]
@@ -82,11 +80,7 @@ mod tests {
         };
         let mut linter = LintGroup::new(lint_config, FstDictionary::curated());
 
-        let mut lints = linter.lint(&doc);
-
-        dbg!(&lints);
-        remove_overlaps(&mut lints);
-        dbg!(&lints);
+        let lints = linter.lint(&doc);
 
         assert_eq!(lints.len(), 3);
     }
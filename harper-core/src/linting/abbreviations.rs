@@ -0,0 +1,309 @@
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{CharStringExt, Document, Span, Token, TokenKind};
+
+lazy_static! {
+    static ref CURATED: AbbreviationTable =
+        AbbreviationTable::parse(include_str!("../../abbreviations.tsv"));
+}
+
+/// How formal the text being checked is supposed to be.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WritingStyle {
+    Formal,
+    #[default]
+    Informal,
+}
+
+/// Abbreviations, like "e.g.", and what they stand for.
+///
+/// Abbreviations are matched without regard to case.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbbreviationTable {
+    expansions: HashMap<String, String>,
+}
+
+impl AbbreviationTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The table of common Latin abbreviations included in the Harper binary.
+    pub fn curated() -> Self {
+        CURATED.clone()
+    }
+
+    /// Parse a table of abbreviations, one `abbreviation<TAB>expansion` pair
+    /// per line.
+    ///
+    /// Blank lines and lines that fail to parse are skipped.
+    pub fn parse(source: &str) -> Self {
+        let mut table = Self::new();
+
+        for line in source.lines() {
+            if let Some((abbreviation, expansion)) = line.split_once('\t') {
+                table.add(abbreviation.trim(), expansion.trim());
+            }
+        }
+
+        table
+    }
+
+    /// Add an abbreviation, including any periods (e.g. `"approx."`),
+    /// replacing any previous expansion.
+    pub fn add(&mut self, abbreviation: &str, expansion: &str) {
+        self.expansions
+            .insert(abbreviation.to_lowercase(), expansion.to_string());
+    }
+
+    fn get(&self, abbreviation: &str) -> Option<&str> {
+        self.expansions.get(abbreviation).map(String::as_str)
+    }
+}
+
+/// Suggest spelling out abbreviations like "e.g." in formal writing, using
+/// the curated [`AbbreviationTable`].
+///
+/// Nothing is flagged for [`WritingStyle::Informal`].
+pub fn abbreviation_check(document: &Document, style: WritingStyle) -> Vec<Lint> {
+    lint_with_table(document, &CURATED, style)
+}
+
+/// Looks for abbreviations, like "e.g.", that should be spelled out in
+/// formal writing.
+#[derive(Debug, Clone)]
+pub struct Abbreviations {
+    table: AbbreviationTable,
+    style: WritingStyle,
+}
+
+impl Abbreviations {
+    pub fn new(style: WritingStyle) -> Self {
+        Self::with_table(AbbreviationTable::curated(), style)
+    }
+
+    /// Use a custom table instead of the curated one.
+    pub fn with_table(table: AbbreviationTable, style: WritingStyle) -> Self {
+        Self { table, style }
+    }
+}
+
+/// Checks formal writing, since informal writing is never flagged.
+impl Default for Abbreviations {
+    fn default() -> Self {
+        Self::new(WritingStyle::Formal)
+    }
+}
+
+impl Rule for Abbreviations {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        lint_with_table(document, &self.table, self.style)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
+    fn description(&self) -> &'static str {
+        "Abbreviations like \"e.g.\" and \"i.e.\" can be unclear to some readers. In formal writing, this rule suggests spelling them out."
+    }
+}
+
+fn lint_with_table(
+    document: &Document,
+    table: &AbbreviationTable,
+    style: WritingStyle,
+) -> Vec<Lint> {
+    if style == WritingStyle::Informal {
+        return Vec::new();
+    }
+
+    let tokens = document.get_tokens();
+
+    let mut output = Vec::new();
+
+    for (idx, token) in tokens.iter().enumerate() {
+        if !token.kind.is_word() {
+            continue;
+        }
+
+        let word = document.get_span_content_str(token.span).to_lowercase();
+
+        // Abbreviations the dictionary knows, like "e.g.", are a single token,
+        // but others are followed by a separate period.
+        let found = table
+            .get(&word)
+            .map(|expansion| (token.span, expansion, idx + 1));
+        let found = found.or_else(|| {
+            let period = tokens.get(idx + 1)?;
+
+            if !period.kind.is_period() {
+                return None;
+            }
+
+            table.get(&format!("{word}.")).map(|expansion| {
+                (
+                    Span::new(token.span.start, period.span.end),
+                    expansion,
+                    idx + 2,
+                )
+            })
+        });
+
+        let Some((span, expansion, next_idx)) = found else {
+            continue;
+        };
+
+        let abbreviation = document.get_span_content(span);
+
+        let mut replacement = expansion
+            .chars()
+            .collect::<Vec<_>>()
+            .with_case_of(abbreviation)
+            .to_vec();
+
+        // The abbreviation's period may also have ended the sentence, in
+        // which case it needs to stay.
+        if abbreviation.last() == Some(&'.') && ends_sentence(document, &tokens[next_idx..]) {
+            replacement.push('.');
+        }
+
+        output.push(Lint {
+            span,
+            lint_kind: LintKind::Readability,
+            suggestions: vec![Suggestion::ReplaceWith(replacement)],
+            message: format!(
+                "In formal writing, spell out “{}” as “{expansion}”.",
+                abbreviation.iter().collect::<String>()
+            ),
+            severity: LintKind::Readability.default_severity(),
+            ..Default::default()
+        });
+    }
+
+    output
+}
+
+/// Whether a period followed by `rest` ends its sentence.
+///
+/// Harper doesn't treat known abbreviations as the end of a sentence, so this
+/// guesses from what comes next: the end of a line, or a capitalized word
+/// that isn't a name.
+fn ends_sentence(document: &Document, rest: &[Token]) -> bool {
+    let Some(next) = rest.iter().find(|t| !matches!(t.kind, TokenKind::Space(_))) else {
+        return true;
+    };
+
+    match next.kind {
+        TokenKind::Newline(_) | TokenKind::ParagraphBreak => true,
+        TokenKind::Word(metadata) => {
+            !metadata.is_proper_noun()
+                && document
+                    .get_span_content(next.span)
+                    .first()
+                    .is_some_and(|c| c.is_uppercase())
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{abbreviation_check, AbbreviationTable, Abbreviations, WritingStyle};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn expands_eg_before_comma() {
+        assert_suggestion_result(
+            "Bring fruit, e.g., apples.",
+            Abbreviations::default(),
+            "Bring fruit, for example, apples.",
+        );
+    }
+
+    #[test]
+    fn expands_ie() {
+        assert_suggestion_result(
+            "Use the small one (i.e. the red one).",
+            Abbreviations::default(),
+            "Use the small one (that is the red one).",
+        );
+    }
+
+    #[test]
+    fn keeps_sentence_ending_period() {
+        assert_suggestion_result(
+            "We need apples, pears, etc. Then we can go.",
+            Abbreviations::default(),
+            "We need apples, pears, and so on. Then we can go.",
+        );
+    }
+
+    #[test]
+    fn keeps_period_at_end_of_text() {
+        assert_suggestion_result(
+            "We need apples, pears, etc.",
+            Abbreviations::default(),
+            "We need apples, pears, and so on.",
+        );
+    }
+
+    #[test]
+    fn expands_separate_period() {
+        let mut table = AbbreviationTable::new();
+        table.add("approx.", "approximately");
+
+        assert_suggestion_result(
+            "It weighs approx. two tons.",
+            Abbreviations::with_table(table, WritingStyle::Formal),
+            "It weighs approximately two tons.",
+        );
+    }
+
+    #[test]
+    fn matches_capitalized_abbreviations() {
+        assert_suggestion_result(
+            "E.g., the red one.",
+            Abbreviations::default(),
+            "For example, the red one.",
+        );
+    }
+
+    #[test]
+    fn informal_is_never_flagged() {
+        assert_lint_count(
+            "Bring fruit, e.g., apples, pears, etc.",
+            Abbreviations::new(WritingStyle::Informal),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_ordinary_words() {
+        assert_lint_count("Et cetera is two words.", Abbreviations::default(), 0);
+    }
+
+    #[test]
+    fn table_can_be_extended() {
+        let mut table = AbbreviationTable::curated();
+        table.add("a.k.a.", "also known as");
+
+        assert_suggestion_result(
+            "Robert, a.k.a. Bob, is here.",
+            Abbreviations::with_table(table, WritingStyle::Formal),
+            "Robert, also known as Bob, is here.",
+        );
+    }
+
+    #[test]
+    fn free_function_respects_style() {
+        let document = Document::new_plain_english_curated("Bring fruit, e.g., apples.");
+
+        assert_eq!(abbreviation_check(&document, WritingStyle::Formal).len(), 1);
+        assert!(abbreviation_check(&document, WritingStyle::Informal).is_empty());
+    }
+}
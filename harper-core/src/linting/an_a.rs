@@ -2,14 +2,14 @@ use std::borrow::Cow;
 
 use itertools::Itertools;
 
-use crate::linting::{Lint, LintKind, Linter, Suggestion};
+use crate::linting::{Lint, LintKind, Rule, Suggestion};
 use crate::{Document, TokenStringExt};
 
 #[derive(Debug, Default)]
 pub struct AnA;
 
-impl Linter for AnA {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for AnA {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
         for chunk in document.iter_chunks() {
@@ -32,8 +32,8 @@ impl Linter for AnA {
                     .unwrap_or(chars_second);
 
                 let is_a_an = match chars_first {
-                    ['a'] => Some(true),
-                    ['a', 'n'] => Some(false),
+                    ['a' | 'A'] => Some(true),
+                    ['a' | 'A', 'n' | 'N'] => Some(false),
                     _ => None,
                 };
 
@@ -41,12 +41,22 @@ impl Linter for AnA {
                     continue;
                 };
 
+                // A capital "A" might be the letter itself, as in "A or B".
+                // Articles are never followed by these kinds of words.
+                if chars_first[0].is_uppercase()
+                    && (second.kind.is_conjunction()
+                        || second.kind.is_linking_verb()
+                        || second.kind.as_word().is_some_and(|m| m.preposition))
+                {
+                    continue;
+                }
+
                 let should_be_a_an = !starts_with_vowel(chars_second);
 
                 if a_an != should_be_a_an {
                     let replacement = match a_an {
-                        true => vec!['a', 'n'],
-                        false => vec!['a'],
+                        true => vec![chars_first[0], 'n'],
+                        false => vec![chars_first[0]],
                     };
 
                     lints.push(Lint {
@@ -55,6 +65,8 @@ impl Linter for AnA {
                         suggestions: vec![Suggestion::ReplaceWith(replacement)],
                         message: "Incorrect indefinite article.".to_string(),
                         priority: 31,
+                        severity: LintKind::Miscellaneous.default_severity(),
+                        ..Default::default()
                     })
                 }
             }
@@ -63,6 +75,10 @@ impl Linter for AnA {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "A rule that looks for incorrect indefinite articles. For example, \"this is an mule\" would be flagged as incorrect."
     }
@@ -108,11 +124,32 @@ fn starts_with_vowel(word: &[char]) -> bool {
         return false;
     }
 
-    if matches!(word, |['h', 'o', 'u', 'r', ..]| ['h', 'o', 'n', ..]
-        | ['u', 'n', 'i', 'n' | 'm', ..]
-        | ['u', 'n', 'a' | 'u', ..]
-        | ['h', 'e', 'r', 'b', ..]
-        | ['u', 'r', 'b', ..])
+    if matches!(word, |['h', 'o', 'u', 'r', ..]| [
+        'h',
+        'o',
+        'n',
+        'e',
+        's',
+        't',
+        ..
+    ] | [
+        'u',
+        'n',
+        'i',
+        'n' | 'm',
+        ..
+    ] | [
+        'u',
+        'n',
+        'a' | 'u',
+        ..
+    ] | [
+        'h',
+        'e',
+        'r',
+        'b',
+        ..
+    ] | ['u', 'r', 'b', ..])
     {
         return true;
     }
@@ -178,7 +215,7 @@ fn starts_with_vowel(word: &[char]) -> bool {
 #[cfg(test)]
 mod tests {
     use super::AnA;
-    use crate::linting::tests::assert_lint_count;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
 
     #[test]
     fn detects_html_as_vowel() {
@@ -249,4 +286,43 @@ mod tests {
     fn disallows_uppercase_consonants() {
         assert_lint_count("not an Crash", AnA, 1);
     }
+
+    #[test]
+    fn capitalized_article() {
+        assert_suggestion_result("A apple a day.", AnA, "An apple a day.");
+    }
+
+    #[test]
+    fn capitalized_an() {
+        assert_suggestion_result("An house on a hill.", AnA, "A house on a hill.");
+    }
+
+    #[test]
+    fn letter_a() {
+        assert_lint_count("A or B is fine, but A is better.", AnA, 0);
+    }
+
+    #[test]
+    fn silent_h() {
+        assert_lint_count("It took an hour to find an honest man.", AnA, 0);
+        assert_lint_count("It took a hour.", AnA, 1);
+    }
+
+    #[test]
+    fn pronounced_h() {
+        assert_lint_count("Bring a honey jar from a Honda.", AnA, 0);
+        assert_lint_count("It is an honor.", AnA, 0);
+    }
+
+    #[test]
+    fn acronyms() {
+        assert_lint_count("He is an FBI agent.", AnA, 0);
+        assert_lint_count("He is a FBI agent.", AnA, 1);
+    }
+
+    #[test]
+    fn u_as_you() {
+        assert_lint_count("She went to a university.", AnA, 0);
+        assert_lint_count("She went to an university.", AnA, 1);
+    }
 }
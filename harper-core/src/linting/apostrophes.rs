@@ -0,0 +1,226 @@
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::token::TokenStringExt;
+use crate::{CharString, CharStringExt, Document, Token};
+
+/// Contractions that are often written without their apostrophe, and how
+/// they should be spelled.
+const CONTRACTIONS: &[(&str, &str)] = &[
+    ("arent", "aren't"),
+    ("cant", "can't"),
+    ("couldnt", "couldn't"),
+    ("couldve", "could've"),
+    ("didnt", "didn't"),
+    ("doesnt", "doesn't"),
+    ("dont", "don't"),
+    ("hadnt", "hadn't"),
+    ("hasnt", "hasn't"),
+    ("havent", "haven't"),
+    ("im", "I'm"),
+    ("isnt", "isn't"),
+    ("ive", "I've"),
+    ("mustnt", "mustn't"),
+    ("shouldnt", "shouldn't"),
+    ("shouldve", "should've"),
+    ("thats", "that's"),
+    ("theyll", "they'll"),
+    ("theyre", "they're"),
+    ("theyve", "they've"),
+    ("wasnt", "wasn't"),
+    ("werent", "weren't"),
+    ("weve", "we've"),
+    ("whats", "what's"),
+    ("wont", "won't"),
+    ("wouldnt", "wouldn't"),
+    ("wouldve", "would've"),
+    ("youll", "you'll"),
+    ("youre", "you're"),
+    ("youve", "you've"),
+];
+
+/// Entries of [`CONTRACTIONS`] that are also words in their own right ("the
+/// cant of politicians", "as was her wont"), so are only flagged right after
+/// a subject pronoun.
+const REAL_WORDS: &[&str] = &["cant", "wont"];
+
+const SUBJECT_PRONOUNS: &[&str] = &["i", "you", "he", "she", "it", "we", "they"];
+
+/// Words that can follow "it's" but never the possessive "its".
+const AFTER_IT_IS: &[&str] = &["been", "not", "going", "gonna", "too", "very", "about"];
+
+/// Flag contractions that are missing their apostrophe, like "dont", and
+/// "its" where "it's" was meant.
+///
+/// "its" is only flagged when the next word can't follow a possessive, like
+/// "a" or "been", so "its owner" is left alone.
+pub fn apostrophe_check(document: &Document) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for chunk in document.iter_chunks() {
+        let tokens: Vec<&Token> = chunk
+            .iter()
+            .filter(|token| !token.kind.is_whitespace())
+            .collect();
+
+        for (idx, token) in tokens.iter().enumerate() {
+            if !token.kind.is_word() {
+                continue;
+            }
+
+            let word = document.get_span_content(token.span);
+            let normalized = lowercase(word);
+
+            let neighbor = |idx: Option<usize>| {
+                idx.and_then(|idx| tokens.get(idx))
+                    .filter(|token| token.kind.is_word())
+            };
+            let prev = neighbor(idx.checked_sub(1))
+                .map(|token| lowercase(document.get_span_content(token.span)));
+            let prev = prev.as_deref();
+            let next = neighbor(Some(idx + 1));
+
+            let replacement = if matches(&normalized, "its") {
+                let Some(next) = next else {
+                    continue;
+                };
+
+                let next_word = lowercase(document.get_span_content(next.span));
+                let is_article = next.kind.as_word().is_some_and(|meta| meta.is_article());
+
+                if !is_article && !AFTER_IT_IS.iter().any(|w| matches(&next_word, w)) {
+                    continue;
+                }
+
+                "it's"
+            } else {
+                let Some((contraction, replacement)) = CONTRACTIONS
+                    .iter()
+                    .find(|(contraction, _)| matches(&normalized, contraction))
+                else {
+                    continue;
+                };
+
+                if REAL_WORDS.contains(contraction)
+                    && !prev.is_some_and(|prev| SUBJECT_PRONOUNS.iter().any(|p| matches(prev, p)))
+                {
+                    continue;
+                }
+
+                replacement
+            };
+
+            let replacement: CharString = replacement.chars().collect();
+
+            output.push(Lint {
+                span: token.span,
+                lint_kind: LintKind::Spelling,
+                suggestions: vec![Suggestion::ReplaceWith(
+                    replacement.with_case_of(word).to_vec(),
+                )],
+                message: format!(
+                    "This looks like the contraction “{}”, which needs an apostrophe.",
+                    replacement.iter().collect::<String>()
+                ),
+                // Should win over the spell checker, which may suggest an
+                // unrelated word.
+                priority: 31,
+                severity: LintKind::Spelling.default_severity(),
+                ..Default::default()
+            });
+        }
+    }
+
+    output
+}
+
+/// Looks for missing apostrophes in contractions, like "dont" or "its a".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Apostrophes;
+
+impl Rule for Apostrophes {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        apostrophe_check(document)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Spelling
+    }
+
+    fn description(&self) -> &'static str {
+        "Contractions like \"don't\" need an apostrophe where letters were left out. This rule finds ones that are missing it."
+    }
+}
+
+fn lowercase(word: &[char]) -> CharString {
+    word.iter().flat_map(|c| c.to_lowercase()).collect()
+}
+
+fn matches(normalized: &[char], word: &str) -> bool {
+    normalized.iter().copied().eq(word.chars())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apostrophe_check, Apostrophes};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn dont() {
+        assert_suggestion_result("I dont know.", Apostrophes, "I don't know.");
+    }
+
+    #[test]
+    fn keeps_capitalization() {
+        assert_suggestion_result("Dont do that.", Apostrophes, "Don't do that.");
+        assert_suggestion_result("im here.", Apostrophes, "I'm here.");
+    }
+
+    #[test]
+    fn cant_and_wont_after_pronoun() {
+        assert_suggestion_result("We cant stay.", Apostrophes, "We can't stay.");
+        assert_suggestion_result("They wont mind.", Apostrophes, "They won't mind.");
+    }
+
+    #[test]
+    fn allows_cant_and_wont_as_nouns() {
+        assert_lint_count("She rose early, as was her wont.", Apostrophes, 0);
+        assert_lint_count("The cant of politicians is tiring.", Apostrophes, 0);
+    }
+
+    #[test]
+    fn its_a() {
+        assert_suggestion_result("Its a nice day.", Apostrophes, "It's a nice day.");
+    }
+
+    #[test]
+    fn its_been() {
+        assert_suggestion_result(
+            "I think its been a while.",
+            Apostrophes,
+            "I think it's been a while.",
+        );
+    }
+
+    #[test]
+    fn allows_possessive_its() {
+        assert_lint_count("The dog wagged its tail.", Apostrophes, 0);
+        assert_lint_count("Its owner said it's fine.", Apostrophes, 0);
+        assert_lint_count("The company and its.", Apostrophes, 0);
+    }
+
+    #[test]
+    fn allows_correct_contractions() {
+        assert_lint_count(
+            "I don't think they're home, and we can't wait.",
+            Apostrophes,
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_each_contraction() {
+        let document = Document::new_plain_english_curated("It doesnt matter, thats fine.");
+
+        assert_eq!(apostrophe_check(&document).len(), 2);
+    }
+}
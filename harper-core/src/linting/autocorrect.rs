@@ -0,0 +1,237 @@
+use hashbrown::HashSet;
+
+use super::{apply_lints, Lint, LintKind, Linter, SpellCheck, Suggestion};
+use crate::{CharString, CharStringExt, Dictionary, DistancePolicy, Document, Span};
+
+/// Controls which spelling mistakes [`autocorrect`] fixes on its own.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutocorrectOptions {
+    min_confidence: f32,
+    fix_capitalized: bool,
+}
+
+impl Default for AutocorrectOptions {
+    fn default() -> Self {
+        Self {
+            min_confidence: 0.8,
+            fix_capitalized: false,
+        }
+    }
+}
+
+impl AutocorrectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only fix words whose correction has at least this confidence, computed
+    /// as `1 - edit_distance / word_len`.
+    /// Defaults to `0.8`, so a five letter word may have a single typo.
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Whether to fix words that start with a capital letter, which are often
+    /// names the dictionary doesn't know.
+    /// Off by default.
+    pub fn with_fix_capitalized(mut self, fix_capitalized: bool) -> Self {
+        self.fix_capitalized = fix_capitalized;
+        self
+    }
+}
+
+/// Fix the spelling mistakes in `source` that have exactly one likely
+/// correction, returning the corrected text and the spelling lints that were
+/// left for a human to resolve.
+///
+/// A mistake is only fixed if a single word is closer to it than any other,
+/// and that word is at least as confident as
+/// [`AutocorrectOptions::with_min_confidence`] requires.
+/// The spans of the returned lints refer to the corrected text.
+///
+/// `source` is parsed as Markdown, so code is left alone.
+pub fn autocorrect(
+    source: &str,
+    dict: &dyn Dictionary,
+    options: &AutocorrectOptions,
+) -> (String, Vec<Lint>) {
+    let document = Document::new_markdown(source, &dict);
+    let lints = SpellCheck::new(dict).lint(&document);
+
+    let mut fixes = Vec::new();
+    let mut unresolved = Vec::new();
+
+    for lint in lints {
+        if lint.lint_kind == LintKind::UnknownWord {
+            unresolved.push(lint);
+            continue;
+        }
+
+        if lint.lint_kind != LintKind::Spelling {
+            continue;
+        }
+
+        let word = document.get_span_content(lint.span);
+
+        let fix = if options.fix_capitalized || !word.first().is_some_and(|c| c.is_uppercase()) {
+            sole_correction(word, dict, options.min_confidence)
+        } else {
+            None
+        };
+
+        match fix {
+            Some(fix) => fixes.push(Lint {
+                suggestions: vec![Suggestion::ReplaceWith(fix.with_case_of(word).to_vec())],
+                ..lint
+            }),
+            None => unresolved.push(lint),
+        }
+    }
+
+    // Both are applied to `source`, which may not be normalized.
+    document.normalizations().restore_spans(&mut fixes);
+    document.normalizations().restore_spans(&mut unresolved);
+
+    // Fixes that came earlier change the length of the text, so shift the
+    // remaining lints to match.
+    for lint in unresolved.iter_mut() {
+        let shift: isize = fixes
+            .iter()
+            .filter(|fix| fix.span.end <= lint.span.start)
+            .map(|fix| match &fix.suggestions[0] {
+                Suggestion::ReplaceWith(chars) => chars.len() as isize - fix.span.len() as isize,
+                Suggestion::Remove => -(fix.span.len() as isize),
+            })
+            .sum();
+
+        lint.span = Span::new(
+            lint.span.start.saturating_add_signed(shift),
+            lint.span.end.saturating_add_signed(shift),
+        );
+    }
+
+    (apply_lints(source, &fixes), unresolved)
+}
+
+/// The only word closest to `word`, if it is confident enough.
+fn sole_correction(
+    word: &[char],
+    dict: &dyn Dictionary,
+    min_confidence: f32,
+) -> Option<CharString> {
+    let query = word.to_lower();
+    let max_distance = DistancePolicy::ByLength.max_distance(word.len());
+
+    let mut best_distance = u8::MAX;
+    let mut best = HashSet::new();
+
+    for (candidate, distance, _) in dict.fuzzy_match_iter(&query, max_distance) {
+        if distance < best_distance {
+            best_distance = distance;
+            best.clear();
+        }
+
+        if distance == best_distance {
+            best.insert(candidate.to_lower());
+        }
+    }
+
+    if best.len() != 1 {
+        return None;
+    }
+
+    let confidence = 1.0 - best_distance as f32 / word.len() as f32;
+
+    if confidence < min_confidence {
+        return None;
+    }
+
+    best.into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{autocorrect, AutocorrectOptions};
+    use crate::FstDictionary;
+
+    fn run(source: &str, options: AutocorrectOptions) -> (String, usize) {
+        let dict = FstDictionary::curated();
+        let (text, lints) = autocorrect(source, &*dict, &options);
+
+        (text, lints.len())
+    }
+
+    #[test]
+    fn fixes_unambiguous_typos() {
+        assert_eq!(
+            run(
+                "We climbed the mountein at dawn.",
+                AutocorrectOptions::new()
+            ),
+            ("We climbed the mountain at dawn.".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn leaves_ambiguous_typos() {
+        let (text, unresolved) = run("Hello wrld.", AutocorrectOptions::new());
+
+        assert_eq!(text, "Hello wrld.");
+        assert_eq!(unresolved, 1);
+    }
+
+    #[test]
+    fn skips_capitalized_words_by_default() {
+        assert_eq!(
+            run("Mountein air is fresh.", AutocorrectOptions::new()),
+            ("Mountein air is fresh.".to_string(), 1)
+        );
+        assert_eq!(
+            run(
+                "Mountein air is fresh.",
+                AutocorrectOptions::new().with_fix_capitalized(true)
+            ),
+            ("Mountain air is fresh.".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn threshold_is_respected() {
+        assert_eq!(
+            run(
+                "It is a mountein.",
+                AutocorrectOptions::new().with_min_confidence(0.9)
+            ),
+            ("It is a mountein.".to_string(), 1)
+        );
+    }
+
+    #[test]
+    fn fixes_land_in_decomposed_text() {
+        let (text, _) = run(
+            "The cafe\u{301} is up the mountein.",
+            AutocorrectOptions::new(),
+        );
+
+        assert_eq!(text, "The cafe\u{301} is up the mountain.");
+    }
+
+    #[test]
+    fn unresolved_spans_follow_corrected_text() {
+        let dict = FstDictionary::curated();
+        let (text, lints) = autocorrect(
+            "The mountein and the wrld.",
+            &*dict,
+            &AutocorrectOptions::new(),
+        );
+
+        let chars: Vec<char> = text.chars().collect();
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            lints[0].span.get_content(&chars).iter().collect::<String>(),
+            "wrld"
+        );
+    }
+}
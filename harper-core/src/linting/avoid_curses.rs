@@ -1,11 +1,11 @@
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Rule};
 use crate::{Document, TokenStringExt};
 
 #[derive(Debug, Default)]
 pub struct AvoidCurses;
 
-impl Linter for AvoidCurses {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for AvoidCurses {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         document
             .iter_words()
             .filter(|t| t.kind.is_swear())
@@ -15,10 +15,16 @@ impl Linter for AvoidCurses {
                 suggestions: vec![],
                 message: "Try to avoid offensive language.".to_string(),
                 priority: 63,
+                severity: LintKind::Miscellaneous.default_severity(),
+                ..Default::default()
             })
             .collect()
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "A rule that looks for common offensive language."
     }
@@ -42,9 +42,15 @@ impl PatternLinter for BoringWords {
                 matched_word
             ),
             priority: 127,
+            severity: LintKind::Enhancement.default_severity(),
+            ..Default::default()
         }
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Enhancement
+    }
+
     fn description(&self) -> &'static str {
         "This rule looks for particularly boring or overused words. Using varied language is an easy way to keep a reader's attention."
     }
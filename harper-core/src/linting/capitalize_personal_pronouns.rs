@@ -1,13 +1,13 @@
 use crate::{NounData, TokenKind, TokenStringExt, WordMetadata};
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 
 /// A super-simple linter that makes sure you capitalize "I".
 #[derive(Default)]
 pub struct CapitalizePersonalPronouns;
 
-impl Linter for CapitalizePersonalPronouns {
-    fn lint(&mut self, document: &crate::Document) -> Vec<Lint> {
+impl Rule for CapitalizePersonalPronouns {
+    fn check(&self, document: &crate::Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
         for tok in document.iter_words() {
@@ -27,6 +27,8 @@ impl Linter for CapitalizePersonalPronouns {
                         suggestions: vec![Suggestion::ReplaceWith(vec!['I'])],
                         message: "First-person singular pronouns must be capitalized.".to_string(),
                         priority: 31,
+                        severity: LintKind::Capitalization.default_severity(),
+                        ..Default::default()
                     });
                 }
             }
@@ -35,6 +37,10 @@ impl Linter for CapitalizePersonalPronouns {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Capitalization
+    }
+
     fn description(&self) -> &'static str {
         "Forgetting to capitalize personal pronouns, like \"I\" or \"I'm\" is one of the most common errors. This rule helps with that."
     }
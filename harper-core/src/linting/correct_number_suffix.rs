@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 use crate::token::{NumberSuffix, TokenStringExt};
 use crate::{Document, Span, TokenKind};
 
@@ -6,8 +6,8 @@ use crate::{Document, Span, TokenKind};
 #[derive(Debug, Clone, Copy, Default)]
 pub struct CorrectNumberSuffix;
 
-impl Linter for CorrectNumberSuffix {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for CorrectNumberSuffix {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut output = Vec::new();
 
         for number_tok in document.iter_numbers() {
@@ -22,6 +22,7 @@ impl Linter for CorrectNumberSuffix {
                             message: "This number needs a different suffix to sound right."
                                 .to_string(),
                             suggestions: vec![Suggestion::ReplaceWith(correct_suffix.to_chars())],
+                            severity: LintKind::Miscellaneous.default_severity(),
                             ..Default::default()
                         })
                     }
@@ -32,6 +33,10 @@ impl Linter for CorrectNumberSuffix {
         output
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "When making quick edits, it is common for authors to change the value of a number without changing its suffix. This rule looks for these cases, for example: \"2st\"."
     }
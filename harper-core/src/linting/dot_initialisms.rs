@@ -49,9 +49,15 @@ impl PatternLinter for DotInitialisms {
             suggestions: vec![Suggestion::ReplaceWith(correction.chars().collect())],
             message: "Initialisms should have dot-separated letters.".to_owned(),
             priority: 63,
+            severity: LintKind::Formatting.default_severity(),
+            ..Default::default()
         }
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
     fn description(&self) -> &'static str {
         "Ensures common initialisms (like \"i.e.\") are properly dot-separated."
     }
@@ -0,0 +1,134 @@
+use super::{Lint, LintKind, Rule};
+use crate::token::TokenStringExt;
+use crate::{Document, Punctuation, Span, Token, TokenKind};
+
+/// Words that negate the clause they appear in, besides contractions ending
+/// in "n't".
+///
+/// "Nor" is left out, since it pairs with "neither" and "not" on purpose.
+const NEGATIVES: &[&str] = &[
+    "not", "no", "never", "nothing", "nobody", "none", "nowhere", "neither", "cannot",
+];
+
+/// Flag clauses with more than one negative, like "I don't need no help",
+/// from the first negative to the second.
+///
+/// Clauses are delimited by sentence boundaries and by commas, semicolons,
+/// colons and dashes, so "No, I don't" isn't flagged.
+/// No suggestion is given, since there is more than one way to fix a double
+/// negative.
+pub fn double_negative_check(document: &Document) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for sentence in document.iter_sentences() {
+        for clause in sentence.split(|token| is_clause_break(token.kind)) {
+            let mut negatives = clause
+                .iter()
+                .filter(|token| token.kind.is_word() && is_negative(document, token));
+
+            let (Some(first), Some(second)) = (negatives.next(), negatives.next()) else {
+                continue;
+            };
+
+            output.push(Lint {
+                span: Span::new(first.span.start, second.span.end),
+                lint_kind: LintKind::Readability,
+                message: "This clause has two negatives, which can cancel each other out. Consider keeping only one."
+                    .to_string(),
+                severity: LintKind::Readability.default_severity(),
+                ..Default::default()
+            });
+        }
+    }
+
+    output
+}
+
+/// Looks for double negatives, like "I don't need no help".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DoubleNegatives;
+
+impl Rule for DoubleNegatives {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        double_negative_check(document)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
+    fn description(&self) -> &'static str {
+        "Two negatives in one clause, like \"don't need no\", read poorly and can be taken to mean the opposite. This rule flags clauses with more than one negative."
+    }
+}
+
+fn is_clause_break(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Punctuation(
+            Punctuation::Comma
+                | Punctuation::Semicolon
+                | Punctuation::Colon
+                | Punctuation::EnDash
+                | Punctuation::EmDash
+        )
+    )
+}
+
+fn is_negative(document: &Document, token: &Token) -> bool {
+    let word: String = document
+        .get_span_content(token.span)
+        .iter()
+        .map(|c| match c {
+            '’' | '‘' => '\'',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect();
+
+    NEGATIVES.contains(&word.as_str()) || word.ends_with("n't")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{double_negative_check, DoubleNegatives};
+    use crate::linting::tests::assert_lint_count;
+    use crate::{Document, Span};
+
+    #[test]
+    fn flags_contraction_and_no() {
+        assert_lint_count("I don't need no help.", DoubleNegatives, 1);
+    }
+
+    #[test]
+    fn flags_not_and_nothing() {
+        assert_lint_count("We did not see nothing.", DoubleNegatives, 1);
+        assert_lint_count("She never told nobody.", DoubleNegatives, 1);
+    }
+
+    #[test]
+    fn spans_both_negatives() {
+        let text = "I can’t find it nowhere.";
+        let document = Document::new_plain_english_curated(text);
+        let lints = double_negative_check(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span, Span::new(2, 23));
+    }
+
+    #[test]
+    fn allows_single_negatives() {
+        assert_lint_count("I don't need any help.", DoubleNegatives, 0);
+        assert_lint_count("There is nothing to see here.", DoubleNegatives, 0);
+    }
+
+    #[test]
+    fn scopes_to_clauses() {
+        assert_lint_count("No, I don't think so.", DoubleNegatives, 0);
+        assert_lint_count("It isn't here. No one knows.", DoubleNegatives, 0);
+    }
+
+    #[test]
+    fn allows_neither_nor() {
+        assert_lint_count("It is neither red nor blue.", DoubleNegatives, 0);
+    }
+}
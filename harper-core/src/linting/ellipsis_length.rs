@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 use crate::TokenStringExt;
 
 /// A linter that checks that an ellipsis doesn't contain too many periods (or
@@ -8,8 +8,8 @@ use crate::TokenStringExt;
 #[derive(Debug, Default)]
 pub struct EllipsisLength;
 
-impl Linter for EllipsisLength {
-    fn lint(&mut self, document: &crate::Document) -> Vec<super::Lint> {
+impl Rule for EllipsisLength {
+    fn check(&self, document: &crate::Document) -> Vec<super::Lint> {
         let mut lints = Vec::new();
 
         for tok in document.iter_ellipsiss() {
@@ -29,6 +29,8 @@ impl Linter for EllipsisLength {
                     suggestions: vec![Suggestion::ReplaceWith(vec!['.', '.', '.'])],
                     message: "Horizontal ellipsis must have 3 dots.".to_string(),
                     priority: 31,
+                    severity: LintKind::Formatting.default_severity(),
+                    ..Default::default()
                 })
             }
         }
@@ -36,6 +38,10 @@ impl Linter for EllipsisLength {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
     fn description(&self) -> &'static str {
         "Make sure you have the correct number of dots in your ellipsis."
     }
@@ -0,0 +1,209 @@
+use unicode_blocks::{
+    find_unicode_block, BASIC_LATIN, LATIN_1_SUPPLEMENT, LATIN_EXTENDED_A,
+    LATIN_EXTENDED_ADDITIONAL, LATIN_EXTENDED_B,
+};
+
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{Document, TokenStringExt};
+
+/// Characters from other scripts that look the same as a Latin letter, and
+/// the letter they look like.
+const CONFUSABLES: &[(char, char)] = &[
+    // Cyrillic
+    ('а', 'a'),
+    ('е', 'e'),
+    ('о', 'o'),
+    ('р', 'p'),
+    ('с', 'c'),
+    ('у', 'y'),
+    ('х', 'x'),
+    ('і', 'i'),
+    ('ј', 'j'),
+    ('ѕ', 's'),
+    ('һ', 'h'),
+    ('ԁ', 'd'),
+    ('ԛ', 'q'),
+    ('ԝ', 'w'),
+    ('ӏ', 'l'),
+    ('А', 'A'),
+    ('В', 'B'),
+    ('Е', 'E'),
+    ('К', 'K'),
+    ('М', 'M'),
+    ('Н', 'H'),
+    ('О', 'O'),
+    ('Р', 'P'),
+    ('С', 'C'),
+    ('Т', 'T'),
+    ('Х', 'X'),
+    ('У', 'Y'),
+    ('І', 'I'),
+    ('Ј', 'J'),
+    ('Ѕ', 'S'),
+    // Greek
+    ('ο', 'o'),
+    ('ν', 'v'),
+    ('Α', 'A'),
+    ('Β', 'B'),
+    ('Ε', 'E'),
+    ('Ζ', 'Z'),
+    ('Η', 'H'),
+    ('Ι', 'I'),
+    ('Κ', 'K'),
+    ('Μ', 'M'),
+    ('Ν', 'N'),
+    ('Ο', 'O'),
+    ('Ρ', 'P'),
+    ('Τ', 'T'),
+    ('Υ', 'Y'),
+    ('Χ', 'X'),
+];
+
+/// Flag words that mix Latin letters with letters from another script, like
+/// a Cyrillic "а" hiding in "cаt".
+///
+/// Words written entirely in another script are left alone.
+pub fn homoglyph_check(document: &Document) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for word in document.iter_words() {
+        let chars = document.get_span_content(word.span);
+
+        if !chars.iter().any(|c| is_latin(*c)) {
+            continue;
+        }
+
+        let Some(foreign) = chars
+            .iter()
+            .copied()
+            .find(|c| c.is_alphabetic() && !is_latin(*c))
+        else {
+            continue;
+        };
+
+        let latin: Option<Vec<char>> = chars
+            .iter()
+            .map(|c| {
+                if is_latin(*c) || !c.is_alphabetic() {
+                    Some(*c)
+                } else {
+                    latin_lookalike(*c)
+                }
+            })
+            .collect();
+
+        let script = find_unicode_block(foreign).map_or("another", |block| block.name());
+
+        let message = match latin_lookalike(foreign) {
+            Some(lookalike) => format!(
+                "The “{foreign}” in this word is a {script} character (U+{:04X}) that looks like the Latin “{lookalike}”. It's hard to see, but it stops the word from being recognized.",
+                foreign as u32
+            ),
+            None => format!(
+                "This word mixes Latin letters with a {script} character, “{foreign}” (U+{:04X}).",
+                foreign as u32
+            ),
+        };
+
+        output.push(Lint {
+            span: word.span,
+            lint_kind: LintKind::Spelling,
+            suggestions: latin.map(Suggestion::ReplaceWith).into_iter().collect(),
+            message,
+            // Should win over the spell checker, which can't explain the
+            // problem.
+            priority: 31,
+            severity: LintKind::Spelling.default_severity(),
+            ..Default::default()
+        });
+    }
+
+    output
+}
+
+/// Looks for words that contain hidden characters from other scripts, like a
+/// Cyrillic "а" in place of a Latin "a".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Homoglyphs;
+
+impl Rule for Homoglyphs {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        homoglyph_check(document)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Spelling
+    }
+
+    fn description(&self) -> &'static str {
+        "Text copied from elsewhere can contain letters from other alphabets that look identical to English ones, like a Cyrillic \"а\". This rule finds words that mix them with Latin letters."
+    }
+}
+
+fn is_latin(c: char) -> bool {
+    c.is_alphabetic()
+        && find_unicode_block(c).is_some_and(|block| {
+            [
+                BASIC_LATIN,
+                LATIN_1_SUPPLEMENT,
+                LATIN_EXTENDED_A,
+                LATIN_EXTENDED_B,
+                LATIN_EXTENDED_ADDITIONAL,
+            ]
+            .contains(&block)
+        })
+}
+
+fn latin_lookalike(c: char) -> Option<char> {
+    CONFUSABLES
+        .iter()
+        .find(|(confusable, _)| *confusable == c)
+        .map(|(_, latin)| *latin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{homoglyph_check, Homoglyphs};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn replaces_cyrillic_a() {
+        assert_suggestion_result("The cаt sat.", Homoglyphs, "The cat sat.");
+    }
+
+    #[test]
+    fn replaces_several_lookalikes() {
+        assert_suggestion_result("Pаssword rеsеt", Homoglyphs, "Password reset");
+    }
+
+    #[test]
+    fn replaces_greek_capitals() {
+        assert_suggestion_result("Call ΝASA now.", Homoglyphs, "Call NASA now.");
+    }
+
+    #[test]
+    fn allows_other_scripts() {
+        assert_lint_count("Привет means hello.", Homoglyphs, 0);
+        assert_lint_count("The café is open.", Homoglyphs, 0);
+    }
+
+    #[test]
+    fn explains_hidden_character() {
+        let document = Document::new_plain_english_curated("The cаt sat.");
+        let lints = homoglyph_check(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert!(lints[0].message.contains("Cyrillic"));
+        assert!(lints[0].message.contains("U+0430"));
+    }
+
+    #[test]
+    fn flags_mixed_scripts_without_lookalikes() {
+        let document = Document::new_plain_english_curated("The caжt sat.");
+        let lints = homoglyph_check(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert!(lints[0].suggestions.is_empty());
+    }
+}
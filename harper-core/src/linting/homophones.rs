@@ -0,0 +1,267 @@
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::token::TokenStringExt;
+use crate::{CharString, CharStringExt, Document, Token};
+
+/// A homophone that is likely the wrong choice in a given context, such as
+/// "their" right before "is".
+///
+/// A rule applies when the word matches and every context that is given
+/// matches too.
+/// Words are compared without regard to case, and curly apostrophes are
+/// treated as straight ones.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HomophoneRule {
+    /// The word that is likely wrong.
+    pub word: String,
+    /// Words, any one of which must come directly before [`Self::word`].
+    #[serde(default)]
+    pub preceded_by: Vec<String>,
+    /// Words, any one of which must come directly after [`Self::word`].
+    #[serde(default)]
+    pub followed_by: Vec<String>,
+    /// What the writer probably meant, most likely first.
+    pub replace_with: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HomophoneTable {
+    rules: Vec<HomophoneRule>,
+}
+
+/// Parse a table of [`HomophoneRule`]s from JSON, in the format of
+/// `homophones.json`.
+pub fn parse_homophone_rules(source: &str) -> serde_json::Result<Vec<HomophoneRule>> {
+    serde_json::from_str::<HomophoneTable>(source).map(|table| table.rules)
+}
+
+lazy_static! {
+    static ref CURATED_RULES: Vec<HomophoneRule> =
+        parse_homophone_rules(include_str!("../../homophones.json"))
+            .expect("The curated homophone table should be valid.");
+}
+
+/// Flag commonly confused homophones (their/there/they're, your/you're,
+/// its/it's, then/than) using the curated rule table.
+pub fn homophone_check(document: &Document) -> Vec<Lint> {
+    lint_with_rules(document, &CURATED_RULES)
+}
+
+/// Looks for homophones that don't fit their surroundings, like "your
+/// welcome".
+#[derive(Debug, Clone)]
+pub struct Homophones {
+    rules: Vec<HomophoneRule>,
+}
+
+impl Homophones {
+    /// Use a custom rule table instead of the curated one.
+    pub fn new(rules: Vec<HomophoneRule>) -> Self {
+        Self { rules }
+    }
+}
+
+impl Default for Homophones {
+    fn default() -> Self {
+        Self::new(CURATED_RULES.clone())
+    }
+}
+
+impl Rule for Homophones {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        lint_with_rules(document, &self.rules)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
+    fn description(&self) -> &'static str {
+        "Words like \"their\", \"there\" and \"they're\" sound the same but mean different things. This rule looks for ones that don't fit their surroundings."
+    }
+}
+
+fn lint_with_rules(document: &Document, rules: &[HomophoneRule]) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for chunk in document.iter_chunks() {
+        let tokens: Vec<&Token> = chunk
+            .iter()
+            .filter(|token| !token.kind.is_whitespace())
+            .collect();
+
+        for (idx, token) in tokens.iter().enumerate() {
+            if !token.kind.is_word() {
+                continue;
+            }
+
+            let word = document.get_span_content(token.span);
+            let normalized = normalize(word);
+
+            let neighbor = |idx: Option<usize>| {
+                idx.and_then(|idx| tokens.get(idx))
+                    .filter(|token| token.kind.is_word())
+                    .map(|token| normalize(document.get_span_content(token.span)))
+            };
+            let prev = neighbor(idx.checked_sub(1));
+            let next = neighbor(Some(idx + 1));
+
+            let Some(rule) = rules.iter().find(|rule| {
+                matches(&normalized, &rule.word)
+                    && context_matches(prev.as_deref(), &rule.preceded_by)
+                    && context_matches(next.as_deref(), &rule.followed_by)
+            }) else {
+                continue;
+            };
+
+            let curly = word.contains(&'’');
+
+            output.push(Lint {
+                span: token.span,
+                lint_kind: LintKind::Miscellaneous,
+                suggestions: rule
+                    .replace_with
+                    .iter()
+                    .map(|replacement| {
+                        let replacement: Vec<char> = replacement
+                            .chars()
+                            .map(|c| if curly && c == '\'' { '’' } else { c })
+                            .collect();
+                        Suggestion::ReplaceWith(replacement.with_case_of(word).to_vec())
+                    })
+                    .collect(),
+                message: format!(
+                    "Did you mean “{}”?",
+                    rule.replace_with.first().map_or("", String::as_str)
+                ),
+                severity: LintKind::Miscellaneous.default_severity(),
+                ..Default::default()
+            });
+        }
+    }
+
+    output
+}
+
+fn normalize(word: &[char]) -> CharString {
+    word.iter()
+        .flat_map(|c| c.to_lowercase())
+        .map(|c| if c == '’' { '\'' } else { c })
+        .collect()
+}
+
+fn matches(normalized: &[char], word: &str) -> bool {
+    normalized.iter().copied().eq(word.chars())
+}
+
+/// An empty context always matches.
+fn context_matches(neighbor: Option<&[char]>, context: &[String]) -> bool {
+    if context.is_empty() {
+        return true;
+    }
+
+    neighbor.is_some_and(|neighbor| context.iter().any(|word| matches(neighbor, word)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{homophone_check, parse_homophone_rules, Homophones};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::Suggestion;
+    use crate::Document;
+
+    #[test]
+    fn their_is() {
+        assert_suggestion_result("Their is a cat.", Homophones::default(), "There is a cat.");
+    }
+
+    #[test]
+    fn your_welcome() {
+        assert_suggestion_result(
+            "Thanks! Oh, your welcome.",
+            Homophones::default(),
+            "Thanks! Oh, you're welcome.",
+        );
+    }
+
+    #[test]
+    fn its_been() {
+        assert_suggestion_result(
+            "I think its been a while.",
+            Homophones::default(),
+            "I think it's been a while.",
+        );
+    }
+
+    #[test]
+    fn more_then() {
+        assert_suggestion_result(
+            "This is more then enough.",
+            Homophones::default(),
+            "This is more than enough.",
+        );
+    }
+
+    #[test]
+    fn keeps_curly_apostrophes() {
+        assert_suggestion_result(
+            "They lost they’re own keys.",
+            Homophones::default(),
+            "They lost their own keys.",
+        );
+    }
+
+    #[test]
+    fn your_going() {
+        assert_suggestion_result(
+            "Do it your own way, your going to anyway.",
+            Homophones::default(),
+            "Do it your own way, you're going to anyway.",
+        );
+    }
+
+    #[test]
+    fn allows_correct_usage() {
+        assert_lint_count(
+            "There is a cat in their house, and they're happy about it.",
+            Homophones::default(),
+            0,
+        );
+        assert_lint_count("Its owner said it's fine.", Homophones::default(), 0);
+        assert_lint_count("It is bigger than the last one.", Homophones::default(), 0);
+    }
+
+    #[test]
+    fn context_does_not_cross_punctuation() {
+        assert_lint_count("I know their, is it?", Homophones::default(), 0);
+    }
+
+    #[test]
+    fn offers_every_alternative() {
+        let rules = parse_homophone_rules(
+            r#"{"rules": [{"word": "to", "followed_by": ["much"], "replace_with": ["too", "two"]}]}"#,
+        )
+        .unwrap();
+
+        let document = Document::new_plain_english_curated("That is to much.");
+        let lints = super::lint_with_rules(&document, &rules);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            lints[0].suggestions,
+            vec![
+                Suggestion::ReplaceWith("too".chars().collect()),
+                Suggestion::ReplaceWith("two".chars().collect())
+            ]
+        );
+    }
+
+    #[test]
+    fn curated_table_is_used() {
+        let document = Document::new_plain_english_curated("Their was a problem.");
+
+        assert_eq!(homophone_check(&document).len(), 1);
+    }
+}
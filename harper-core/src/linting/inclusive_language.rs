@@ -0,0 +1,287 @@
+use lazy_static::lazy_static;
+
+use super::{Lint, LintKind, Rule, Severity, Suggestion};
+use crate::{CharString, CharStringExt, Document, Span, Token, TokenKind};
+
+lazy_static! {
+    static ref CURATED: InclusiveLanguageTable =
+        InclusiveLanguageTable::parse(include_str!("../../inclusive_language.tsv"));
+}
+
+/// Terms that some readers find exclusionary, like "whitelist", and the
+/// alternatives to suggest for them.
+///
+/// Terms can be several words long, like "master branch", and are matched
+/// without regard to case.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InclusiveLanguageTable {
+    /// Each term's lowercase words and its replacement, longest terms first
+    /// so they win over any shorter term they contain.
+    entries: Vec<(Vec<String>, String)>,
+}
+
+impl InclusiveLanguageTable {
+    /// Create an empty table, for replacing the curated one entirely.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The small table of common terms included in the Harper binary.
+    pub fn curated() -> Self {
+        CURATED.clone()
+    }
+
+    /// Parse a table of terms, one `term<TAB>replacement` pair per line.
+    ///
+    /// Blank lines and lines that fail to parse are skipped.
+    pub fn parse(source: &str) -> Self {
+        let mut table = Self::new();
+
+        for line in source.lines() {
+            if let Some((term, replacement)) = line.split_once('\t') {
+                table.add(term, replacement.trim());
+            }
+        }
+
+        table
+    }
+
+    /// Add a term, replacing any previous replacement for it.
+    ///
+    /// Terms without any words are ignored.
+    pub fn add(&mut self, term: &str, replacement: &str) {
+        let words = Self::words_of(term);
+
+        if words.is_empty() {
+            return;
+        }
+
+        self.remove(term);
+
+        let idx = self
+            .entries
+            .partition_point(|(w, _)| w.len() >= words.len());
+        self.entries.insert(idx, (words, replacement.to_string()));
+    }
+
+    /// Stop flagging a term.
+    pub fn remove(&mut self, term: &str) {
+        let words = Self::words_of(term);
+        self.entries.retain(|(w, _)| *w != words);
+    }
+
+    fn words_of(term: &str) -> Vec<String> {
+        term.split_whitespace().map(str::to_lowercase).collect()
+    }
+}
+
+/// Suggest alternatives to non-inclusive terms, like "whitelist", using the
+/// curated [`InclusiveLanguageTable`].
+pub fn inclusive_language_check(document: &Document) -> Vec<Lint> {
+    lint_with_table(document, &CURATED)
+}
+
+/// Looks for terms that some readers find exclusionary, like "blacklist",
+/// and suggests alternatives.
+#[derive(Debug, Clone)]
+pub struct InclusiveLanguage {
+    table: InclusiveLanguageTable,
+}
+
+impl InclusiveLanguage {
+    /// Use a custom table instead of the curated one.
+    pub fn new(table: InclusiveLanguageTable) -> Self {
+        Self { table }
+    }
+}
+
+impl Default for InclusiveLanguage {
+    fn default() -> Self {
+        Self::new(InclusiveLanguageTable::curated())
+    }
+}
+
+impl Rule for InclusiveLanguage {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        lint_with_table(document, &self.table)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
+    fn description(&self) -> &'static str {
+        "Some common technical terms, like \"whitelist\", can make readers feel excluded. This rule suggests widely used alternatives."
+    }
+}
+
+fn lint_with_table(document: &Document, table: &InclusiveLanguageTable) -> Vec<Lint> {
+    let tokens = document.get_tokens();
+    let mut output = Vec::new();
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let found = table.entries.iter().find_map(|(words, replacement)| {
+            match_term(document, &tokens[idx..], words).map(|len| (len, replacement))
+        });
+
+        let Some((len, replacement)) = found else {
+            idx += 1;
+            continue;
+        };
+
+        let matched = &tokens[idx..idx + len];
+        let span = Span::new(matched[0].span.start, matched[len - 1].span.end);
+        let term = document.get_span_content(span);
+
+        let replacement: CharString = replacement.chars().collect();
+
+        output.push(Lint {
+            span,
+            lint_kind: LintKind::Readability,
+            suggestions: vec![Suggestion::ReplaceWith(
+                replacement.with_case_of(term).to_vec(),
+            )],
+            message: format!(
+                "Consider using “{}” instead of “{}”.",
+                replacement.iter().collect::<String>(),
+                term.iter().collect::<String>()
+            ),
+            severity: Severity::Info,
+            ..Default::default()
+        });
+
+        idx += len;
+    }
+
+    output
+}
+
+/// If `tokens` starts with the given words, separated only by spaces, the
+/// number of tokens they cover.
+fn match_term(document: &Document, tokens: &[Token], words: &[String]) -> Option<usize> {
+    let mut idx = 0;
+
+    for (i, word) in words.iter().enumerate() {
+        if i > 0 {
+            if !matches!(tokens.get(idx)?.kind, TokenKind::Space(_)) {
+                return None;
+            }
+            idx += 1;
+        }
+
+        let token = tokens.get(idx)?;
+
+        if !token.kind.is_word() {
+            return None;
+        }
+
+        let content = document.get_span_content(token.span);
+
+        if !content
+            .iter()
+            .flat_map(|c| c.to_lowercase())
+            .eq(word.chars())
+        {
+            return None;
+        }
+
+        idx += 1;
+    }
+
+    Some(idx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{inclusive_language_check, InclusiveLanguage, InclusiveLanguageTable};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::Severity;
+    use crate::Document;
+
+    #[test]
+    fn whitelist() {
+        assert_suggestion_result(
+            "Add the domain to the whitelist.",
+            InclusiveLanguage::default(),
+            "Add the domain to the allowlist.",
+        );
+    }
+
+    #[test]
+    fn preserves_case() {
+        assert_suggestion_result(
+            "Blacklisted hosts are dropped.",
+            InclusiveLanguage::default(),
+            "Denylisted hosts are dropped.",
+        );
+        assert_suggestion_result(
+            "See the WHITELIST.",
+            InclusiveLanguage::default(),
+            "See the ALLOWLIST.",
+        );
+    }
+
+    #[test]
+    fn matches_phrases() {
+        assert_suggestion_result(
+            "Push to the master branch first.",
+            InclusiveLanguage::default(),
+            "Push to the main branch first.",
+        );
+    }
+
+    #[test]
+    fn phrases_need_every_word() {
+        assert_lint_count(
+            "You need to master the branch of mathematics.",
+            InclusiveLanguage::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn longer_terms_win() {
+        let mut table = InclusiveLanguageTable::new();
+        table.add("master", "primary");
+        table.add("master branch", "main branch");
+
+        assert_suggestion_result(
+            "Merge into master branch.",
+            InclusiveLanguage::new(table.clone()),
+            "Merge into main branch.",
+        );
+        assert_lint_count(
+            "Merge master branch into master.",
+            InclusiveLanguage::new(table),
+            2,
+        );
+    }
+
+    #[test]
+    fn table_can_be_overridden() {
+        let mut table = InclusiveLanguageTable::curated();
+        table.remove("whitelist");
+        table.add("blacklist", "blocklist");
+
+        assert_lint_count(
+            "Check the whitelist.",
+            InclusiveLanguage::new(table.clone()),
+            0,
+        );
+        assert_suggestion_result(
+            "Check the blacklist.",
+            InclusiveLanguage::new(table),
+            "Check the blocklist.",
+        );
+    }
+
+    #[test]
+    fn lints_are_info() {
+        let document = Document::new_plain_english_curated("Update the whitelist.");
+        let lints = inclusive_language_check(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].severity, Severity::Info);
+    }
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{Document, Span};
+
+/// The line ending [`line_ending_check`] should normalize to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LineEndingStyle {
+    /// Whichever of `\n` and `\r\n` the document uses most, preferring `\n`
+    /// in a tie.
+    /// Documents that only use one style are never flagged.
+    #[default]
+    Dominant,
+    /// Unix-style `\n`.
+    Lf,
+    /// Windows-style `\r\n`.
+    CrLf,
+}
+
+/// Flag line endings that don't match `target`, suggesting the one that
+/// does.
+///
+/// Lone carriage returns aren't treated as line endings.
+pub fn line_ending_check(document: &Document, target: LineEndingStyle) -> Vec<Lint> {
+    let source = document.get_source();
+
+    // The spans of each line ending, and whether it is `\r\n`.
+    let endings: Vec<(Span, bool)> = source
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| **c == '\n')
+        .map(|(idx, _)| {
+            if idx > 0 && source[idx - 1] == '\r' {
+                (Span::new(idx - 1, idx + 1), true)
+            } else {
+                (Span::new(idx, idx + 1), false)
+            }
+        })
+        .collect();
+
+    let crlf = endings.iter().filter(|(_, is_crlf)| *is_crlf).count();
+    let lf = endings.len() - crlf;
+
+    let want_crlf = match target {
+        LineEndingStyle::Dominant if crlf == 0 || lf == 0 => return Vec::new(),
+        LineEndingStyle::Dominant => crlf > lf,
+        LineEndingStyle::Lf => false,
+        LineEndingStyle::CrLf => true,
+    };
+
+    let (replacement, message) = if want_crlf {
+        (
+            vec!['\r', '\n'],
+            "This line ends with `\\n`, but `\\r\\n` is expected.",
+        )
+    } else {
+        (
+            vec!['\n'],
+            "This line ends with `\\r\\n`, but `\\n` is expected.",
+        )
+    };
+
+    endings
+        .into_iter()
+        .filter(|(_, is_crlf)| *is_crlf != want_crlf)
+        .map(|(span, _)| Lint {
+            span,
+            lint_kind: LintKind::Formatting,
+            suggestions: vec![Suggestion::ReplaceWith(replacement.clone())],
+            message: message.to_string(),
+            priority: 63,
+            severity: LintKind::Formatting.default_severity(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Looks for documents that mix `\n` and `\r\n` line endings.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LineEndings {
+    target: LineEndingStyle,
+}
+
+impl LineEndings {
+    pub fn new(target: LineEndingStyle) -> Self {
+        Self { target }
+    }
+}
+
+impl Rule for LineEndings {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        line_ending_check(document, self.target)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
+    fn description(&self) -> &'static str {
+        "Mixing Unix (`\\n`) and Windows (`\\r\\n`) line endings in one file can confuse other tools. This rule flags the line endings that don't match the rest."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{line_ending_check, LineEndingStyle, LineEndings};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn consistent_endings_are_fine() {
+        assert_lint_count("One.\nTwo.\nThree.\n", LineEndings::default(), 0);
+        assert_lint_count("One.\r\nTwo.\r\nThree.\r\n", LineEndings::default(), 0);
+    }
+
+    #[test]
+    fn normalizes_to_dominant_lf() {
+        assert_suggestion_result(
+            "One.\nTwo.\r\nThree.\n",
+            LineEndings::default(),
+            "One.\nTwo.\nThree.\n",
+        );
+    }
+
+    #[test]
+    fn normalizes_to_dominant_crlf() {
+        assert_suggestion_result(
+            "One.\r\nTwo.\nThree.\r\n",
+            LineEndings::default(),
+            "One.\r\nTwo.\r\nThree.\r\n",
+        );
+    }
+
+    #[test]
+    fn explicit_target_flags_consistent_documents() {
+        assert_suggestion_result(
+            "One.\nTwo.",
+            LineEndings::new(LineEndingStyle::CrLf),
+            "One.\r\nTwo.",
+        );
+        assert_lint_count(
+            "One.\r\nTwo.\r\nThree.",
+            LineEndings::new(LineEndingStyle::Lf),
+            2,
+        );
+    }
+
+    #[test]
+    fn document_keeps_carriage_returns() {
+        let document = Document::new_plain_english_curated("One.\r\nTwo.\nThree.");
+
+        assert_eq!(
+            document.get_source().iter().filter(|c| **c == '\r').count(),
+            1
+        );
+        assert_eq!(
+            line_ending_check(&document, LineEndingStyle::Dominant).len(),
+            1
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Rule};
 use crate::token::TokenStringExt;
 use crate::Document;
 
@@ -6,8 +6,8 @@ use crate::Document;
 #[derive(Debug, Clone, Copy, Default)]
 pub struct LinkingVerbs;
 
-impl Linter for LinkingVerbs {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for LinkingVerbs {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut output = Vec::new();
 
         for chunk in document.iter_chunks() {
@@ -25,6 +25,7 @@ impl Linter for LinkingVerbs {
                                 "Linking verbs like “{}” must be preceded by a noun.",
                                 linking_verb_text
                             ),
+                            severity: LintKind::Miscellaneous.default_severity(),
                             ..Default::default()
                         })
                     }
@@ -35,6 +36,10 @@ impl Linter for LinkingVerbs {
         output
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "Linking verbs connect nouns to other ideas. Make sure you do not accidentaly link words that aren't nouns."
     }
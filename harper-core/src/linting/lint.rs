@@ -1,11 +1,14 @@
+use std::collections::BTreeMap;
 use std::fmt::Display;
 
 use is_macro::Is;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::Span;
+use crate::{Span, WordMetadata};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Lint {
     pub span: Span,
     pub lint_kind: LintKind,
@@ -14,6 +17,23 @@ pub struct Lint {
     /// A numerical value for the importance of a lint.
     /// Lower = more important.
     pub priority: u8,
+    /// How the lint should be presented to the user.
+    ///
+    /// Linters should usually set this to the
+    /// [`LintKind::default_severity`] of the lint's kind, as [`Lint::new`]
+    /// does. [`Default::default`] always gives [`Severity::Warning`].
+    pub severity: Severity,
+    /// For spelling lints, the [`WordMetadata`] of the closest dictionary
+    /// word, if there is one.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: Option<WordMetadata>,
+    /// For spelling lints, the dictionary words that were considered as
+    /// replacements, each with its edit distance from the flagged word.
+    ///
+    /// These are in the same order as [`Self::suggestions`], for UIs that want
+    /// to show how close each one is.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub candidates: Vec<(String, u8)>,
 }
 
 impl Default for Lint {
@@ -24,13 +44,40 @@ impl Default for Lint {
             suggestions: Default::default(),
             message: Default::default(),
             priority: 127,
+            severity: Default::default(),
+            metadata: Default::default(),
+            candidates: Default::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Is, Default)]
+impl Lint {
+    /// Create a lint with a generic message derived from its [`LintKind`].
+    ///
+    /// Linters should generally provide a more specific message, but this
+    /// keeps new linters from showing nothing at all.
+    pub fn new(span: Span, lint_kind: LintKind, suggestions: Vec<Suggestion>) -> Self {
+        Self {
+            span,
+            lint_kind,
+            suggestions,
+            message: lint_kind.default_message().to_string(),
+            severity: lint_kind.default_severity(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Kinds are declared in order of importance, which is used to break ties
+/// in [`resolve_overlaps`].
+#[derive(Debug, Clone, Copy, Is, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum LintKind {
+    /// A likely typo, with a dictionary word a single edit away.
     Spelling,
+    /// A word that isn't in the dictionary and isn't close to anything that
+    /// is, like a name or a piece of jargon.
+    UnknownWord,
     Capitalization,
     Formatting,
     Repetition,
@@ -40,10 +87,53 @@ pub enum LintKind {
     Miscellaneous,
 }
 
+/// How serious a lint is, from most to least severe.
+///
+/// These map directly onto the LSP's `DiagnosticSeverity`.
+#[derive(Debug, Clone, Copy, Is, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Severity {
+    Error,
+    #[default]
+    Warning,
+    Info,
+    Hint,
+}
+
+impl LintKind {
+    /// The severity used for lints of this kind, unless configured otherwise.
+    pub fn default_severity(&self) -> Severity {
+        match self {
+            LintKind::Spelling => Severity::Error,
+            LintKind::UnknownWord
+            | LintKind::Capitalization
+            | LintKind::Repetition
+            | LintKind::Miscellaneous => Severity::Warning,
+            LintKind::Formatting | LintKind::Readability => Severity::Info,
+            LintKind::Enhancement => Severity::Hint,
+        }
+    }
+
+    /// A generic, human-readable message for lints of this kind.
+    pub fn default_message(&self) -> &'static str {
+        match self {
+            LintKind::Spelling => "Possible spelling mistake.",
+            LintKind::UnknownWord => "This word isn't in the dictionary.",
+            LintKind::Capitalization => "This may be capitalized incorrectly.",
+            LintKind::Formatting => "This may be formatted incorrectly.",
+            LintKind::Repetition => "This appears to be repeated.",
+            LintKind::Enhancement => "This could be improved.",
+            LintKind::Readability => "This may be difficult to read.",
+            LintKind::Miscellaneous => "There may be a problem here.",
+        }
+    }
+}
+
 impl Display for LintKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             LintKind::Spelling => "Spelling",
+            LintKind::UnknownWord => "UnknownWord",
             LintKind::Capitalization => "Capitalization",
             LintKind::Formatting => "Formatting",
             LintKind::Repetition => "Repetition",
@@ -56,9 +146,11 @@ impl Display for LintKind {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Is)]
+#[derive(Debug, Clone, Is, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Suggestion {
-    ReplaceWith(Vec<char>),
+    /// Serialized as a plain string, rather than an array of characters.
+    ReplaceWith(#[cfg_attr(feature = "serde", serde(with = "chars_as_string"))] Vec<char>),
     Remove,
 }
 
@@ -90,6 +182,128 @@ impl Suggestion {
     }
 }
 
+#[cfg(feature = "serde")]
+mod chars_as_string {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(chars: &[char], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&chars.iter().collect::<String>())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<char>, D::Error> {
+        Ok(String::deserialize(deserializer)?.chars().collect())
+    }
+}
+
+/// Sort lints into the order [`Linter::lint`](super::Linter::lint) is
+/// expected to return them in: by the start of [`Lint::span`], then its end,
+/// then [`Lint::lint_kind`].
+///
+/// The sort is stable, so lints that tie on all three keep their relative
+/// order.
+pub fn sort_lints(lints: &mut [Lint]) {
+    lints.sort_by_key(|lint| (lint.span.start, lint.span.end, lint.lint_kind));
+}
+
+/// Remove lints that overlap with a more important lint, so that the
+/// remaining lints can all be applied at once.
+///
+/// When two lints overlap, the one that is kept is chosen by comparing, in
+/// order:
+///
+/// 1. [`Lint::severity`], where [`Severity::Error`] wins.
+/// 2. [`Lint::lint_kind`], in the order the variants of [`LintKind`] are
+///    declared, so [`LintKind::Spelling`] wins.
+/// 3. [`Lint::priority`], where lower wins.
+/// 4. The start of [`Lint::span`], where the earlier lint wins.
+///
+/// If all of these are equal, the lint that came first in `lints` is kept.
+/// The result is sorted with [`sort_lints`].
+pub fn resolve_overlaps(mut lints: Vec<Lint>) -> Vec<Lint> {
+    lints.sort_by_key(|lint| {
+        (
+            lint.severity,
+            lint.lint_kind,
+            lint.priority,
+            lint.span.start,
+        )
+    });
+
+    let mut kept: Vec<Lint> = Vec::with_capacity(lints.len());
+    // The spans of `kept` never overlap, so it's enough to check the kept
+    // span that starts closest before the end of a candidate.
+    let mut kept_spans: BTreeMap<usize, Span> = BTreeMap::new();
+
+    for lint in lints {
+        let overlaps = kept_spans
+            .range(..lint.span.end.max(lint.span.start + 1))
+            .next_back()
+            .is_some_and(|(_, other)| other.overlaps_with(lint.span) || *other == lint.span);
+
+        if !overlaps {
+            kept_spans.insert(lint.span.start, lint.span);
+            kept.push(lint);
+        }
+    }
+
+    sort_lints(&mut kept);
+
+    kept
+}
+
+/// How the lints of a document changed between two passes, as produced by
+/// [`Document::relint_dirty`](crate::Document::relint_dirty).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintDiff {
+    /// Lints that are new in this pass.
+    pub added: Vec<Lint>,
+    /// Lints from the previous pass that no longer apply.
+    pub removed: Vec<Lint>,
+}
+
+impl LintDiff {
+    /// Update the lints of the previous pass to match this one.
+    pub fn apply(&self, lints: &mut Vec<Lint>) {
+        lints.retain(|lint| !self.removed.contains(lint));
+        lints.extend(self.added.iter().cloned());
+    }
+}
+
+/// Apply the first suggestion of each lint to some source text, returning the
+/// corrected text.
+///
+/// Lints are considered in the order given. If a lint overlaps with one that
+/// was already accepted, it is skipped. Lints without suggestions are ignored.
+pub fn apply_lints(source: &str, lints: &[Lint]) -> String {
+    let mut accepted: Vec<(Span, &Suggestion)> = Vec::new();
+
+    for lint in lints {
+        let Some(suggestion) = lint.suggestions.first() else {
+            continue;
+        };
+
+        if accepted
+            .iter()
+            .any(|(span, _)| span.overlaps_with(lint.span))
+        {
+            continue;
+        }
+
+        accepted.push((lint.span, suggestion));
+    }
+
+    // Work from right to left so the spans of earlier lints stay valid.
+    accepted.sort_by_key(|(span, _)| std::cmp::Reverse(span.start));
+
+    let mut chars: Vec<char> = source.chars().collect();
+
+    for (span, suggestion) in accepted {
+        suggestion.apply(span, &mut chars);
+    }
+
+    chars.into_iter().collect()
+}
+
 impl Display for Suggestion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -100,3 +314,210 @@ impl Display for Suggestion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_lints, resolve_overlaps, Lint, LintKind, Severity, Suggestion};
+    use crate::Span;
+
+    fn replace(start: usize, end: usize, with: &str) -> Lint {
+        Lint {
+            span: Span::new(start, end),
+            suggestions: vec![Suggestion::ReplaceWith(with.chars().collect())],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn new_lint_gets_default_message() {
+        let lint = Lint::new(Span::new(0, 3), LintKind::Spelling, Vec::new());
+
+        assert_eq!(lint.message, LintKind::Spelling.default_message());
+        assert_eq!(lint.priority, Lint::default().priority);
+        assert_eq!(lint.severity, Severity::Error);
+    }
+
+    #[test]
+    fn severities_are_ordered_by_importance() {
+        assert!(Severity::Error < Severity::Warning);
+        assert!(Severity::Warning < Severity::Info);
+        assert!(Severity::Info < Severity::Hint);
+    }
+
+    fn lint_at(start: usize, end: usize, lint_kind: LintKind, severity: Severity) -> Lint {
+        Lint {
+            span: Span::new(start, end),
+            lint_kind,
+            severity,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn overlaps_keep_most_severe() {
+        let lints = vec![
+            lint_at(0, 4, LintKind::Capitalization, Severity::Warning),
+            lint_at(2, 6, LintKind::Spelling, Severity::Error),
+        ];
+
+        let resolved = resolve_overlaps(lints);
+
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].lint_kind, LintKind::Spelling);
+    }
+
+    #[test]
+    fn overlaps_break_ties_by_kind() {
+        let lints = vec![
+            lint_at(0, 4, LintKind::Repetition, Severity::Warning),
+            lint_at(0, 4, LintKind::Capitalization, Severity::Warning),
+        ];
+
+        let resolved = resolve_overlaps(lints);
+
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].lint_kind, LintKind::Capitalization);
+    }
+
+    #[test]
+    fn overlaps_break_ties_by_position() {
+        let lints = vec![
+            lint_at(3, 8, LintKind::Spelling, Severity::Error),
+            lint_at(0, 4, LintKind::Spelling, Severity::Error),
+        ];
+
+        let resolved = resolve_overlaps(lints);
+
+        assert_eq!(resolved.len(), 1);
+        assert_eq!(resolved[0].span, Span::new(0, 4));
+    }
+
+    #[test]
+    fn keeps_disjoint_lints_in_order() {
+        let lints = vec![
+            lint_at(10, 12, LintKind::Formatting, Severity::Info),
+            lint_at(0, 4, LintKind::Spelling, Severity::Error),
+            lint_at(4, 8, LintKind::Spelling, Severity::Error),
+        ];
+
+        let starts: Vec<_> = resolve_overlaps(lints)
+            .into_iter()
+            .map(|l| l.span.start)
+            .collect();
+
+        assert_eq!(starts, vec![0, 4, 10]);
+    }
+
+    #[test]
+    fn applies_multiple_lints_of_different_lengths() {
+        let lints = vec![
+            replace(0, 4, "These"),
+            replace(8, 9, "some"),
+            replace(10, 15, "tests"),
+        ];
+
+        assert_eq!(
+            apply_lints("This is a tezzt", &lints),
+            "These is some tests"
+        );
+    }
+
+    #[test]
+    fn skips_overlapping_lints() {
+        let lints = vec![replace(10, 15, "test"), replace(8, 15, "the test")];
+
+        assert_eq!(apply_lints("This is a tezzt", &lints), "This is a test");
+    }
+
+    #[test]
+    fn ignores_lints_without_suggestions() {
+        let lints = vec![
+            Lint {
+                span: Span::new(0, 15),
+                ..Default::default()
+            },
+            replace(10, 15, "test"),
+        ];
+
+        assert_eq!(apply_lints("This is a tezzt", &lints), "This is a test");
+    }
+
+    #[test]
+    fn remove_deletes_spanned_text() {
+        let mut source: Vec<char> = "This is is a test".chars().collect();
+
+        Suggestion::Remove.apply(Span::new(7, 10), &mut source);
+
+        assert_eq!(source.iter().collect::<String>(), "This is a test");
+    }
+
+    #[test]
+    fn replace_with_shorter_text() {
+        let mut source: Vec<char> = "This is a tezzt".chars().collect();
+
+        Suggestion::ReplaceWith("test".chars().collect()).apply(Span::new(10, 15), &mut source);
+
+        assert_eq!(source.iter().collect::<String>(), "This is a test");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn replace_with_serializes_as_string() {
+        assert_eq!(
+            serde_json::to_string(&Suggestion::ReplaceWith("test".chars().collect())).unwrap(),
+            r#"{"ReplaceWith":"test"}"#
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn lint_round_trips() {
+        let lint = Lint {
+            span: Span::new(3, 7),
+            lint_kind: LintKind::Repetition,
+            suggestions: vec![
+                Suggestion::ReplaceWith("tést".chars().collect()),
+                Suggestion::Remove,
+            ],
+            message: "Some message.".to_string(),
+            priority: 12,
+            severity: Severity::Info,
+            metadata: Some(crate::WordMetadata::default()),
+            candidates: vec![("tést".to_string(), 1)],
+        };
+
+        let json = serde_json::to_string(&lint).unwrap();
+
+        assert_eq!(serde_json::from_str::<Lint>(&json).unwrap(), lint);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn spelling_details_are_optional_in_json() {
+        let json = r#"{"span":{"start":0,"end":4},"lint_kind":"Spelling","suggestions":[],"message":"","priority":63,"severity":"Error"}"#;
+        let lint = serde_json::from_str::<Lint>(json).unwrap();
+
+        assert_eq!(lint.metadata, None);
+        assert!(lint.candidates.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn lint_kind_round_trips() {
+        let json = serde_json::to_string(&LintKind::Readability).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<LintKind>(&json).unwrap(),
+            LintKind::Readability
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn remove_serializes_without_payload() {
+        assert_eq!(
+            serde_json::to_string(&Suggestion::Remove).unwrap(),
+            "\"Remove\""
+        );
+    }
+}
@@ -1,37 +1,55 @@
+use hashbrown::{HashMap, HashSet};
 use paste::paste;
 use serde::{Deserialize, Serialize};
 
+use super::abbreviations::Abbreviations;
 use super::an_a::AnA;
+use super::apostrophes::Apostrophes;
 use super::avoid_curses::AvoidCurses;
 use super::boring_words::BoringWords;
 use super::capitalize_personal_pronouns::CapitalizePersonalPronouns;
 use super::correct_number_suffix::CorrectNumberSuffix;
 use super::dot_initialisms::DotInitialisms;
+use super::double_negatives::DoubleNegatives;
 use super::ellipsis_length::EllipsisLength;
+use super::homoglyphs::Homoglyphs;
+use super::homophones::Homophones;
+use super::inclusive_language::InclusiveLanguage;
+use super::line_endings::LineEndings;
 use super::linking_verbs::LinkingVerbs;
 use super::long_sentences::LongSentences;
 use super::matcher::Matcher;
+use super::merge_words::MergeWords;
 use super::multiple_sequential_pronouns::MultipleSequentialPronouns;
+use super::name_consistency::NameConsistency;
+use super::number_style::NumberStyle;
 use super::number_suffix_capitalization::NumberSuffixCapitalization;
+use super::number_units::{NumberUnitConfig, NumberUnits};
+use super::passive_voice::PassiveVoice;
 use super::proper_noun_capitalization_linters::{
     AmazonNames, Americas, AppleNames, AzureNames, ChineseCommunistParty, GoogleNames, Holidays,
     MetaNames, MicrosoftNames, UnitedOrganizations,
 };
+use super::punctuation_repetition::PunctuationRepetition;
+use super::rare_words::RareWords;
 use super::repeated_words::RepeatedWords;
 use super::sentence_capitalization::SentenceCapitalization;
 use super::spaces::Spaces;
 use super::spell_check::SpellCheck;
 use super::spelled_numbers::SpelledNumbers;
+use super::split_words::SplitWords;
+use super::terminal_punctuation::TerminalPunctuation;
 use super::terminating_conjunctions::TerminatingConjunctions;
 use super::that_which::ThatWhich;
+use super::title_case::TitleCase;
 use super::unclosed_quotes::UnclosedQuotes;
 use super::use_genitive::UseGenitive;
 use super::wrong_quotes::WrongQuotes;
-use super::{Lint, Linter};
+use super::{resolve_overlaps, Lint, LintKind, Linter, Rule, Severity};
 use crate::{Dictionary, Document};
 
 macro_rules! create_lint_group_config {
-    ($($linter:ident => $default:expr),*) => {
+    ($($linter:ident => $default:expr),*; $($dict_linter:ident => $dict_default:expr),*) => {
         paste! {
             /// A collection of all the descriptions from the composing linters.
             #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
@@ -40,7 +58,10 @@ macro_rules! create_lint_group_config {
                     #[doc = "The description for the [`" $linter "`] linter."]
                     pub [<$linter:snake>]: &'a str,
                 )*
-                pub spell_check: &'a str
+                $(
+                    #[doc = "The description for the [`" $dict_linter "`] linter."]
+                    pub [<$dict_linter:snake>]: &'a str,
+                )*
             }
 
             #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
@@ -50,7 +71,11 @@ macro_rules! create_lint_group_config {
                     If set to [`None`], the default configuration will be used."]
                     pub [<$linter:snake>]: Option<bool>,
                 )*
-                pub spell_check: Option<bool>
+                $(
+                    #[doc = "Configures the use of the [`" $dict_linter "`] linter.
+                    If set to [`None`], the default configuration will be used."]
+                    pub [<$dict_linter:snake>]: Option<bool>,
+                )*
             }
 
             impl LintGroupConfig {
@@ -60,7 +85,9 @@ macro_rules! create_lint_group_config {
                         $(
                             [<$linter:snake>]: Some(false),
                         )*
-                        spell_check: Some(false)
+                        $(
+                            [<$dict_linter:snake>]: Some(false),
+                        )*
                     }
                 }
 
@@ -71,10 +98,11 @@ macro_rules! create_lint_group_config {
                             self.[<$linter:snake>] = Some($default);
                         }
                     )*
-
-                    if self.spell_check.is_none() {
-                        self.spell_check = Some(true);
-                    }
+                    $(
+                        if self.[<$dict_linter:snake>].is_none() {
+                            self.[<$dict_linter:snake>] = Some($dict_default);
+                        }
+                    )*
                 }
             }
 
@@ -84,28 +112,97 @@ macro_rules! create_lint_group_config {
                 $(
                     [<$linter:snake>]: $linter,
                 )*
-                spell_check: SpellCheck<T>,
-                pub config: LintGroupConfig
+                $(
+                    [<$dict_linter:snake>]: $dict_linter<T>,
+                )*
+                pub config: LintGroupConfig,
+                /// Kinds of lints that are filtered out of the results.
+                disabled_kinds: HashSet<LintKind>,
+                /// Severities that replace [`LintKind::default_severity`].
+                severity_overrides: HashMap<LintKind, Severity>,
+                /// Rules added with [`Self::add_rule`], run after the
+                /// built-in ones.
+                rules: Vec<Box<dyn Rule>>,
             }
 
 
-            impl<T: Dictionary> LintGroup<T> {
+            impl<T: Dictionary + Clone> LintGroup<T> {
                 pub fn new(config: LintGroupConfig, dictionary: T) -> Self {
                     Self {
                         $(
                             [<$linter:snake>]: $linter::default(),
                         )*
-                        spell_check: SpellCheck::new(dictionary),
+                        $(
+                            [<$dict_linter:snake>]: $dict_linter::new(dictionary.clone()),
+                        )*
                         config,
+                        disabled_kinds: HashSet::new(),
+                        severity_overrides: HashMap::new(),
+                        rules: Vec::new(),
                     }
                 }
+            }
+
+            impl<T: Dictionary> LintGroup<T> {
+                /// Report lints of the given kind. All kinds are enabled by
+                /// default.
+                pub fn enable(mut self, kind: LintKind) -> Self {
+                    self.disabled_kinds.remove(&kind);
+                    self
+                }
+
+                /// Stop reporting lints of the given kind, regardless of
+                /// which linter produces them.
+                pub fn disable(mut self, kind: LintKind) -> Self {
+                    self.disabled_kinds.insert(kind);
+                    self
+                }
+
+                pub fn is_kind_enabled(&self, kind: LintKind) -> bool {
+                    !self.disabled_kinds.contains(&kind)
+                }
+
+                /// Report lints of the given kind with a different severity,
+                /// to upgrade or downgrade a whole category.
+                ///
+                /// This replaces whatever severity the linters chose for
+                /// lints of that kind.
+                pub fn with_severity(mut self, kind: LintKind, severity: Severity) -> Self {
+                    self.severity_overrides.insert(kind, severity);
+                    self
+                }
+
+                /// Run a rule of your own alongside the built-in ones, so
+                /// domain-specific checks don't need a fork of Harper.
+                ///
+                /// Its lints go through the same filtering, severity
+                /// overrides and overlap resolution as the built-in ones.
+                /// The rule isn't run at all while its [`Rule::kind`] is
+                /// disabled. Added rules aren't part of [`LintGroupConfig`]
+                /// or [`Self::all_descriptions`].
+                pub fn add_rule(&mut self, rule: Box<dyn Rule>) {
+                    self.rules.push(rule);
+                }
+
+                /// The severity lints of the given kind are reported with,
+                /// unless a linter chose a different one itself.
+                pub fn severity_for(&self, kind: LintKind) -> Severity {
+                    self.severity_overrides
+                        .get(&kind)
+                        .copied()
+                        .unwrap_or_else(|| kind.default_severity())
+                }
+            }
 
+            impl<T: Dictionary> LintGroup<T> {
                 pub fn all_descriptions(&self) -> LintGroupDescriptions<'_> {
                     LintGroupDescriptions {
                         $(
-                            [<$linter:snake>]: self.[<$linter:snake>].description(),
+                            [<$linter:snake>]: Rule::description(&self.[<$linter:snake>]),
+                        )*
+                        $(
+                            [<$dict_linter:snake>]: Rule::description(&self.[<$dict_linter:snake>]),
                         )*
-                        spell_check: self.spell_check.description(),
                     }
                 }
             }
@@ -119,16 +216,35 @@ macro_rules! create_lint_group_config {
 
                     $(
                         if config.[<$linter:snake>].unwrap() {
-                            lints.append(&mut self.[<$linter:snake>].lint(document));
+                            lints.append(&mut self.[<$linter:snake>].check(document));
+                        }
+                    )*
+                    $(
+                        if config.[<$dict_linter:snake>].unwrap() {
+                            lints.append(&mut self.[<$dict_linter:snake>].check(document));
                         }
                     )*
 
-                    if config.spell_check.unwrap() {
-                        lints.append(&mut self.spell_check.lint(document));
+                    for rule in &self.rules {
+                        if self.is_kind_enabled(rule.kind()) {
+                            lints.append(&mut rule.check(document));
+                        }
                     }
 
+                    lints.retain(|lint| self.is_kind_enabled(lint.lint_kind));
+
+                    // Keep the severity each linter chose, unless the user
+                    // asked for a different one for the whole kind.
+                    for lint in &mut lints {
+                        if let Some(severity) = self.severity_overrides.get(&lint.lint_kind) {
+                            lint.severity = *severity;
+                        }
+                    }
 
-                    lints
+                    // Several linters may flag the same problem (e.g. a curated
+                    // phrase and the generic word merger), and overlapping
+                    // lints can't be applied together.
+                    resolve_overlaps(lints)
                 }
 
                 fn description(&self) -> &'static str {
@@ -146,20 +262,33 @@ create_lint_group_config!(
     UnclosedQuotes => true,
     WrongQuotes => false,
     LongSentences => true,
+    PassiveVoice => false,
+    DoubleNegatives => false,
+    PunctuationRepetition => false,
+    Homophones => true,
+    Homoglyphs => true,
+    Abbreviations => false,
+    Apostrophes => true,
+    InclusiveLanguage => false,
+    LineEndings => true,
     RepeatedWords => true,
     Spaces => true,
     Matcher => true,
     CorrectNumberSuffix => true,
     NumberSuffixCapitalization => true,
+    NumberUnits => true,
+    NumberStyle => false,
     MultipleSequentialPronouns => true,
     LinkingVerbs => false,
     AvoidCurses => true,
     TerminatingConjunctions => true,
+    TerminalPunctuation => false,
     EllipsisLength => true,
     DotInitialisms => true,
     BoringWords => false,
     UseGenitive => false,
     ThatWhich => true,
+    TitleCase => false,
     CapitalizePersonalPronouns => true,
     Americas => true,
     ChineseCommunistParty => true,
@@ -170,24 +299,253 @@ create_lint_group_config!(
     MetaNames => true,
     MicrosoftNames => true,
     AppleNames => true,
-    AzureNames => true
+    AzureNames => true;
+    // Linters that need access to the dictionary
+    SpellCheck => true,
+    SplitWords => true,
+    MergeWords => false,
+    NameConsistency => false,
+    RareWords => false
 );
 
-impl<T: Dictionary + Default> Default for LintGroup<T> {
+impl<T: Dictionary + Clone + Default> Default for LintGroup<T> {
     fn default() -> Self {
         Self::new(LintGroupConfig::default(), T::default())
     }
 }
 
+impl<T: Dictionary> LintGroup<T> {
+    /// Flag sentences longer than `max_words` words, instead of
+    /// [`LongSentences::DEFAULT_MAX_WORDS`].
+    pub fn with_max_sentence_words(mut self, max_words: usize) -> Self {
+        self.long_sentences = LongSentences::new(max_words);
+        self
+    }
+
+    /// Choose which number and unit spacing rules to check, for locales with
+    /// different conventions.
+    pub fn with_number_unit_config(mut self, config: NumberUnitConfig) -> Self {
+        self.number_units = NumberUnits::with_config(config);
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::FullDictionary;
+    use crate::{Document, FstDictionary, FullDictionary};
+
+    use super::{LintGroup, LintGroupConfig};
+    use crate::linting::{Lint, LintKind, Linter, Rule, Severity, Suggestion};
+    use crate::TokenStringExt;
 
-    use super::LintGroup;
+    /// Flags every occurrence of "foo", as a stand-in for a third-party rule.
+    struct NoFoo;
+
+    impl Rule for NoFoo {
+        fn check(&self, document: &Document) -> Vec<Lint> {
+            document
+                .iter_words()
+                .filter(|word| document.get_span_content_str(word.span) == "foo")
+                .map(|word| Lint::new(word.span, LintKind::Miscellaneous, Vec::new()))
+                .collect()
+        }
+
+        fn kind(&self) -> LintKind {
+            LintKind::Miscellaneous
+        }
+
+        fn description(&self) -> &'static str {
+            "Flags \"foo\"."
+        }
+    }
 
     #[test]
     fn can_get_all_descriptions() {
         let group = LintGroup::<FullDictionary>::default();
         group.all_descriptions();
     }
+
+    #[test]
+    fn deduplicates_identical_lints() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This is an other test.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict);
+
+        assert_eq!(group.lint(&document).len(), 1);
+    }
+
+    #[test]
+    fn disabled_kinds_are_filtered() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This is an tezt.", &dict);
+        let mut group =
+            LintGroup::new(LintGroupConfig::default(), dict).disable(LintKind::Spelling);
+
+        let lints = group.lint(&document);
+
+        assert!(!lints.is_empty());
+        assert!(lints.iter().all(|lint| !lint.lint_kind.is_spelling()));
+    }
+
+    #[test]
+    fn kinds_can_be_reenabled() {
+        let group = LintGroup::new(LintGroupConfig::default(), FstDictionary::curated())
+            .disable(LintKind::Spelling)
+            .enable(LintKind::Spelling);
+
+        assert!(group.is_kind_enabled(LintKind::Spelling));
+    }
+
+    #[test]
+    fn lints_are_sorted_by_position() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("this is an tezt.  there are too  spaces.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict);
+
+        let lints = group.lint(&document);
+
+        assert!(lints.windows(2).all(|w| w[0].span.start <= w[1].span.start));
+    }
+
+    #[test]
+    fn max_sentence_words_is_configurable() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This sentence has five words.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::none(), dict);
+        group.config.long_sentences = Some(true);
+
+        assert!(group.lint(&document).is_empty());
+
+        let mut group = group.with_max_sentence_words(4);
+        let lints = group.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].lint_kind, LintKind::Readability);
+        assert_eq!(lints[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn spelling_corrections_win_over_splits() {
+        let dict = FstDictionary::curated();
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict.clone());
+
+        for (typo, correction) in [("adress", "address"), ("occured", "occurred")] {
+            let document = Document::new_markdown(&format!("The {typo} is here."), &dict);
+            let lints = group.lint(&document);
+
+            assert_eq!(lints.len(), 1);
+            assert!(lints[0]
+                .suggestions
+                .contains(&Suggestion::ReplaceWith(correction.chars().collect())));
+        }
+    }
+
+    #[test]
+    fn severity_defaults_to_lint_kind() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This is a tezt.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict);
+
+        let lints = group.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn runs_added_rules() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This foo is a tezt.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict);
+        group.add_rule(Box::new(NoFoo));
+
+        let lints = group.lint(&document);
+
+        assert_eq!(lints.len(), 2);
+        assert_eq!(lints[0].lint_kind, LintKind::Miscellaneous);
+        assert!(lints[1].lint_kind.is_spelling());
+    }
+
+    #[test]
+    fn added_rules_respect_disabled_kinds() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This foo is fine.", &dict);
+        let mut group =
+            LintGroup::new(LintGroupConfig::default(), dict).disable(LintKind::Miscellaneous);
+        group.add_rule(Box::new(NoFoo));
+
+        assert!(group.lint(&document).is_empty());
+    }
+
+    #[test]
+    fn skips_rules_of_disabled_kinds() {
+        struct Unreachable;
+
+        impl Rule for Unreachable {
+            fn check(&self, _document: &Document) -> Vec<Lint> {
+                unreachable!("Rules of disabled kinds shouldn't run.")
+            }
+
+            fn kind(&self) -> LintKind {
+                LintKind::Readability
+            }
+
+            fn description(&self) -> &'static str {
+                "Never runs."
+            }
+        }
+
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This is fine.", &dict);
+        let mut group =
+            LintGroup::new(LintGroupConfig::default(), dict).disable(LintKind::Readability);
+        group.add_rule(Box::new(Unreachable));
+
+        assert!(group.lint(&document).is_empty());
+    }
+
+    #[test]
+    fn keeps_severity_chosen_by_linter() {
+        struct LoudFoo;
+
+        impl Rule for LoudFoo {
+            fn check(&self, document: &Document) -> Vec<Lint> {
+                NoFoo
+                    .check(document)
+                    .into_iter()
+                    .map(|lint| Lint {
+                        severity: Severity::Error,
+                        ..lint
+                    })
+                    .collect()
+            }
+
+            fn kind(&self) -> LintKind {
+                LintKind::Miscellaneous
+            }
+
+            fn description(&self) -> &'static str {
+                "Flags \"foo\" loudly."
+            }
+        }
+
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This foo is fine.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict);
+        group.add_rule(Box::new(LoudFoo));
+
+        assert_eq!(group.lint(&document)[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn severity_can_be_overridden() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_markdown("This is a tezt.", &dict);
+        let mut group = LintGroup::new(LintGroupConfig::default(), dict)
+            .with_severity(LintKind::Spelling, Severity::Hint);
+
+        let lints = group.lint(&document);
+
+        assert_eq!(lints[0].severity, Severity::Hint);
+    }
 }
@@ -1,29 +1,40 @@
-use super::{Lint, LintKind, Linter};
-use crate::token::TokenStringExt;
-use crate::{Document, Span};
+use super::{Lint, LintKind, Rule};
+use crate::Document;
 
 /// Detect and warn that the sentence is too long.
-#[derive(Debug, Clone, Copy, Default)]
-pub struct LongSentences;
-
-impl Linter for LongSentences {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
-        let mut output = Vec::new();
-
-        for sentence in document.iter_sentences() {
-            let word_count = sentence.iter_words().count();
-
-            if word_count > 40 {
-                output.push(Lint {
-                    span: Span::new(sentence[0].span.start, sentence.last().unwrap().span.end),
-                    lint_kind: LintKind::Readability,
-                    message: format!("This sentence is {} words long.", word_count),
-                    ..Default::default()
-                })
-            }
-        }
+#[derive(Debug, Clone, Copy)]
+pub struct LongSentences {
+    max_words: usize,
+}
+
+impl LongSentences {
+    /// The sentence length, in words, above which sentences are flagged by
+    /// default.
+    pub const DEFAULT_MAX_WORDS: usize = 40;
+
+    /// Flag sentences that are more than `max_words` words long.
+    pub fn new(max_words: usize) -> Self {
+        Self { max_words }
+    }
 
-        output
+    pub fn max_words(&self) -> usize {
+        self.max_words
+    }
+}
+
+impl Default for LongSentences {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_WORDS)
+    }
+}
+
+impl Rule for LongSentences {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        long_sentence_check(document, self.max_words)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
     }
 
     fn description(&self) -> &'static str {
@@ -31,3 +42,86 @@ impl Linter for LongSentences {
 "
     }
 }
+
+/// Flag every sentence in `document` that is more than `max_words` words
+/// long.
+///
+/// The lints cover the whole sentence, as given by [`Document::sentences`],
+/// and carry no suggestions.
+pub fn long_sentence_check(document: &Document, max_words: usize) -> Vec<Lint> {
+    let mut output = Vec::new();
+    let mut words = document
+        .tokens()
+        .filter(|token| token.kind.is_word())
+        .peekable();
+
+    for sentence in document.sentences() {
+        let mut word_count = 0;
+
+        while let Some(word) = words.next_if(|word| word.span.start < sentence.end) {
+            if word.span.start >= sentence.start {
+                word_count += 1;
+            }
+        }
+
+        if word_count > max_words {
+            output.push(Lint {
+                span: sentence,
+                lint_kind: LintKind::Readability,
+                message: format!("This sentence is {} words long.", word_count),
+                severity: LintKind::Readability.default_severity(),
+                ..Default::default()
+            })
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{long_sentence_check, LongSentences};
+    use crate::linting::tests::assert_lint_count;
+    use crate::linting::{LintKind, Severity};
+    use crate::{Document, Span};
+
+    #[test]
+    fn allows_short_sentences() {
+        assert_lint_count(
+            "This is short. So is this one.",
+            LongSentences::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_only_the_long_sentence() {
+        let text = "Short one. This one has exactly six words. Fine.";
+        let document = Document::new_plain_english_curated(text);
+
+        let lints = long_sentence_check(&document, 5);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span, Span::new(11, 42));
+        assert_eq!(lints[0].lint_kind, LintKind::Readability);
+        assert_eq!(lints[0].severity, Severity::Info);
+        assert!(lints[0].suggestions.is_empty());
+    }
+
+    #[test]
+    fn limit_is_inclusive() {
+        let document = Document::new_plain_english_curated("One two three four five.");
+
+        assert!(long_sentence_check(&document, 5).is_empty());
+        assert_eq!(long_sentence_check(&document, 4).len(), 1);
+    }
+
+    #[test]
+    fn defaults_to_forty_words() {
+        let forty = "word ".repeat(40);
+        let forty_one = "word ".repeat(41);
+
+        assert_lint_count(&forty, LongSentences::default(), 0);
+        assert_lint_count(&forty_one, LongSentences::default(), 1);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::linting::{Lint, LintKind, Linter, Suggestion};
+use crate::linting::{Lint, LintKind, Suggestion};
 use crate::{CharString, Document, Punctuation, Span, Token, TokenKind, WordMetadata};
 
 #[derive(Debug, PartialEq, PartialOrd, Clone)]
@@ -312,8 +312,8 @@ impl Default for Matcher {
     }
 }
 
-impl Linter for Matcher {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl super::Rule for Matcher {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
         let mut match_tokens = Vec::new();
@@ -351,6 +351,8 @@ impl Linter for Matcher {
                             trigger.replace_with.iter().collect::<String>()
                         ),
                         priority: 15,
+                        severity: LintKind::Miscellaneous.default_severity(),
+                        ..Default::default()
                     })
                 }
             }
@@ -359,6 +361,10 @@ impl Linter for Matcher {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "A collection of curated rules. A catch-all that will be removed in the future."
     }
@@ -366,7 +372,8 @@ impl Linter for Matcher {
 
 #[cfg(test)]
 mod tests {
-    use super::{Linter, Matcher};
+    use super::Matcher;
+    use crate::linting::Linter;
     use crate::Document;
 
     #[test]
@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{BigramModel, Dictionary, Document, Span, TokenStringExt};
+
+/// How many times a pair must appear in the [`BigramModel`] to count as
+/// common, and so be left split.
+const MIN_COMMON_COUNT: u32 = 10;
+
+/// Flag pairs of words that should be a single word, like "note book".
+///
+/// A pair is only flagged if it joins into a dictionary word and isn't a
+/// common pair in `bigrams`, so phrasal verbs like "set up" and "log in"
+/// are left alone.
+pub fn merge_word_check(
+    document: &Document,
+    dictionary: &impl Dictionary,
+    bigrams: &BigramModel,
+) -> Vec<Lint> {
+    let mut lints = Vec::new();
+
+    for chunk in document.iter_chunks() {
+        let mut iter = chunk.iter_word_indices().zip(chunk.iter_words()).peekable();
+
+        while let (Some((idx_a, tok_a)), Some((idx_b, tok_b))) = (iter.next(), iter.peek()) {
+            // Only merge words separated by plain whitespace.
+            let intervening_tokens = &chunk[idx_a + 1..*idx_b];
+            if intervening_tokens.is_empty()
+                || intervening_tokens.iter().any(|t| !t.kind.is_whitespace())
+            {
+                continue;
+            }
+
+            let word_a = document.get_span_content(tok_a.span);
+            let word_b = document.get_span_content(tok_b.span);
+
+            if bigrams.count(word_a, word_b) >= MIN_COMMON_COUNT {
+                continue;
+            }
+
+            let mut merged = word_a.to_vec();
+            merged.extend_from_slice(word_b);
+
+            if !dictionary.contains_word(&merged) {
+                continue;
+            }
+
+            lints.push(Lint {
+                // Spanning both words (and the whitespace between them)
+                // means that applying the suggestion removes the space.
+                span: Span::new(tok_a.span.start, tok_b.span.end),
+                lint_kind: LintKind::Spelling,
+                message: format!("Did you mean “{}”?", merged.iter().collect::<String>()),
+                suggestions: vec![Suggestion::ReplaceWith(merged)],
+                priority: 31,
+                severity: LintKind::Spelling.default_severity(),
+                ..Default::default()
+            })
+        }
+    }
+
+    lints
+}
+
+/// Looks for pairs of words that should be a single word, like "note book".
+///
+/// Off by default, since many pairs that join into a word are also correct
+/// on their own.
+pub struct MergeWords<T>
+where
+    T: Dictionary,
+{
+    dictionary: T,
+    /// Pairs that are common as two words, like "set up", are never merged.
+    bigrams: Arc<BigramModel>,
+}
+
+impl<T: Dictionary> MergeWords<T> {
+    pub fn new(dictionary: T) -> Self {
+        Self {
+            dictionary,
+            bigrams: BigramModel::curated(),
+        }
+    }
+
+    /// Use different bigram counts, instead of [`BigramModel::curated`], to
+    /// decide which pairs are common as two words.
+    pub fn with_bigrams(mut self, bigrams: Arc<BigramModel>) -> Self {
+        self.bigrams = bigrams;
+        self
+    }
+}
+
+impl<T: Dictionary> Rule for MergeWords<T> {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        merge_word_check(document, &self.dictionary, &self.bigrams)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Spelling
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for pairs of words that are more likely to be a single word with a stray space in the middle."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{merge_word_check, MergeWords};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::Suggestion;
+    use crate::{BigramModel, Document, FstDictionary};
+
+    #[test]
+    fn merges_note_book() {
+        assert_suggestion_result(
+            "I bought a new note book.",
+            MergeWords::new(FstDictionary::curated()),
+            "I bought a new notebook.",
+        );
+    }
+
+    #[test]
+    fn merges_across_multiple_spaces() {
+        assert_suggestion_result(
+            "Where is my note  book?",
+            MergeWords::new(FstDictionary::curated()),
+            "Where is my notebook?",
+        );
+    }
+
+    #[test]
+    fn ignores_common_bigrams() {
+        assert_lint_count(
+            "There is a way in to the house, and so on.",
+            MergeWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_phrasal_verbs() {
+        assert_lint_count(
+            "Set up the server, log in, back up the files, Check out the branch, go on line, we can not wait, shut down, Look up the docs.",
+            MergeWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn bigrams_are_configurable() {
+        let document = Document::new_plain_english_curated("Set up the note book.");
+        let bigrams = BigramModel::parse("note\tbook\t50\n");
+
+        let lints = merge_word_check(&document, &FstDictionary::curated(), &bigrams);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            lints[0].suggestions,
+            vec![Suggestion::ReplaceWith("Setup".chars().collect())]
+        );
+    }
+
+    #[test]
+    fn ignores_words_across_punctuation() {
+        assert_lint_count(
+            "Take the note. Book it.",
+            MergeWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+}
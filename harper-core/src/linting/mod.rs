@@ -1,67 +1,128 @@
+mod abbreviations;
 mod an_a;
+mod apostrophes;
+mod autocorrect;
 mod avoid_curses;
 mod boring_words;
 mod capitalize_personal_pronouns;
 mod correct_number_suffix;
 mod dot_initialisms;
+mod double_negatives;
 mod ellipsis_length;
+mod homoglyphs;
+mod homophones;
+mod inclusive_language;
+mod line_endings;
 mod linking_verbs;
 mod lint;
 mod lint_group;
 mod long_sentences;
 mod matcher;
+mod merge_words;
 mod multiple_sequential_pronouns;
+mod name_consistency;
+mod number_style;
 mod number_suffix_capitalization;
+mod number_units;
+mod passive_voice;
 mod pattern_linter;
 mod proper_noun_capitalization_linters;
+mod punctuation_repetition;
+mod rare_words;
 mod repeated_words;
+mod rule;
 mod sentence_capitalization;
 mod spaces;
 mod spell_check;
 mod spelled_numbers;
+mod split_words;
+mod terminal_punctuation;
 mod terminating_conjunctions;
 mod that_which;
+mod title_case;
 mod unclosed_quotes;
 mod use_genitive;
 mod wrong_quotes;
 
+pub use abbreviations::{abbreviation_check, AbbreviationTable, Abbreviations, WritingStyle};
 pub use an_a::AnA;
+pub use apostrophes::{apostrophe_check, Apostrophes};
+pub use autocorrect::{autocorrect, AutocorrectOptions};
 pub use avoid_curses::AvoidCurses;
 pub use boring_words::BoringWords;
 pub use capitalize_personal_pronouns::CapitalizePersonalPronouns;
 pub use correct_number_suffix::CorrectNumberSuffix;
 pub use dot_initialisms::DotInitialisms;
+pub use double_negatives::{double_negative_check, DoubleNegatives};
 pub use ellipsis_length::EllipsisLength;
+pub use homoglyphs::{homoglyph_check, Homoglyphs};
+pub use homophones::{homophone_check, parse_homophone_rules, HomophoneRule, Homophones};
+pub use inclusive_language::{inclusive_language_check, InclusiveLanguage, InclusiveLanguageTable};
+pub use line_endings::{line_ending_check, LineEndingStyle, LineEndings};
 pub use linking_verbs::LinkingVerbs;
-pub use lint::{Lint, LintKind, Suggestion};
+pub use lint::{
+    apply_lints, resolve_overlaps, sort_lints, Lint, LintDiff, LintKind, Severity, Suggestion,
+};
 pub use lint_group::{LintGroup, LintGroupConfig};
-pub use long_sentences::LongSentences;
+pub use long_sentences::{long_sentence_check, LongSentences};
 pub use matcher::Matcher;
+pub use merge_words::{merge_word_check, MergeWords};
 pub use multiple_sequential_pronouns::MultipleSequentialPronouns;
+pub use name_consistency::{name_consistency_check, NameConsistency};
+pub use number_style::{number_style_check, NumberStyle, NumberStylePolicy};
 pub use number_suffix_capitalization::NumberSuffixCapitalization;
+pub use number_units::{number_unit_check, NumberUnitConfig, NumberUnits};
+pub use passive_voice::{passive_voice_check, PassiveVoice};
 pub use pattern_linter::PatternLinter;
 pub use proper_noun_capitalization_linters::{
     AmazonNames, Americas, AppleNames, AzureNames, ChineseCommunistParty, GoogleNames, Holidays,
     MetaNames, MicrosoftNames, UnitedOrganizations,
 };
+pub use punctuation_repetition::{punctuation_repetition_check, PunctuationRepetition};
+pub use rare_words::{rare_word_check, RareWords, SynonymTable};
 pub use repeated_words::RepeatedWords;
+pub use rule::Rule;
 pub use sentence_capitalization::SentenceCapitalization;
 pub use spaces::Spaces;
-pub use spell_check::SpellCheck;
+pub use spell_check::{
+    spell_check_diff, spell_check_reader, SpellCheck, SpellCheckConfig, SpellingVariant,
+};
 pub use spelled_numbers::SpelledNumbers;
+pub use split_words::SplitWords;
+pub use terminal_punctuation::{terminal_punctuation_check, TerminalPunctuation};
 pub use terminating_conjunctions::TerminatingConjunctions;
 pub use that_which::ThatWhich;
+pub use title_case::{title_case_check, TitleCase, TitleCaseStyle};
 pub use unclosed_quotes::UnclosedQuotes;
 pub use use_genitive::UseGenitive;
 pub use wrong_quotes::WrongQuotes;
 
 use crate::Document;
 
+/// Something that finds problems in a [`Document`].
+///
+/// [`Linter::lint`] should return its lints sorted with [`sort_lints`], and
+/// produce the same lints, with the same suggestions in the same order, every
+/// time it is run on the same document, so its output can be compared
+/// between runs.
+///
+/// Checks that don't need to change while linting should implement [`Rule`]
+/// instead, which makes them a [`Linter`] too.
 #[cfg(not(feature = "concurrent"))]
 pub trait Linter {
     fn lint(&mut self, document: &Document) -> Vec<Lint>;
     fn description(&self) -> &str;
 }
+
+/// Something that finds problems in a [`Document`].
+///
+/// [`Linter::lint`] should return its lints sorted with [`sort_lints`], and
+/// produce the same lints, with the same suggestions in the same order, every
+/// time it is run on the same document, so its output can be compared
+/// between runs.
+///
+/// Checks that don't need to change while linting should implement [`Rule`]
+/// instead, which makes them a [`Linter`] too.
 #[cfg(feature = "concurrent")]
 pub trait Linter: Send + Sync {
     fn lint(&mut self, document: &Document) -> Vec<Lint>;
@@ -59,9 +59,15 @@ impl PatternLinter for MultipleSequentialPronouns {
             message: "There are too many personal pronouns in sequence here.".to_owned(),
             priority: 63,
             suggestions,
+            severity: LintKind::Repetition.default_severity(),
+            ..Default::default()
         }
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Repetition
+    }
+
     fn description(&self) -> &'static str {
         "When editing work to change point of view (i.e. first-person or third-person) it is common to add pronouns while neglecting to remove old ones. This rule catches cases where you have multiple disparate pronouns in sequence."
     }
@@ -0,0 +1,173 @@
+use hashbrown::HashMap;
+
+use super::{sort_lints, Lint, LintKind, Rule, Suggestion};
+use crate::spell::{metaphone, suggestion_edit_distance};
+use crate::{CharString, CharStringExt, Dictionary, Document, Span, TokenStringExt};
+
+/// How far apart two spellings may be and still be counted as the same name.
+const MAX_VARIANT_DISTANCE: u8 = 2;
+
+/// Flag names that are spelled more than one way within a document, like
+/// "Siobhan" and "Siobhain", suggesting the spelling used most often.
+///
+/// Only capitalized words the dictionary doesn't know, with no dictionary
+/// word a single edit away, are treated as names.
+/// Names are grouped by how they sound (their Metaphone code), and spellings
+/// in a group are only considered variants of each other if they are within
+/// a couple of edits.
+/// When variants are used equally often, the one that appears first wins.
+pub fn name_consistency_check(document: &Document, dictionary: &impl Dictionary) -> Vec<Lint> {
+    // Every occurrence of each name, grouped by how it sounds.
+    let mut groups: HashMap<CharString, Vec<(CharString, Span)>> = HashMap::new();
+
+    for word in document.iter_words() {
+        let chars = document.get_span_content(word.span);
+
+        if !is_name_like(chars) || dictionary.contains_word(chars) {
+            continue;
+        }
+
+        if !dictionary.fuzzy_match(chars, 1, 1).is_empty() {
+            continue;
+        }
+
+        groups
+            .entry(metaphone(chars))
+            .or_default()
+            .push((chars.into(), word.span));
+    }
+
+    let mut output = Vec::new();
+
+    for occurrences in groups.values() {
+        let mut counts: Vec<(&CharString, usize)> = Vec::new();
+
+        for (spelling, _) in occurrences {
+            match counts.iter_mut().find(|(known, _)| *known == spelling) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((spelling, 1)),
+            }
+        }
+
+        if counts.len() < 2 {
+            continue;
+        }
+
+        // `max_by_key` keeps the last maximum, so search in reverse to prefer
+        // the spelling that appeared first.
+        let Some((preferred, _)) = counts.iter().rev().max_by_key(|(_, count)| *count) else {
+            continue;
+        };
+
+        for (spelling, span) in occurrences {
+            if spelling == *preferred
+                || suggestion_edit_distance(spelling, preferred) > MAX_VARIANT_DISTANCE
+            {
+                continue;
+            }
+
+            output.push(Lint {
+                span: *span,
+                lint_kind: LintKind::Spelling,
+                suggestions: vec![Suggestion::ReplaceWith(preferred.to_vec())],
+                message: format!(
+                    "This name is spelled “{}” elsewhere in the document.",
+                    preferred.to_string()
+                ),
+                priority: 63,
+                severity: LintKind::Spelling.default_severity(),
+                ..Default::default()
+            });
+        }
+    }
+
+    sort_lints(&mut output);
+
+    output
+}
+
+/// Looks for names that are spelled inconsistently within a document.
+pub struct NameConsistency<T>
+where
+    T: Dictionary,
+{
+    dictionary: T,
+}
+
+impl<T: Dictionary> NameConsistency<T> {
+    pub fn new(dictionary: T) -> Self {
+        Self { dictionary }
+    }
+}
+
+impl<T: Dictionary> Rule for NameConsistency<T> {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        name_consistency_check(document, &self.dictionary)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Spelling
+    }
+
+    fn description(&self) -> &'static str {
+        "Names the dictionary doesn't know are easy to spell two different ways in one document. This rule flags the less common spelling."
+    }
+}
+
+/// Capitalized, with at least three letters, and not in all caps like an
+/// acronym.
+fn is_name_like(word: &[char]) -> bool {
+    word.len() >= 3 && word[0].is_uppercase() && word[1..].iter().any(|c| c.is_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{name_consistency_check, NameConsistency};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn suggests_most_common_spelling() {
+        assert_suggestion_result(
+            "Siobhan arrived. Later, Siobhain left. Siobhan came back.",
+            NameConsistency::new(FstDictionary::curated()),
+            "Siobhan arrived. Later, Siobhan left. Siobhan came back.",
+        );
+    }
+
+    #[test]
+    fn first_spelling_wins_ties() {
+        let document = Document::new_plain_english_curated("Siobhain met Siobhan.");
+        let lints = name_consistency_check(&document, &FstDictionary::curated());
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].span.start, 13);
+    }
+
+    #[test]
+    fn allows_consistent_names() {
+        assert_lint_count(
+            "Siobhan arrived. Siobhan left.",
+            NameConsistency::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_different_names() {
+        assert_lint_count(
+            "Zbigniew met Siobhan.",
+            NameConsistency::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_lowercase_words() {
+        assert_lint_count(
+            "siobhan met siobhain.",
+            NameConsistency::new(FstDictionary::curated()),
+            0,
+        );
+    }
+}
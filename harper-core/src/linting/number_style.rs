@@ -0,0 +1,445 @@
+use serde::{Deserialize, Serialize};
+
+use super::number_units::UNITS;
+use super::spelled_numbers::{parse_spelled_number, spell_out_number};
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{CharStringExt, Document, Punctuation, Span, Token, TokenKind};
+
+/// Words that mark a number next to them as part of a date, like "March 3".
+const MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "jan",
+    "feb",
+    "mar",
+    "apr",
+    "jun",
+    "jul",
+    "aug",
+    "sep",
+    "sept",
+    "oct",
+    "nov",
+    "dec",
+];
+
+/// Words that mark the number after them as a label rather than a quantity,
+/// like "version 2" or "chapter 3".
+const LABELS: &[&str] = &[
+    "version", "v", "release", "chapter", "page", "section", "step", "figure", "table", "part",
+    "volume", "no",
+];
+
+/// Spelled-out units and times of day, which are written with digits, in
+/// addition to the abbreviations in [`UNITS`].
+const UNIT_WORDS: &[&str] = &[
+    "percent",
+    "degrees",
+    "inch",
+    "inches",
+    "foot",
+    "feet",
+    "mile",
+    "miles",
+    "meter",
+    "meters",
+    "metre",
+    "metres",
+    "kilometers",
+    "kilometres",
+    "gram",
+    "grams",
+    "kilograms",
+    "pound",
+    "pounds",
+    "px",
+    "am",
+    "pm",
+    "a.m.",
+    "p.m.",
+];
+
+/// When [`number_style_check`] prefers numbers spelled out as words, and
+/// when it prefers digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NumberStylePolicy {
+    /// Spell out whole numbers below the threshold, like "three", and use
+    /// digits from the threshold on, like "12".
+    SpellOutBelow(u64),
+    /// Always use digits.
+    Digits,
+}
+
+/// Spell out numbers below ten, as most style guides recommend.
+impl Default for NumberStylePolicy {
+    fn default() -> Self {
+        Self::SpellOutBelow(10)
+    }
+}
+
+/// Flag numerals that `policy` would spell out, like "3", and number words
+/// it would write with digits, like "twelve".
+///
+/// Only numbers up to one hundred are converted. Numbers that are part of a
+/// date, time, version or measurement (like "March 3", "3:30", "v2" or
+/// "5 kg") are left alone, as is "one", which is usually a pronoun.
+pub fn number_style_check(document: &Document, policy: NumberStylePolicy) -> Vec<Lint> {
+    let threshold = match policy {
+        NumberStylePolicy::SpellOutBelow(threshold) => threshold,
+        NumberStylePolicy::Digits => 0,
+    };
+
+    let tokens = document.get_tokens();
+    let mut output = Vec::new();
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let found = numeral_lint(document, tokens, idx, threshold)
+            .map(|lint| (lint, 1))
+            .or_else(|| number_words_lint(document, tokens, idx, threshold));
+
+        match found {
+            Some((lint, len)) => {
+                output.push(lint);
+                idx += len;
+            }
+            None => idx += 1,
+        }
+    }
+
+    output
+}
+
+/// Looks for numbers that are written inconsistently with a
+/// [`NumberStylePolicy`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NumberStyle {
+    policy: NumberStylePolicy,
+}
+
+impl NumberStyle {
+    pub fn new(policy: NumberStylePolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Rule for NumberStyle {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        number_style_check(document, self.policy)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
+    fn description(&self) -> &'static str {
+        "Style guides recommend spelling out small numbers, like \"three\", and using digits for larger ones, like \"12\". This rule flags numbers that don't follow that pattern."
+    }
+}
+
+fn numeral_lint(document: &Document, tokens: &[Token], idx: usize, threshold: u64) -> Option<Lint> {
+    let token = &tokens[idx];
+
+    let TokenKind::Number(value, None) = token.kind else {
+        return None;
+    };
+
+    let value = value.into_inner();
+
+    if value.fract() != 0.0 || value < 0.0 || value > 100.0 || value >= threshold as f64 {
+        return None;
+    }
+
+    if in_special_context(document, tokens, idx) {
+        return None;
+    }
+
+    let mut words: Vec<char> = spell_out_number(value as u64)?.chars().collect();
+
+    if starts_sentence(tokens, idx) {
+        words[0] = words[0].to_ascii_uppercase();
+    }
+
+    Some(Lint {
+        span: token.span,
+        lint_kind: LintKind::Readability,
+        suggestions: vec![Suggestion::ReplaceWith(words)],
+        message: format!("Spell out numbers below {threshold}."),
+        severity: LintKind::Readability.default_severity(),
+        ..Default::default()
+    })
+}
+
+fn number_words_lint(
+    document: &Document,
+    tokens: &[Token],
+    idx: usize,
+    threshold: u64,
+) -> Option<(Lint, usize)> {
+    let (value, len) = parse_number_words(document, &tokens[idx..])?;
+
+    // "One" is more often a pronoun than a number.
+    if value < threshold || (len == 1 && value == 1) {
+        return None;
+    }
+
+    // Sentences shouldn't start with digits.
+    if starts_sentence(tokens, idx) {
+        return None;
+    }
+
+    let message = if threshold == 0 {
+        "Use digits for numbers.".to_string()
+    } else {
+        format!("Use digits for numbers of {threshold} or more.")
+    };
+
+    Some((
+        Lint {
+            span: Span::new(tokens[idx].span.start, tokens[idx + len - 1].span.end),
+            lint_kind: LintKind::Readability,
+            suggestions: vec![Suggestion::ReplaceWith(value.to_string().chars().collect())],
+            message,
+            severity: LintKind::Readability.default_severity(),
+            ..Default::default()
+        },
+        len,
+    ))
+}
+
+/// Parse a number written as words at the start of `tokens`, like "seven",
+/// "twenty-five" or "one hundred", returning its value and how many tokens
+/// it covers.
+fn parse_number_words(document: &Document, tokens: &[Token]) -> Option<(u64, usize)> {
+    let first = tokens.first()?;
+
+    if !first.kind.is_word() {
+        return None;
+    }
+
+    let first_word = document.get_span_content(first.span).to_lower().to_string();
+
+    if let [_, joiner, second, ..] = tokens {
+        let joiner = match joiner.kind {
+            TokenKind::Punctuation(Punctuation::Hyphen) => Some('-'),
+            TokenKind::Space(1) => Some(' '),
+            _ => None,
+        };
+
+        if let (Some(joiner), true) = (joiner, second.kind.is_word()) {
+            let second_word = document.get_span_content(second.span).to_lower();
+            let joined = format!("{first_word}{joiner}{}", second_word.to_string());
+
+            if let Some(value) = parse_spelled_number(&joined) {
+                return Some((value, 3));
+            }
+        }
+    }
+
+    parse_spelled_number(&first_word).map(|value| (value, 1))
+}
+
+/// Whether the token at `idx` is the first in its sentence.
+fn starts_sentence(tokens: &[Token], idx: usize) -> bool {
+    tokens[..idx]
+        .iter()
+        .rev()
+        .find(|token| !token.kind.is_whitespace())
+        .is_none_or(|token| token.kind.is_sentence_terminator())
+}
+
+/// Whether the number at `idx` is part of a date, time, version, measurement
+/// or something similar, where it shouldn't be converted.
+fn in_special_context(document: &Document, tokens: &[Token], idx: usize) -> bool {
+    // Glued to something before it, like "$5", "v2" or the "3" of "2.3".
+    let glued_before = idx.checked_sub(1).is_some_and(|prev| {
+        let prev = &tokens[prev];
+        !prev.kind.is_whitespace() && !is_opening(prev)
+    });
+
+    // Glued to something after it, like "5kg", "3:30" or "1.2.3", but not
+    // to punctuation that just ends a clause.
+    let glued_after = tokens.get(idx + 1).is_some_and(|next| {
+        if next.kind.is_whitespace() {
+            return false;
+        }
+
+        let continues = tokens
+            .get(idx + 2)
+            .is_some_and(|after| !after.kind.is_whitespace());
+
+        !is_closing(next) || continues
+    });
+
+    if glued_before || glued_after {
+        return true;
+    }
+
+    let word_at = |token: Option<&Token>| {
+        token
+            .filter(|token| token.kind.is_word())
+            .map(|token| document.get_span_content(token.span).to_string())
+    };
+
+    let prev = word_at(
+        idx.checked_sub(2)
+            .filter(|_| tokens[idx - 1].kind.is_space())
+            .map(|prev| &tokens[prev]),
+    );
+    let next = word_at(
+        tokens
+            .get(idx + 1)
+            .filter(|token| token.kind.is_space())
+            .and_then(|_| tokens.get(idx + 2)),
+    );
+
+    let prev_lower = prev.as_deref().map(str::to_lowercase);
+    let next_lower = next.as_deref().map(str::to_lowercase);
+
+    prev_lower
+        .as_deref()
+        .is_some_and(|word| MONTHS.contains(&word) || LABELS.contains(&word))
+        || next_lower
+            .as_deref()
+            .is_some_and(|word| MONTHS.contains(&word) || UNIT_WORDS.contains(&word))
+        || next.as_deref().is_some_and(|word| UNITS.contains(&word))
+}
+
+fn is_opening(token: &Token) -> bool {
+    matches!(
+        token.kind,
+        TokenKind::Punctuation(Punctuation::OpenRound | Punctuation::Quote(_))
+    )
+}
+
+fn is_closing(token: &Token) -> bool {
+    matches!(
+        token.kind,
+        TokenKind::Punctuation(
+            Punctuation::Period
+                | Punctuation::Comma
+                | Punctuation::Bang
+                | Punctuation::Question
+                | Punctuation::Semicolon
+                | Punctuation::CloseRound
+                | Punctuation::Quote(_)
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number_style_check, NumberStyle, NumberStylePolicy};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn spells_out_small_numbers() {
+        assert_suggestion_result(
+            "We adopted 3 cats.",
+            NumberStyle::default(),
+            "We adopted three cats.",
+        );
+    }
+
+    #[test]
+    fn capitalizes_at_sentence_start() {
+        assert_suggestion_result(
+            "3 cats were adopted.",
+            NumberStyle::default(),
+            "Three cats were adopted.",
+        );
+    }
+
+    #[test]
+    fn uses_digits_for_large_numbers() {
+        assert_suggestion_result(
+            "We adopted twelve cats.",
+            NumberStyle::default(),
+            "We adopted 12 cats.",
+        );
+        assert_suggestion_result(
+            "We adopted twenty-five cats.",
+            NumberStyle::default(),
+            "We adopted 25 cats.",
+        );
+    }
+
+    #[test]
+    fn allows_consistent_numbers() {
+        assert_lint_count(
+            "We adopted three cats and 12 dogs.",
+            NumberStyle::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn digits_policy_converts_every_word() {
+        assert_suggestion_result(
+            "We adopted three cats.",
+            NumberStyle::new(NumberStylePolicy::Digits),
+            "We adopted 3 cats.",
+        );
+        assert_lint_count(
+            "We adopted 3 cats.",
+            NumberStyle::new(NumberStylePolicy::Digits),
+            0,
+        );
+    }
+
+    #[test]
+    fn leaves_one_alone() {
+        assert_lint_count(
+            "Only one of them came.",
+            NumberStyle::new(NumberStylePolicy::Digits),
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_dates_times_versions_and_measurements() {
+        for source in [
+            "The release is on March 3.",
+            "It happened on 4 July.",
+            "Meet me at 3:30 today.",
+            "Upgrade to version 2 first.",
+            "It weighs 5 kg in total.",
+            "The box is 4kg heavier.",
+            "Install 1.2.3 instead.",
+            "Prices rose 5 percent.",
+            "It costs $5 now.",
+        ] {
+            assert_lint_count(source, NumberStyle::default(), 0);
+        }
+    }
+
+    #[test]
+    fn skips_ordinals() {
+        assert_lint_count("She came 3rd in the race.", NumberStyle::default(), 0);
+    }
+
+    #[test]
+    fn threshold_is_configurable() {
+        let document = Document::new_plain_english_curated("We saw fifteen birds and 12 bats.");
+
+        assert_eq!(
+            number_style_check(&document, NumberStylePolicy::SpellOutBelow(20)).len(),
+            1
+        );
+        assert_eq!(
+            number_style_check(&document, NumberStylePolicy::default()).len(),
+            1
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 use crate::token::TokenStringExt;
 use crate::{Document, Span, TokenKind};
 
@@ -6,8 +6,8 @@ use crate::{Document, Span, TokenKind};
 #[derive(Debug, Clone, Copy, Default)]
 pub struct NumberSuffixCapitalization;
 
-impl Linter for NumberSuffixCapitalization {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for NumberSuffixCapitalization {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut output = Vec::new();
 
         for number_tok in document.iter_numbers() {
@@ -26,6 +26,7 @@ impl Linter for NumberSuffixCapitalization {
                     suggestions: vec![Suggestion::ReplaceWith(
                         chars.iter().map(|c| c.to_ascii_lowercase()).collect(),
                     )],
+                    severity: LintKind::Capitalization.default_severity(),
                     ..Default::default()
                 })
             }
@@ -34,6 +35,10 @@ impl Linter for NumberSuffixCapitalization {
         output
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Capitalization
+    }
+
     fn description(&self) -> &'static str {
         "You should never capitalize number suffixes."
     }
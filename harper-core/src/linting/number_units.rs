@@ -0,0 +1,221 @@
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{Document, Punctuation, Span, Token, TokenKind};
+
+/// Units that should be separated from their quantity by a space, as in
+/// "5 kg".
+///
+/// Matched case-sensitively, since "mb" and "MB" mean different things.
+/// Units that double as common suffixes (like "s" in "1990s") are left out.
+pub(super) const UNITS: &[&str] = &[
+    "mg", "g", "kg", "mm", "cm", "km", "ml", "mL", "L", "ms", "Hz", "kHz", "MHz", "GHz", "KB",
+    "kB", "MB", "GB", "TB", "KiB", "MiB", "GiB", "TiB", "kW", "mAh", "lb", "lbs", "oz", "ft",
+    "mph",
+];
+
+/// Options that control which rules [`NumberUnits`] checks, since
+/// conventions differ between locales and style guides.
+#[derive(Debug, Clone, Copy)]
+pub struct NumberUnitConfig {
+    unit_spacing: bool,
+    percent_spacing: bool,
+}
+
+impl Default for NumberUnitConfig {
+    fn default() -> Self {
+        Self {
+            unit_spacing: true,
+            percent_spacing: true,
+        }
+    }
+}
+
+impl NumberUnitConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Flag units glued to their number, like "5kg".
+    /// Defaults to `true`.
+    pub fn with_unit_spacing(mut self, unit_spacing: bool) -> Self {
+        self.unit_spacing = unit_spacing;
+        self
+    }
+
+    /// Flag percent signs separated from their number, like "10 %".
+    /// Defaults to `true`.
+    pub fn with_percent_spacing(mut self, percent_spacing: bool) -> Self {
+        self.percent_spacing = percent_spacing;
+        self
+    }
+}
+
+/// Looks for inconsistent spacing between numbers and their units.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NumberUnits {
+    config: NumberUnitConfig,
+}
+
+impl NumberUnits {
+    pub fn with_config(config: NumberUnitConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Rule for NumberUnits {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        let mut output = Vec::new();
+        let tokens = document.get_tokens();
+
+        for (idx, number) in tokens.iter().enumerate() {
+            if !number.kind.is_number() {
+                continue;
+            }
+
+            if self.config.unit_spacing {
+                output.extend(glued_unit(document, number, &tokens[idx + 1..]));
+            }
+
+            if self.config.percent_spacing {
+                output.extend(spaced_percent(document, number, &tokens[idx + 1..]));
+            }
+        }
+
+        output
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
+    fn description(&self) -> &'static str {
+        "Units like \"kg\" should be separated from their number by a space, while percent signs should not. This rule looks for cases like \"5kg\" and \"10 %\"."
+    }
+}
+
+/// Flag numbers directly glued to a unit, like "5kg", or with a space before
+/// a percent sign, like "10 %", using the default [`NumberUnitConfig`].
+pub fn number_unit_check(document: &Document) -> Vec<Lint> {
+    NumberUnits::default().check(document)
+}
+
+fn glued_unit(document: &Document, number: &Token, rest: &[Token]) -> Option<Lint> {
+    let unit = rest.first()?;
+
+    if !unit.kind.is_word() || unit.span.start != number.span.end {
+        return None;
+    }
+
+    let unit_chars = document.get_span_content(unit.span);
+
+    if !UNITS
+        .iter()
+        .any(|u| u.chars().eq(unit_chars.iter().copied()))
+    {
+        return None;
+    }
+
+    let mut replacement = document.get_span_content(number.span).to_vec();
+    replacement.push(' ');
+    replacement.extend_from_slice(unit_chars);
+
+    Some(Lint {
+        span: Span::new(number.span.start, unit.span.end),
+        lint_kind: LintKind::Formatting,
+        suggestions: vec![Suggestion::ReplaceWith(replacement)],
+        message: "Put a space between the number and its unit.".to_string(),
+        severity: LintKind::Formatting.default_severity(),
+        ..Default::default()
+    })
+}
+
+fn spaced_percent(document: &Document, number: &Token, rest: &[Token]) -> Option<Lint> {
+    let [space, percent, ..] = rest else {
+        return None;
+    };
+
+    if !matches!(space.kind, TokenKind::Space(_))
+        || percent.kind != TokenKind::Punctuation(Punctuation::Percent)
+    {
+        return None;
+    }
+
+    let mut replacement = document.get_span_content(number.span).to_vec();
+    replacement.push('%');
+
+    Some(Lint {
+        span: Span::new(number.span.start, percent.span.end),
+        lint_kind: LintKind::Formatting,
+        suggestions: vec![Suggestion::ReplaceWith(replacement)],
+        message: "Percent signs shouldn't be separated from their number.".to_string(),
+        severity: LintKind::Formatting.default_severity(),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number_unit_check, NumberUnitConfig, NumberUnits};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn separates_glued_units() {
+        assert_suggestion_result(
+            "The box weighs 5kg.",
+            NumberUnits::default(),
+            "The box weighs 5 kg.",
+        );
+        assert_suggestion_result(
+            "It needs 2.5GB of memory.",
+            NumberUnits::default(),
+            "It needs 2.5 GB of memory.",
+        );
+    }
+
+    #[test]
+    fn joins_spaced_percent() {
+        assert_suggestion_result(
+            "Prices rose by 10 % this year.",
+            NumberUnits::default(),
+            "Prices rose by 10% this year.",
+        );
+    }
+
+    #[test]
+    fn allows_correct_spacing() {
+        assert_lint_count(
+            "The box weighs 5 kg and costs 10% more.",
+            NumberUnits::default(),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_non_units() {
+        assert_lint_count("It happened in the 1990s.", NumberUnits::default(), 0);
+        assert_lint_count("She finished 2nd.", NumberUnits::default(), 0);
+    }
+
+    #[test]
+    fn rules_can_be_disabled() {
+        let text = "Use 5kg and 10 % of the rest.";
+
+        assert_lint_count(
+            text,
+            NumberUnits::with_config(NumberUnitConfig::new().with_unit_spacing(false)),
+            1,
+        );
+        assert_lint_count(
+            text,
+            NumberUnits::with_config(NumberUnitConfig::new().with_percent_spacing(false)),
+            1,
+        );
+    }
+
+    #[test]
+    fn free_function_uses_defaults() {
+        let document = Document::new_plain_english_curated("Add 5kg, about 10 %.");
+
+        assert_eq!(number_unit_check(&document).len(), 2);
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Lint, LintKind, Rule};
+use crate::token::TokenStringExt;
+use crate::{Document, Span, Token};
+
+/// Detect sentences written in the passive voice, like "the letter was
+/// written".
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PassiveVoice;
+
+impl Rule for PassiveVoice {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        passive_voice_check(document)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
+    fn description(&self) -> &'static str {
+        "The passive voice hides who is doing the action. This rule looks for forms of \"to be\" followed by a past participle, like \"was written\"."
+    }
+}
+
+/// Forms of "to be" that can introduce the passive voice.
+const AUXILIARIES: &[&str] = &["am", "is", "are", "was", "were", "be", "been", "being"];
+
+/// How many words (adverbs, "not" or further auxiliaries) may sit between an
+/// auxiliary and its participle, as in "was not quickly eaten".
+const MAX_GAP: usize = 2;
+
+/// Flag every form of "to be" that is followed, within a couple of words, by
+/// a past participle.
+///
+/// This relies on [`WordMetadata::is_past_participle_verb`](crate::WordMetadata::is_past_participle_verb),
+/// so participles missing from the dictionary won't be caught, and
+/// adjectives that look like participles ("is interested") will be.
+pub fn passive_voice_check(document: &Document) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for sentence in document.iter_sentences() {
+        let tokens: Vec<&Token> = sentence
+            .iter()
+            .filter(|token| !token.kind.is_whitespace())
+            .collect();
+
+        let mut idx = 0;
+
+        while idx < tokens.len() {
+            let auxiliary = tokens[idx];
+
+            if !is_auxiliary(document, auxiliary) {
+                idx += 1;
+                continue;
+            }
+
+            let Some(offset) = find_participle(document, &tokens[idx + 1..]) else {
+                idx += 1;
+                continue;
+            };
+
+            let participle = tokens[idx + 1 + offset];
+
+            output.push(Lint {
+                span: Span::new(auxiliary.span.start, participle.span.end),
+                lint_kind: LintKind::Readability,
+                message: "This is in the passive voice. Consider saying who is doing the action."
+                    .to_string(),
+                severity: LintKind::Readability.default_severity(),
+                ..Default::default()
+            });
+
+            idx += offset + 2;
+        }
+    }
+
+    output
+}
+
+fn is_auxiliary(document: &Document, token: &Token) -> bool {
+    token.kind.is_word() && word_is_any_of(document, token, AUXILIARIES)
+}
+
+/// Find the index of the participle completing a passive construction at the
+/// start of `tokens`, if any.
+fn find_participle(document: &Document, tokens: &[&Token]) -> Option<usize> {
+    for (idx, token) in tokens.iter().enumerate().take(MAX_GAP + 1) {
+        let metadata = token.kind.as_word()?;
+
+        if metadata.is_past_participle_verb() {
+            return Some(idx);
+        }
+
+        let can_intervene = metadata.is_adverb()
+            || word_is_any_of(document, token, &["not", "never"])
+            || is_auxiliary(document, token);
+
+        if !can_intervene {
+            return None;
+        }
+    }
+
+    None
+}
+
+fn word_is_any_of(document: &Document, token: &Token, words: &[&str]) -> bool {
+    let chars = document.get_span_content(token.span);
+
+    words.iter().any(|word| {
+        word.chars()
+            .eq(chars.iter().map(|c| c.to_ascii_lowercase()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{passive_voice_check, PassiveVoice};
+    use crate::linting::tests::assert_lint_count;
+    use crate::linting::{LintKind, Severity};
+    use crate::Document;
+
+    #[test]
+    fn catches_irregular_participles() {
+        assert_lint_count("The letter was written by Bob.", PassiveVoice, 1);
+        assert_lint_count("The apples were eaten.", PassiveVoice, 1);
+    }
+
+    #[test]
+    fn catches_regular_participles() {
+        assert_lint_count("The door was closed.", PassiveVoice, 1);
+    }
+
+    #[test]
+    fn allows_active_voice() {
+        assert_lint_count("Bob wrote the letter.", PassiveVoice, 0);
+        assert_lint_count("We ate the apples.", PassiveVoice, 0);
+        assert_lint_count("The sky is blue.", PassiveVoice, 0);
+    }
+
+    #[test]
+    fn spans_the_whole_construction() {
+        let text = "The cake was not quickly eaten.";
+        let document = Document::new_plain_english_curated(text);
+
+        let lints = passive_voice_check(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            document.get_span_content_str(lints[0].span),
+            "was not quickly eaten"
+        );
+        assert_eq!(lints[0].lint_kind, LintKind::Readability);
+        assert_eq!(lints[0].severity, Severity::Info);
+    }
+
+    #[test]
+    fn includes_stacked_auxiliaries() {
+        let document = Document::new_plain_english_curated("It is being built.");
+
+        let lints = passive_voice_check(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            document.get_span_content_str(lints[0].span),
+            "is being built"
+        );
+    }
+
+    #[test]
+    fn participle_must_be_nearby() {
+        assert_lint_count("The report was the one my boss written.", PassiveVoice, 0);
+    }
+}
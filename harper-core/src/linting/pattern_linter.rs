@@ -1,4 +1,4 @@
-use super::{Lint, Linter};
+use super::{Lint, LintKind, Rule};
 use crate::patterns::Pattern;
 use crate::{Token, TokenStringExt};
 
@@ -7,7 +7,9 @@ pub trait PatternLinter {
     /// A simple getter for the pattern to be searched for.
     fn pattern(&self) -> &dyn Pattern;
     fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint;
-    fn description<'a>(&'a self) -> &'a str;
+    /// The kind of the lints [`Self::match_to_lint`] produces.
+    fn kind(&self) -> LintKind;
+    fn description(&self) -> &str;
 }
 
 #[cfg(feature = "concurrent")]
@@ -15,14 +17,16 @@ pub trait PatternLinter: Send + Sync {
     /// A simple getter for the pattern to be searched for.
     fn pattern(&self) -> &dyn Pattern;
     fn match_to_lint(&self, matched_tokens: &[Token], source: &[char]) -> Lint;
+    /// The kind of the lints [`Self::match_to_lint`] produces.
+    fn kind(&self) -> LintKind;
     fn description(&self) -> &str;
 }
 
-impl<L> Linter for L
+impl<L> Rule for L
 where
     L: PatternLinter,
 {
-    fn lint(&mut self, document: &crate::Document) -> Vec<Lint> {
+    fn check(&self, document: &crate::Document) -> Vec<Lint> {
         let mut lints = Vec::new();
         let source = document.get_source();
 
@@ -51,7 +55,11 @@ where
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        PatternLinter::kind(self)
+    }
+
     fn description(&self) -> &str {
-        self.description()
+        PatternLinter::description(self)
     }
 }
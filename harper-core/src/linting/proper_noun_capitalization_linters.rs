@@ -48,9 +48,15 @@ macro_rules! create_linter_for {
                     suggestions: vec![Suggestion::ReplaceWith(proper)],
                     message: $message.to_string(),
                     priority: 31,
+                    severity: LintKind::Capitalization.default_severity(),
+                    ..Default::default()
                 }
             }
 
+            fn kind(&self) -> LintKind {
+                LintKind::Capitalization
+            }
+
             fn description(&self) -> &'static str {
                 $description
             }
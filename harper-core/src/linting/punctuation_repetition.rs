@@ -0,0 +1,156 @@
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{Document, Punctuation, Span, TokenKind};
+
+/// Flag runs of two or more identical question marks or exclamation points,
+/// like "!!!" or "??", suggesting a single mark.
+///
+/// Runs of periods are lexed as ellipses, which
+/// [`EllipsisLength`](super::EllipsisLength) looks after.
+pub fn punctuation_repetition_check(document: &Document) -> Vec<Lint> {
+    lint_repetition(document, false)
+}
+
+/// Looks for repeated terminal punctuation, like "!!!".
+///
+/// Off by default, since some informal writing uses it for emphasis on
+/// purpose.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PunctuationRepetition {
+    interrobangs: bool,
+}
+
+impl PunctuationRepetition {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also flag interrobangs that are drawn out, like "?!?!" or "!?!",
+    /// suggesting a plain "?!".
+    /// Off by default.
+    pub fn with_interrobangs(mut self, interrobangs: bool) -> Self {
+        self.interrobangs = interrobangs;
+        self
+    }
+}
+
+impl Rule for PunctuationRepetition {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        lint_repetition(document, self.interrobangs)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
+    fn description(&self) -> &'static str {
+        "Repeating question marks or exclamation points, like \"!!!\", reads as shouting. This rule suggests using a single mark."
+    }
+}
+
+fn lint_repetition(document: &Document, interrobangs: bool) -> Vec<Lint> {
+    let tokens = document.get_tokens();
+    let mut output = Vec::new();
+    let mut idx = 0;
+
+    while idx < tokens.len() {
+        let run_len = tokens[idx..]
+            .iter()
+            .take_while(|token| terminal_mark(token.kind).is_some())
+            .count();
+
+        if run_len < 2 {
+            idx += 1;
+            continue;
+        }
+
+        let run = &tokens[idx..idx + run_len];
+        idx += run_len;
+
+        let marks: Vec<char> = run
+            .iter()
+            .filter_map(|token| terminal_mark(token.kind))
+            .collect();
+        let span = Span::new(run[0].span.start, run[run_len - 1].span.end);
+
+        let (replacement, message) = if marks.iter().all(|mark| *mark == marks[0]) {
+            (vec![marks[0]], "Use a single punctuation mark.")
+        } else if interrobangs && run_len > 2 {
+            (vec!['?', '!'], "Use a single “?!” instead.")
+        } else {
+            continue;
+        };
+
+        output.push(Lint {
+            span,
+            lint_kind: LintKind::Formatting,
+            suggestions: vec![Suggestion::ReplaceWith(replacement)],
+            message: message.to_string(),
+            priority: 31,
+            severity: LintKind::Formatting.default_severity(),
+            ..Default::default()
+        });
+    }
+
+    output
+}
+
+/// The character of a question mark or exclamation point token.
+fn terminal_mark(kind: TokenKind) -> Option<char> {
+    match kind {
+        TokenKind::Punctuation(Punctuation::Question) => Some('?'),
+        TokenKind::Punctuation(Punctuation::Bang) => Some('!'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{punctuation_repetition_check, PunctuationRepetition};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::Document;
+
+    #[test]
+    fn collapses_exclamation_points() {
+        assert_suggestion_result("Wow!!!", PunctuationRepetition::new(), "Wow!");
+    }
+
+    #[test]
+    fn collapses_question_marks() {
+        assert_suggestion_result("Really??", PunctuationRepetition::new(), "Really?");
+    }
+
+    #[test]
+    fn allows_single_marks() {
+        assert_lint_count(
+            "Is it? Yes! It is. Well... maybe?!",
+            PunctuationRepetition::new(),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_interrobangs_by_default() {
+        assert_lint_count("What?!?!", PunctuationRepetition::new(), 0);
+    }
+
+    #[test]
+    fn can_flag_interrobangs() {
+        assert_suggestion_result(
+            "What?!?!",
+            PunctuationRepetition::new().with_interrobangs(true),
+            "What?!",
+        );
+        assert_lint_count(
+            "What?!",
+            PunctuationRepetition::new().with_interrobangs(true),
+            0,
+        );
+    }
+
+    #[test]
+    fn counts_each_run() {
+        let document = Document::new_plain_english_curated("No!! Why?? Stop!!!");
+
+        assert_eq!(punctuation_repetition_check(&document).len(), 3);
+    }
+}
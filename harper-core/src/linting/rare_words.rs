@@ -0,0 +1,250 @@
+use hashbrown::HashMap;
+
+use super::{Lint, LintKind, Rule, Severity, Suggestion};
+use crate::{CharString, CharStringExt, Dictionary, Document, TokenStringExt};
+
+/// Common replacements for uncommon words, like "utilize" and "use", for
+/// [`RareWords`] to suggest.
+///
+/// Words are matched without regard to case.
+/// There is no curated table; users supply their own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SynonymTable {
+    /// Each lowercase word and its replacement.
+    entries: HashMap<String, String>,
+}
+
+impl SynonymTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a table of synonyms, one `word<TAB>replacement` pair per line.
+    ///
+    /// Blank lines and lines that fail to parse are skipped.
+    pub fn parse(source: &str) -> Self {
+        let mut table = Self::new();
+
+        for line in source.lines() {
+            if let Some((word, replacement)) = line.split_once('\t') {
+                table.add(word.trim(), replacement.trim());
+            }
+        }
+
+        table
+    }
+
+    /// Add a word, replacing any previous replacement for it.
+    ///
+    /// Empty words and replacements are ignored.
+    pub fn add(&mut self, word: &str, replacement: &str) {
+        if word.is_empty() || replacement.is_empty() {
+            return;
+        }
+
+        self.entries
+            .insert(word.to_lowercase(), replacement.to_string());
+    }
+
+    /// Stop suggesting a replacement for a word.
+    pub fn remove(&mut self, word: &str) {
+        self.entries.remove(&word.to_lowercase());
+    }
+
+    /// The replacement for a word, if there is one.
+    pub fn get(&self, word: &str) -> Option<&str> {
+        self.entries.get(&word.to_lowercase()).map(String::as_str)
+    }
+}
+
+/// Flag dictionary words whose [frequency](Dictionary::word_frequency) is
+/// below `threshold`, in occurrences per million words.
+///
+/// Words without a known frequency are treated as rarer than any word that
+/// has one, so they are always flagged.
+/// Words the dictionary doesn't know are left to
+/// [`SpellCheck`](super::SpellCheck), and proper nouns are skipped.
+pub fn rare_word_check(
+    document: &Document,
+    dictionary: &impl Dictionary,
+    threshold: u32,
+) -> Vec<Lint> {
+    lint_rare_words(document, dictionary, threshold, None)
+}
+
+/// Looks for uncommon words, optionally suggesting common replacements from
+/// a [`SynonymTable`].
+///
+/// Off by default, since it is meant for plain-language writing and flags
+/// many perfectly good words.
+pub struct RareWords<T>
+where
+    T: Dictionary,
+{
+    dictionary: T,
+    threshold: u32,
+    synonyms: Option<SynonymTable>,
+}
+
+impl<T: Dictionary> RareWords<T> {
+    /// The threshold used unless [`Self::with_threshold`] says otherwise, in
+    /// occurrences per million words.
+    pub const DEFAULT_THRESHOLD: u32 = 100;
+
+    pub fn new(dictionary: T) -> Self {
+        Self {
+            dictionary,
+            threshold: Self::DEFAULT_THRESHOLD,
+            synonyms: None,
+        }
+    }
+
+    /// Flag words that occur fewer than `threshold` times per million words.
+    pub fn with_threshold(mut self, threshold: u32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Suggest replacements from `synonyms` for the words it lists.
+    pub fn with_synonyms(mut self, synonyms: SynonymTable) -> Self {
+        self.synonyms = Some(synonyms);
+        self
+    }
+}
+
+impl<T: Dictionary> Rule for RareWords<T> {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        lint_rare_words(
+            document,
+            &self.dictionary,
+            self.threshold,
+            self.synonyms.as_ref(),
+        )
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
+    fn description(&self) -> &'static str {
+        "Plain-language writing avoids uncommon words. This rule flags words that are rarely used, suggesting a common alternative when one is known."
+    }
+}
+
+fn lint_rare_words(
+    document: &Document,
+    dictionary: &impl Dictionary,
+    threshold: u32,
+    synonyms: Option<&SynonymTable>,
+) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for word in document.iter_words() {
+        let chars = document.get_span_content(word.span);
+
+        if !dictionary.contains_word(chars) {
+            continue;
+        }
+
+        if dictionary.get_word_metadata(chars).is_proper_noun() {
+            continue;
+        }
+
+        if dictionary.word_frequency(chars).unwrap_or(0) >= threshold {
+            continue;
+        }
+
+        let text = chars.to_string();
+        let replacement = synonyms.and_then(|synonyms| synonyms.get(&text));
+
+        let (suggestions, message) = match replacement {
+            Some(replacement) => {
+                let replacement: CharString = replacement.chars().collect();
+
+                (
+                    vec![Suggestion::ReplaceWith(
+                        replacement.with_case_of(chars).to_vec(),
+                    )],
+                    format!(
+                        "“{text}” is an uncommon word. Consider “{}” instead.",
+                        replacement.to_string()
+                    ),
+                )
+            }
+            None => (Vec::new(), format!("“{text}” is an uncommon word.")),
+        };
+
+        output.push(Lint {
+            span: word.span,
+            lint_kind: LintKind::Readability,
+            suggestions,
+            message,
+            severity: Severity::Info,
+            ..Default::default()
+        });
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rare_word_check, RareWords, SynonymTable};
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::Severity;
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn flags_words_below_threshold() {
+        let document = Document::new_plain_english_curated("We should utilize the tool.");
+        let lints = rare_word_check(&document, &FstDictionary::curated(), 100);
+
+        assert_eq!(lints.len(), 2);
+        assert_eq!(lints[0].severity, Severity::Info);
+        assert!(lints[0].suggestions.is_empty());
+    }
+
+    #[test]
+    fn threshold_of_zero_flags_nothing() {
+        let document = Document::new_plain_english_curated("We should utilize the tool.");
+
+        assert!(rare_word_check(&document, &FstDictionary::curated(), 0).is_empty());
+    }
+
+    #[test]
+    fn skips_unknown_words() {
+        let document = Document::new_plain_english_curated("The zxqvy is here.");
+
+        assert!(rare_word_check(&document, &FstDictionary::curated(), 100).is_empty());
+    }
+
+    #[test]
+    fn suggests_synonyms() {
+        let mut synonyms = SynonymTable::new();
+        synonyms.add("utilize", "use");
+
+        assert_suggestion_result(
+            "Utilize it.",
+            RareWords::new(FstDictionary::curated()).with_synonyms(synonyms),
+            "Use it.",
+        );
+    }
+
+    #[test]
+    fn parses_synonym_tables() {
+        let table = SynonymTable::parse("utilize\tuse\n\nnot a pair\ncommence\tstart\n");
+
+        assert_eq!(table.get("Utilize"), Some("use"));
+        assert_eq!(table.get("commence"), Some("start"));
+        assert_eq!(table.get("not a pair"), None);
+    }
+
+    #[test]
+    fn allows_common_words() {
+        assert_lint_count(
+            "It is the first of the year.",
+            RareWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+}
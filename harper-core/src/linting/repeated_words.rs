@@ -1,6 +1,6 @@
 use smallvec::smallvec;
 
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 use crate::token::TokenStringExt;
 use crate::{CharString, CharStringExt, Document, Span};
 
@@ -9,14 +9,29 @@ pub struct RepeatedWords {
     /// Words that we need to make sure are detected.
     /// We use a `Vec` since there aren't a whole lot of 'em.
     special_cases: Vec<CharString>,
+    /// Words that may legitimately appear twice in a row, like "had had".
+    /// Empty by default, so every repetition is flagged.
+    allowed_repeats: Vec<CharString>,
 }
 impl RepeatedWords {
     pub fn new() -> Self {
         Self {
             special_cases: vec![smallvec!['i', 's'], smallvec!['a']],
+            allowed_repeats: Vec::new(),
         }
     }
 
+    /// Allow a word to be repeated without being flagged.
+    pub fn with_allowed_repeat(mut self, word: &str) -> Self {
+        self.allowed_repeats
+            .push(word.chars().collect::<CharString>().to_lower());
+        self
+    }
+
+    fn is_allowed_repeat(&self, chars: &[char]) -> bool {
+        self.allowed_repeats.contains(&chars.to_lower())
+    }
+
     fn is_special_case(&self, chars: &[char]) -> bool {
         self.special_cases.iter().any(|v| v.as_slice() == chars)
     }
@@ -28,8 +43,8 @@ impl Default for RepeatedWords {
     }
 }
 
-impl Linter for RepeatedWords {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for RepeatedWords {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
         for chunk in document.iter_chunks() {
@@ -40,6 +55,7 @@ impl Linter for RepeatedWords {
                 let word_b = document.get_span_content(tok_b.span);
 
                 if (!tok_a.kind.is_likely_homograph() || self.is_special_case(word_a))
+                    && !self.is_allowed_repeat(word_a)
                     && word_a.to_lower() == word_b.to_lower()
                 {
                     let intervening_tokens = &chunk[idx_a + 1..*idx_b];
@@ -48,13 +64,14 @@ impl Linter for RepeatedWords {
                         continue;
                     }
 
+                    // Cover the second word and the whitespace before it, so
+                    // removing it leaves the sentence intact.
                     lints.push(Lint {
-                        span: Span::new(tok_a.span.start, tok_b.span.end),
+                        span: Span::new(tok_a.span.end, tok_b.span.end),
                         lint_kind: LintKind::Repetition,
-                        suggestions: vec![Suggestion::ReplaceWith(
-                            document.get_span_content(tok_a.span).to_vec(),
-                        )],
+                        suggestions: vec![Suggestion::Remove],
                         message: "Did you mean to repeat this word?".to_string(),
+                        severity: LintKind::Repetition.default_severity(),
                         ..Default::default()
                     })
                 }
@@ -64,6 +81,10 @@ impl Linter for RepeatedWords {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Repetition
+    }
+
     fn description(&self) -> &'static str {
         "This rule looks for repetitions of words that are not homographs."
     }
@@ -105,6 +126,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn removes_second_occurrence_across_line_break() {
+        assert_suggestion_result(
+            "I wanted the\nthe banana.",
+            RepeatedWords::default(),
+            "I wanted the banana.",
+        );
+    }
+
+    #[test]
+    fn catches_different_case() {
+        assert_suggestion_result(
+            "The the banana is ripe.",
+            RepeatedWords::default(),
+            "The banana is ripe.",
+        );
+    }
+
+    #[test]
+    fn flags_had_had_by_default() {
+        assert_lint_count("She had had enough.", RepeatedWords::default(), 1);
+    }
+
+    #[test]
+    fn respects_allowed_repeats() {
+        assert_lint_count(
+            "She had had enough.",
+            RepeatedWords::default().with_allowed_repeat("had"),
+            0,
+        );
+    }
+
     #[test]
     fn double_a() {
         assert_suggestion_result(
@@ -0,0 +1,100 @@
+use super::{Lint, LintKind, Linter};
+use crate::Document;
+
+/// A single check that finds one kind of problem in a [`Document`].
+///
+/// Every built-in check is a rule, and rules of your own can be run
+/// alongside them with [`LintGroup::add_rule`](super::LintGroup::add_rule).
+/// Each rule carries its own configuration, set when it is constructed.
+///
+/// Every rule is also a [`Linter`], and [`Rule::check`] has the same
+/// requirements as [`Linter::lint`].
+#[cfg(not(feature = "concurrent"))]
+pub trait Rule {
+    fn check(&self, document: &Document) -> Vec<Lint>;
+    /// The kind of problem this rule looks for.
+    ///
+    /// Most rules only report lints of this kind, but some also report
+    /// related kinds, like [`LintKind::UnknownWord`] alongside
+    /// [`LintKind::Spelling`].
+    fn kind(&self) -> LintKind;
+    fn description(&self) -> &str;
+}
+
+/// A single check that finds one kind of problem in a [`Document`].
+///
+/// Every built-in check is a rule, and rules of your own can be run
+/// alongside them with [`LintGroup::add_rule`](super::LintGroup::add_rule).
+/// Each rule carries its own configuration, set when it is constructed.
+///
+/// Every rule is also a [`Linter`], and [`Rule::check`] has the same
+/// requirements as [`Linter::lint`].
+#[cfg(feature = "concurrent")]
+pub trait Rule: Send + Sync {
+    fn check(&self, document: &Document) -> Vec<Lint>;
+    /// The kind of problem this rule looks for.
+    ///
+    /// Most rules only report lints of this kind, but some also report
+    /// related kinds, like [`LintKind::UnknownWord`] alongside
+    /// [`LintKind::Spelling`].
+    fn kind(&self) -> LintKind;
+    fn description(&self) -> &str;
+}
+
+impl<R> Linter for R
+where
+    R: Rule,
+{
+    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+        self.check(document)
+    }
+
+    fn description(&self) -> &str {
+        Rule::description(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rule;
+    use crate::linting::{DoubleNegatives, LintKind, Linter, SpellCheck, ThatWhich};
+    use crate::{Document, FstDictionary};
+
+    #[test]
+    fn built_in_checks_are_rules() {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(DoubleNegatives),
+            Box::new(ThatWhich::default()),
+            Box::new(SpellCheck::new(FstDictionary::curated())),
+        ];
+
+        let kinds: Vec<LintKind> = rules.iter().map(|rule| rule.kind()).collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                LintKind::Readability,
+                LintKind::Repetition,
+                LintKind::Spelling
+            ]
+        );
+    }
+
+    #[test]
+    fn rules_are_linters() {
+        let document = Document::new_plain_english_curated("I don't need no help.");
+
+        assert_eq!(
+            DoubleNegatives.check(&document),
+            DoubleNegatives.lint(&document)
+        );
+    }
+
+    #[test]
+    fn spell_check_gives_the_same_lints_twice() {
+        let document = Document::new_plain_english_curated("This is a tezt.");
+        let rule = SpellCheck::new(FstDictionary::curated());
+
+        assert_eq!(rule.check(&document), rule.check(&document));
+    }
+}
@@ -1,17 +1,17 @@
 use itertools::Itertools;
 
 use super::lint::Suggestion;
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Rule};
 use crate::document::Document;
 use crate::{Token, TokenKind, TokenStringExt};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SentenceCapitalization;
 
-impl Linter for SentenceCapitalization {
+impl Rule for SentenceCapitalization {
     /// A linter that checks to make sure the first word of each sentence is
     /// capitalized.
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
         for paragraph in document.iter_paragraphs() {
@@ -51,6 +51,8 @@ impl Linter for SentenceCapitalization {
                                 priority: 31,
                                 message: "This sentence does not start with a capital letter"
                                     .to_string(),
+                                severity: LintKind::Capitalization.default_severity(),
+                                ..Default::default()
                             })
                         }
                     }
@@ -61,6 +63,10 @@ impl Linter for SentenceCapitalization {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Capitalization
+    }
+
     fn description(&self) -> &'static str {
         "The opening word of a sentence should almost always be capitalized."
     }
@@ -166,4 +172,22 @@ mod tests {
     fn issue_228_allows_labels() {
         assert_lint_count("python lsp (fork of pyright)", SentenceCapitalization, 0)
     }
+
+    #[test]
+    fn ignores_abbreviations() {
+        assert_lint_count(
+            "The fruit, e.g. apples and pears, is at the store. I will ask Mr. smith to buy the fruit.",
+            SentenceCapitalization,
+            0,
+        )
+    }
+
+    #[test]
+    fn catches_after_question() {
+        assert_lint_count(
+            "Is there a way to tell? there is no way she is not guilty.",
+            SentenceCapitalization,
+            1,
+        )
+    }
 }
@@ -1,34 +1,53 @@
-use super::{Lint, LintKind, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 use crate::token::TokenStringExt;
 use crate::{Document, Token, TokenKind};
 
 #[derive(Debug, Default)]
 pub struct Spaces;
 
-impl Linter for Spaces {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for Spaces {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut output = Vec::new();
 
-        for sentence in document.iter_sentences() {
-            for space in sentence.iter_spaces() {
-                let TokenKind::Space(count) = space.kind else {
-                    panic!("The space iterator should only return spaces.")
-                };
-
-                if count > 1 {
-                    output.push(Lint {
-                        span: space.span,
-                        lint_kind: LintKind::Formatting,
-                        suggestions: vec![Suggestion::ReplaceWith(vec![' '])],
-                        message: format!(
-                            "There are {} spaces where there should be only one.",
-                            count
-                        ),
-                        priority: 15,
-                    })
-                }
+        let tokens = document.get_tokens();
+
+        for (idx, space) in tokens.iter().enumerate() {
+            let TokenKind::Space(count) = space.kind else {
+                continue;
+            };
+
+            let is_line_break =
+                |tok: &Token| matches!(tok.kind, TokenKind::Newline(_) | TokenKind::ParagraphBreak);
+
+            // Indentation at the start of a line may be intentional.
+            if idx == 0 || is_line_break(&tokens[idx - 1]) {
+                continue;
             }
 
+            if tokens.get(idx + 1).is_none_or(is_line_break) {
+                output.push(Lint {
+                    span: space.span,
+                    lint_kind: LintKind::Formatting,
+                    suggestions: vec![Suggestion::Remove],
+                    message: "Unnecessary whitespace at the end of the line.".to_string(),
+                    priority: 63,
+                    severity: LintKind::Formatting.default_severity(),
+                    ..Default::default()
+                });
+            } else if count > 1 {
+                output.push(Lint {
+                    span: space.span,
+                    lint_kind: LintKind::Formatting,
+                    suggestions: vec![Suggestion::ReplaceWith(vec![' '])],
+                    message: format!("There are {} spaces where there should be only one.", count),
+                    priority: 15,
+                    severity: LintKind::Formatting.default_severity(),
+                    ..Default::default()
+                })
+            }
+        }
+
+        for sentence in document.iter_sentences() {
             if matches!(
                 sentence,
                 [
@@ -55,22 +74,32 @@ impl Linter for Spaces {
                     suggestions: vec![Suggestion::Remove],
                     message: "Unnecessary space at the end of the sentence.".to_string(),
                     priority: 63,
+                    severity: LintKind::Formatting.default_severity(),
+                    ..Default::default()
                 })
             }
         }
 
+        output.sort_by_key(|lint| lint.span.start);
+
         output
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
     fn description(&self) -> &'static str {
-        "Words should be separated by at most one space."
+        "Words should be separated by at most one space, and lines should not end with whitespace."
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Spaces;
-    use crate::linting::tests::assert_lint_count;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::Linter;
+    use crate::Document;
 
     #[test]
     fn detects_space_before_period() {
@@ -85,4 +114,35 @@ mod tests {
 
         assert_lint_count(source, Spaces, 0)
     }
+
+    #[test]
+    fn replaces_double_space() {
+        assert_suggestion_result("This has  two spaces.", Spaces, "This has two spaces.");
+    }
+
+    #[test]
+    fn removes_trailing_space() {
+        let document =
+            Document::new_plain_english_curated("This line ends with a space. \nNext line.");
+        let lints = Spaces.lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(document.get_span_content_str(lints[0].span), " ");
+        assert!(lints[0].suggestions[0].is_remove());
+    }
+
+    #[test]
+    fn removes_trailing_space_at_end_of_document() {
+        let document = Document::new_plain_english_curated("The document ends here.   ");
+
+        assert_eq!(Spaces.lint(&document).len(), 1);
+    }
+
+    #[test]
+    fn allows_indentation() {
+        let document =
+            Document::new_plain_english_curated("A list:\n    indented line.\n\tAnd a tab.");
+
+        assert_eq!(Spaces.lint(&document).len(), 0);
+    }
 }
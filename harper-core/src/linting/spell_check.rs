@@ -1,100 +1,1112 @@
-use hashbrown::HashMap;
+use std::collections::VecDeque;
+use std::io::BufRead;
+use std::sync::{Arc, Mutex};
+
+use hashbrown::{HashMap, HashSet};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
 use smallvec::ToSmallVec;
 
 use super::lint::Suggestion;
-use super::{Lint, LintKind, Linter};
+use super::{sort_lints, Lint, LintKind, Rule};
 use crate::document::Document;
-use crate::spell::suggest_correct_spelling;
-use crate::{CharString, Dictionary, TokenStringExt};
+use crate::parsers::PlainEnglish;
+use crate::spell::{
+    rerank_by_context, suggest_correct_spelling, suggestion_confidence, suggestion_edit_distance,
+};
+use crate::{
+    BigramModel, CharString, CharStringExt, Dictionary, Normalizer, Span, Token, TokenStringExt,
+};
+
+/// Which of the American and British spellings of a word, like "color" and
+/// "colour", [`SpellCheck`] should prefer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SpellingVariant {
+    AmericanEnglish,
+    BritishEnglish,
+    /// Accept both spellings.
+    #[default]
+    Either,
+}
+
+/// Pairs of American and British spellings of the same word, looked up by
+/// their lowercase form.
+struct VariantTable {
+    american_to_british: HashMap<String, String>,
+    british_to_american: HashMap<String, String>,
+}
+
+impl VariantTable {
+    /// Parse a table with one `american<TAB>british` pair per line.
+    ///
+    /// Blank lines and lines that fail to parse are skipped.
+    fn parse(source: &str) -> Self {
+        let pairs: Vec<(String, String)> = source
+            .lines()
+            .filter_map(|line| {
+                let (american, british) = line.split_once('\t')?;
+                Some((
+                    american.trim().to_lowercase(),
+                    british.trim().to_lowercase(),
+                ))
+            })
+            .collect();
+
+        Self {
+            british_to_american: pairs.iter().map(|(a, b)| (b.clone(), a.clone())).collect(),
+            american_to_british: pairs.into_iter().collect(),
+        }
+    }
+
+    /// Whether `word` is either spelling of a word in the table.
+    fn contains(&self, word: &str) -> bool {
+        self.american_to_british.contains_key(word) || self.british_to_american.contains_key(word)
+    }
+
+    /// The spelling to suggest in place of `word`, if `word` isn't the one
+    /// `variant` prefers.
+    fn preferred(&self, word: &str, variant: SpellingVariant) -> Option<&str> {
+        match variant {
+            SpellingVariant::AmericanEnglish => self.british_to_american.get(word),
+            SpellingVariant::BritishEnglish => self.american_to_british.get(word),
+            SpellingVariant::Either => None,
+        }
+        .map(String::as_str)
+    }
+}
+
+lazy_static! {
+    static ref VARIANTS: VariantTable =
+        VariantTable::parse(include_str!("../../spelling_variants.tsv"));
+}
+
+/// Options that control which words [`SpellCheck`] looks at.
+///
+/// Numbers like "2024" are lexed as their own tokens, so they are never
+/// spell checked.
+#[derive(Debug, Clone)]
+pub struct SpellCheckConfig {
+    ignored_words: HashSet<String>,
+    /// Lowercase copies of [`Self::ignored_words`], for case-insensitive
+    /// lookup.
+    ignored_words_lower: HashSet<String>,
+    case_sensitive_ignore: bool,
+    min_length: usize,
+    allow_compounds: bool,
+    bigrams: Option<Arc<BigramModel>>,
+    spelling_variant: SpellingVariant,
+    min_confidence: f32,
+    skip_all_caps: bool,
+    skip_identifiers: bool,
+}
+
+impl Default for SpellCheckConfig {
+    fn default() -> Self {
+        Self {
+            ignored_words: HashSet::new(),
+            ignored_words_lower: HashSet::new(),
+            case_sensitive_ignore: false,
+            min_length: 2,
+            allow_compounds: false,
+            bigrams: None,
+            spelling_variant: SpellingVariant::default(),
+            min_confidence: 0.0,
+            skip_all_caps: false,
+            skip_identifiers: false,
+        }
+    }
+}
+
+impl SpellCheckConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip words with fewer characters than this.
+    /// Defaults to `2`, so single letters are ignored.
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Never flag the given word, without adding it to the dictionary.
+    /// Unlike dictionary words, ignored words are never suggested as
+    /// corrections for other typos.
+    pub fn with_ignored_word(mut self, word: impl Into<String>) -> Self {
+        let word = word.into();
+        self.ignored_words_lower.insert(word.to_lowercase());
+        self.ignored_words.insert(word);
+        self
+    }
+
+    pub fn with_ignored_words(self, words: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        words
+            .into_iter()
+            .fold(self, |config, word| config.with_ignored_word(word))
+    }
+
+    /// Whether ignored words must match case exactly.
+    /// Defaults to `false`.
+    pub fn with_case_sensitive_ignore(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive_ignore = case_sensitive;
+        self
+    }
+
+    /// Accept unknown words that can be split into dictionary words, like
+    /// "grammarchecker".
+    /// Defaults to `false`.
+    ///
+    /// See [`Dictionary::decompose_compound`].
+    pub fn with_compounds(mut self, allow_compounds: bool) -> Self {
+        self.allow_compounds = allow_compounds;
+        self
+    }
+
+    /// Rank suggestions by how well they follow the previous word, using
+    /// the given bigram counts (for example [`BigramModel::curated`]).
+    /// By default, suggestions are ranked without looking at context.
+    pub fn with_bigrams(mut self, bigrams: Arc<BigramModel>) -> Self {
+        self.bigrams = Some(bigrams);
+        self
+    }
+
+    /// Flag American or British spellings, like "color" or "colour", that
+    /// don't match the chosen variant.
+    /// Defaults to [`SpellingVariant::Either`], which accepts both.
+    pub fn with_spelling_variant(mut self, spelling_variant: SpellingVariant) -> Self {
+        self.spelling_variant = spelling_variant;
+        self
+    }
+
+    /// Only suggest corrections with at least this confidence, computed as
+    /// `1 - edit_distance / word_len`.
+    /// A misspelled word with no confident enough correction is still
+    /// flagged, but with no suggestions, so it can be shown as an unknown
+    /// word rather than with a misleading guess.
+    /// Defaults to `0.0`, which keeps every suggestion.
+    pub fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Skip words in all caps, like "NASA" or "JSON", which are usually
+    /// acronyms.
+    /// Single letters aren't affected.
+    /// Defaults to `false`.
+    pub fn with_skip_all_caps(mut self, skip_all_caps: bool) -> Self {
+        self.skip_all_caps = skip_all_caps;
+        self
+    }
+
+    /// Skip words that look like identifiers from code, like "getUserId" or
+    /// "user_id": those with a capital letter directly after a lowercase one,
+    /// or with an underscore.
+    /// Defaults to `false`.
+    pub fn with_skip_identifiers(mut self, skip_identifiers: bool) -> Self {
+        self.skip_identifiers = skip_identifiers;
+        self
+    }
+
+    fn is_ignored(&self, word: &[char]) -> bool {
+        if word.len() < self.min_length {
+            return true;
+        }
+
+        if self.skip_all_caps && is_all_caps(word) {
+            return true;
+        }
+
+        if self.skip_identifiers && is_identifier(word) {
+            return true;
+        }
+
+        if self.ignored_words.is_empty() {
+            return false;
+        }
+
+        let word: String = word.iter().collect();
+
+        if self.case_sensitive_ignore {
+            self.ignored_words.contains(&word)
+        } else {
+            self.ignored_words_lower.contains(&word.to_lowercase())
+        }
+    }
+}
 
 pub struct SpellCheck<T>
 where
     T: Dictionary,
 {
     dictionary: T,
-    word_cache: HashMap<CharString, Vec<CharString>>,
+    config: SpellCheckConfig,
+    /// Suggestions for misspelled words, kept between checks.
+    word_cache: Mutex<HashMap<CharString, Vec<CharString>>>,
 }
 
 impl<T: Dictionary> SpellCheck<T> {
     pub fn new(dictionary: T) -> Self {
+        Self::with_config(dictionary, SpellCheckConfig::default())
+    }
+
+    pub fn with_config(dictionary: T, config: SpellCheckConfig) -> Self {
         Self {
             dictionary,
-            word_cache: HashMap::new(),
+            config,
+            word_cache: Mutex::new(HashMap::new()),
         }
     }
 }
 
 impl<T: Dictionary> SpellCheck<T> {
-    fn cached_suggest_correct_spelling(&mut self, word: &[char]) -> Vec<CharString> {
+    /// The most misspelled words to remember suggestions for, so long-running
+    /// checks don't grow without bound.
+    const MAX_CACHED_WORDS: usize = 10_000;
+
+    fn cached_suggest_correct_spelling(&self, word: &[char]) -> Vec<CharString> {
         let word = word.to_smallvec();
+        let mut word_cache = self.word_cache.lock().unwrap();
+
+        if word_cache.len() >= Self::MAX_CACHED_WORDS && !word_cache.contains_key(&word) {
+            word_cache.clear();
+        }
 
-        self.word_cache
+        word_cache
             .entry(word.clone())
-            .or_insert_with(|| {
-                // Back off until we find a match.
-                let mut suggestions = Vec::new();
-                let mut dist = 2;
-
-                while suggestions.is_empty() && dist < 5 {
-                    suggestions = suggest_correct_spelling(&word, 100, dist, &self.dictionary)
-                        .into_iter()
-                        .map(|v| v.to_smallvec())
-                        .collect();
-
-                    dist += 1;
-                }
+            .or_insert_with(|| uncached_suggest_correct_spelling(&word, &self.dictionary))
+            .clone()
+    }
+
+    /// Compute suggestions for all the uncached words in parallel, so the
+    /// sequential pass only has to hit the cache.
+    #[cfg(feature = "rayon")]
+    fn fill_cache(&self, words: &[CharString]) {
+        use rayon::prelude::*;
 
-                suggestions
+        let missing: HashSet<CharString> = {
+            let word_cache = self.word_cache.lock().unwrap();
+
+            words
+                .iter()
+                .filter(|word| !word_cache.contains_key(*word))
+                .cloned()
+                .collect()
+        };
+
+        let dictionary = &self.dictionary;
+
+        let found: Vec<_> = missing
+            .into_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(|word| {
+                let suggestions = uncached_suggest_correct_spelling(&word, dictionary);
+                (word, suggestions)
             })
-            .clone()
+            .collect();
+
+        self.word_cache.lock().unwrap().extend(found);
     }
 }
 
-impl<T: Dictionary> Linter for SpellCheck<T> {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
-        let mut lints = Vec::new();
+/// Whether a word has at least two letters, all of them uppercase.
+fn is_all_caps(word: &[char]) -> bool {
+    let mut letters = word.iter().filter(|c| c.is_alphabetic());
+
+    letters.clone().count() > 1 && letters.all(|c| c.is_uppercase())
+}
+
+/// Whether a word looks like an identifier from code, like "getUserId" or
+/// "user_id".
+fn is_identifier(word: &[char]) -> bool {
+    word.contains(&'_')
+        || word
+            .windows(2)
+            .any(|pair| pair[0].is_lowercase() && pair[1].is_uppercase())
+}
+
+/// All-caps words carry no information about the intended casing, and
+/// searching for them as-is gives poor suggestions. Search for the lowercase
+/// form instead; the casing is restored afterwards.
+fn suggestion_query(word: &[char]) -> CharString {
+    if is_all_caps(word) {
+        word.to_lower()
+    } else {
+        word.to_smallvec()
+    }
+}
+
+fn uncached_suggest_correct_spelling(
+    word: &[char],
+    dictionary: &impl Dictionary,
+) -> Vec<CharString> {
+    // Back off until we find a match.
+    let mut suggestions = Vec::new();
+    let mut dist = 2;
+
+    while suggestions.is_empty() && dist < 5 {
+        suggestions = suggest_correct_spelling(word, 100, dist, dictionary)
+            .into_iter()
+            .map(|v| v.to_smallvec())
+            .collect();
+
+        dist += 1;
+    }
+
+    suggestions
+}
+
+/// Flag words spelled the way `variant` doesn't prefer, like "color" in
+/// British English.
+fn variant_lints(document: &Document, variant: SpellingVariant) -> Vec<Lint> {
+    if variant == SpellingVariant::Either {
+        return Vec::new();
+    }
+
+    let mut lints = Vec::new();
+
+    for word in document.iter_words() {
+        let chars = document.get_span_content(word.span);
+        let lower = chars.iter().collect::<String>().to_lowercase();
+
+        let Some(preferred) = VARIANTS.preferred(&lower, variant) else {
+            continue;
+        };
+
+        let replacement: CharString = preferred.chars().collect();
+
+        lints.push(Lint {
+            span: word.span,
+            lint_kind: LintKind::Enhancement,
+            suggestions: vec![Suggestion::ReplaceWith(
+                replacement.with_case_of(chars).to_vec(),
+            )],
+            message: match variant {
+                SpellingVariant::BritishEnglish => {
+                    format!("In British English, this is spelled “{preferred}”.")
+                }
+                _ => format!("In American English, this is spelled “{preferred}”."),
+            },
+            priority: 63,
+            severity: LintKind::Enhancement.default_severity(),
+            ..Default::default()
+        });
+    }
+
+    lints
+}
+
+/// Spell check only the lines of `new` that aren't in `old`, so problems in
+/// text that was left untouched aren't reported.
+///
+/// Lines are compared whole, without regard to order, so a line that was only
+/// moved counts as unchanged.
+/// `new` is parsed as Markdown, and the spans of the lints refer to it.
+pub fn spell_check_diff(old: &str, new: &str, dictionary: &impl Dictionary) -> Vec<Lint> {
+    let mut old_lines: HashMap<&str, usize> = HashMap::new();
+
+    for line in old.split('\n') {
+        *old_lines.entry(line).or_default() += 1;
+    }
+
+    let mut changed = Vec::new();
+    let mut start = 0;
+
+    for line in new.split('\n') {
+        let span = Span::new(start, start + line.chars().count());
+        start = span.end + 1;
+
+        match old_lines.get_mut(line) {
+            Some(count) if *count > 0 => *count -= 1,
+            _ => changed.push(span),
+        }
+    }
+
+    if changed.is_empty() {
+        return Vec::new();
+    }
+
+    let document = Document::new_markdown(new, dictionary);
+
+    SpellCheck::new(dictionary).check(&document.restricted_to(&changed))
+}
+
+/// Spell check text from `reader` one line at a time, so inputs of any size
+/// can be checked without holding them in memory.
+///
+/// Each line is parsed as plain English on its own, so a construct split
+/// across lines is checked as separate pieces: a word hyphenated at the end
+/// of one line is seen as two words, for example.
+/// The spans of the lints are char offsets from the start of the stream, and
+/// the text isn't normalized, so they line up with the input exactly.
+///
+/// Memory use is bounded by the longest line, plus a fixed-size cache of
+/// suggestions.
+/// Reading stops at the first I/O error, which is logged.
+pub fn spell_check_reader<R: BufRead, D: Dictionary>(
+    mut reader: R,
+    dictionary: D,
+) -> impl Iterator<Item = Lint> {
+    let linter = SpellCheck::new(dictionary);
+    let mut pending = VecDeque::new();
+    let mut line = String::new();
+    let mut offset = 0;
+
+    std::iter::from_fn(move || loop {
+        if let Some(lint) = pending.pop_front() {
+            return Some(lint);
+        }
+
+        line.clear();
+
+        match reader.read_line(&mut line) {
+            Ok(0) => return None,
+            Ok(_) => (),
+            Err(err) => {
+                tracing::warn!("Stopped spell checking a stream after an I/O error: {err}");
+                return None;
+            }
+        }
+
+        let document = Document::new_with_normalizer(
+            &line,
+            &mut PlainEnglish,
+            &linter.dictionary,
+            &Normalizer::none(),
+        );
+
+        pending.extend(linter.check(&document).into_iter().map(|mut lint| {
+            lint.span.push_by(offset);
+            lint
+        }));
+
+        offset += line.chars().count();
+    })
+}
+
+/// The word directly before `word`, unless something other than whitespace
+/// separates them.
+fn previous_word<'a>(document: &'a Document, word: &Token) -> Option<&'a [char]> {
+    let tokens = document.get_tokens();
+    let idx = tokens
+        .binary_search_by_key(&word.span.start, |token| token.span.start)
+        .ok()?;
+
+    let prev = tokens[..idx]
+        .iter()
+        .rev()
+        .find(|token| !token.kind.is_whitespace())?;
+
+    prev.kind
+        .is_word()
+        .then(|| document.get_span_content(prev.span))
+}
+
+impl<T: Dictionary> Rule for SpellCheck<T> {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        let mut lints = variant_lints(document, self.config.spelling_variant);
+
+        let misspelled: Vec<_> = document
+            .iter_words()
+            .filter(|word| {
+                let chars = document.get_span_content(word.span);
+                !self.config.is_ignored(chars)
+                    && !self.dictionary.contains_word(chars)
+                    && !VARIANTS.contains(&chars.iter().collect::<String>().to_lowercase())
+                    && !(self.config.allow_compounds
+                        && self.dictionary.decompose_compound(chars).is_some())
+            })
+            .collect();
+
+        #[cfg(feature = "rayon")]
+        self.fill_cache(
+            &misspelled
+                .iter()
+                .map(|word| suggestion_query(document.get_span_content(word.span)))
+                .collect::<Vec<_>>(),
+        );
 
-        for word in document.iter_words() {
+        for word in misspelled {
             let word_chars = document.get_span_content(word.span);
-            if self.dictionary.contains_word(word_chars) {
-                continue;
+
+            let query = suggestion_query(word_chars);
+            let mut possibilities = self.cached_suggest_correct_spelling(&query);
+
+            // Without a dictionary word a single edit away, this is more
+            // likely a name or jargon than a typo.
+            let is_typo = possibilities
+                .iter()
+                .any(|possibility| suggestion_edit_distance(&query, possibility) <= 1);
+
+            if self.config.min_confidence > 0.0 {
+                possibilities.retain(|possibility| {
+                    suggestion_confidence(&query, possibility) >= self.config.min_confidence
+                });
             }
 
-            let mut possibilities = self.cached_suggest_correct_spelling(word_chars);
+            if let (Some(bigrams), Some(prev_word)) =
+                (&self.config.bigrams, previous_word(document, &word))
+            {
+                rerank_by_context(prev_word, word_chars, &mut possibilities, bigrams);
+            }
 
             if possibilities.len() > 3 {
                 possibilities.resize_with(3, || panic!());
             }
 
-            // If the misspelled word is capitalized, capitalize the results too.
-            if let Some(mis_f) = word_chars.first() {
-                if mis_f.is_uppercase() {
-                    for sug_f in possibilities.iter_mut().filter_map(|w| w.first_mut()) {
-                        *sug_f = sug_f.to_uppercase().next().unwrap();
-                    }
-                }
-            }
+            let metadata = possibilities
+                .first()
+                .map(|closest| self.dictionary.get_word_metadata(closest));
+
+            // Match the casing of the misspelled word, so "Teh" becomes "The".
+            let possibilities: Vec<CharString> = possibilities
+                .into_iter()
+                .map(|word| word.with_case_of(word_chars).to_smallvec())
+                .collect();
+
+            let candidates = possibilities
+                .iter()
+                .map(|word| (word.to_string(), suggestion_edit_distance(&query, word)))
+                .collect();
 
             let suggestions = possibilities
                 .into_iter()
                 .map(|word| Suggestion::ReplaceWith(word.to_vec()));
 
+            let (lint_kind, message) = if is_typo {
+                (
+                    LintKind::Spelling,
+                    format!(
+                        "Did you mean to spell “{}” this way?",
+                        document.get_span_content_str(word.span)
+                    ),
+                )
+            } else {
+                (
+                    LintKind::UnknownWord,
+                    format!(
+                        "“{}” isn't in the dictionary.",
+                        document.get_span_content_str(word.span)
+                    ),
+                )
+            };
+
             lints.push(Lint {
                 span: word.span,
-                lint_kind: LintKind::Spelling,
+                lint_kind,
                 suggestions: suggestions.collect(),
-                message: format!(
-                    "Did you mean to spell “{}” this way?",
-                    document.get_span_content_str(word.span)
-                ),
+                message,
                 priority: 63,
+                severity: lint_kind.default_severity(),
+                metadata,
+                candidates,
             })
         }
 
+        sort_lints(&mut lints);
+
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Spelling
+    }
+
     fn description(&self) -> &'static str {
         "Looks and provides corrections for misspelled words."
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        spell_check_diff, spell_check_reader, SpellCheck, SpellCheckConfig, SpellingVariant,
+    };
+    use std::sync::Arc;
+
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::linting::{sort_lints, LintKind, Linter, Severity};
+    use crate::{BigramModel, Dictionary, Document, FstDictionary};
+
+    #[test]
+    fn reader_offsets_are_relative_to_the_stream() {
+        let source = "The first line is fine.\nThis one has a tpyo.\r\nSo does thsi one.\n";
+
+        let lints: Vec<_> =
+            spell_check_reader(source.as_bytes(), FstDictionary::curated()).collect();
+
+        let chars: Vec<char> = source.chars().collect();
+        let flagged: Vec<String> = lints
+            .iter()
+            .map(|lint| lint.span.get_content(&chars).iter().collect())
+            .collect();
+
+        assert_eq!(flagged, vec!["tpyo", "thsi"]);
+    }
+
+    #[test]
+    fn reader_matches_whole_document_check() {
+        let source = "Ths is a tset.\nEverything else is spelled right.\nExcept for ths.";
+
+        let streamed = spell_check_reader(source.as_bytes(), FstDictionary::curated()).count();
+        let whole = SpellCheck::new(FstDictionary::curated())
+            .lint(&Document::new_plain_english_curated(source))
+            .len();
+
+        assert_eq!(streamed, whole);
+    }
+
+    #[test]
+    fn diff_ignores_untouched_lines() {
+        let old = "This line has a tpyo.\nThis one is fine.";
+        let new = "This line has a tpyo.\nThis one is fnie.";
+
+        let lints = spell_check_diff(old, new, &FstDictionary::curated());
+
+        assert_eq!(lints.len(), 1);
+
+        let chars: Vec<char> = new.chars().collect();
+        assert_eq!(
+            lints[0].span.get_content(&chars).iter().collect::<String>(),
+            "fnie"
+        );
+    }
+
+    #[test]
+    fn diff_checks_added_lines() {
+        let old = "First line.";
+        let new = "First line.\nA nwe line.\nLast line.";
+
+        assert_eq!(
+            spell_check_diff(old, new, &FstDictionary::curated()).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_text_is_clean() {
+        let text = "A tpyo that was already here.";
+
+        assert!(spell_check_diff(text, text, &FstDictionary::curated()).is_empty());
+    }
+
+    #[test]
+    fn survives_absurdly_long_words() {
+        let source = format!("Someone pasted {} here.", "qxzj".repeat(50));
+
+        assert_lint_count(&source, SpellCheck::new(FstDictionary::curated()), 1);
+    }
+
+    #[test]
+    fn ignores_urls() {
+        assert_lint_count(
+            "See https://github.com/automattic/harperr for details.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_bare_urls() {
+        assert_lint_count(
+            "Visit github.com/automattic/harperr, please.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_email_addresses() {
+        assert_lint_count(
+            "Mail qwertyuiop@harperr.dev.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn still_catches_misspellings_near_urls() {
+        assert_lint_count(
+            "Visit github.com/automattic/harper, plase.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn ignores_configured_words() {
+        assert_lint_count(
+            "Copy your kubeconfig before running rustc.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_ignored_words(["kubeconfig", "rustc"]),
+            ),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignored_words_are_case_insensitive_by_default() {
+        assert_lint_count(
+            "Kubeconfig files are YAML.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_ignored_word("kubeconfig"),
+            ),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignored_words_can_be_case_sensitive() {
+        assert_lint_count(
+            "Kubeconfig files are YAML.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new()
+                    .with_ignored_word("kubeconfig")
+                    .with_case_sensitive_ignore(true),
+            ),
+            1,
+        );
+    }
+
+    #[test]
+    fn skips_single_letters_by_default() {
+        assert_lint_count(
+            "Press ð to continue.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn can_check_single_letters() {
+        assert_lint_count(
+            "Press ð to continue.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_min_length(1),
+            ),
+            1,
+        );
+    }
+
+    #[test]
+    fn checks_two_letter_words_by_default() {
+        assert_lint_count(
+            "Press xq to continue.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn respects_min_length() {
+        assert_lint_count(
+            "Press xqz to continue.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_min_length(4),
+            ),
+            0,
+        );
+    }
+
+    #[test]
+    fn flags_compounds_by_default() {
+        assert_lint_count(
+            "Run the grammarchecker.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn can_allow_compounds() {
+        assert_lint_count(
+            "Run the grammarchecker, not the grammarchekker.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_compounds(true),
+            ),
+            1,
+        );
+    }
+
+    #[test]
+    fn output_is_sorted_and_deterministic() {
+        let source = "Teh colour of the skye. Ths is a tset, and so is thsi.";
+        let config =
+            SpellCheckConfig::new().with_spelling_variant(SpellingVariant::AmericanEnglish);
+
+        let run = || {
+            SpellCheck::with_config(FstDictionary::curated(), config.clone())
+                .lint(&Document::new_plain_english_curated(source))
+        };
+
+        let first = run();
+
+        let mut sorted = first.clone();
+        sort_lints(&mut sorted);
+
+        assert_eq!(first, sorted);
+        assert_eq!(first, run());
+    }
+
+    #[test]
+    fn close_misspellings_are_typos() {
+        let document = Document::new_plain_english_curated("This is a tset.");
+        let lints = SpellCheck::new(FstDictionary::curated()).lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].lint_kind, LintKind::Spelling);
+    }
+
+    #[test]
+    fn distant_words_are_unknown() {
+        let document = Document::new_plain_english_curated("We met Zbigniew there.");
+        let lints = SpellCheck::new(FstDictionary::curated()).lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].lint_kind, LintKind::UnknownWord);
+    }
+
+    #[test]
+    fn keeps_weak_suggestions_by_default() {
+        let document = Document::new_plain_english_curated("The zqxjv was here.");
+        let lints = SpellCheck::new(FstDictionary::curated()).lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert!(!lints[0].suggestions.is_empty());
+    }
+
+    #[test]
+    fn min_confidence_drops_weak_suggestions() {
+        let document = Document::new_plain_english_curated("The zqxjv was here.");
+        let lints = SpellCheck::with_config(
+            FstDictionary::curated(),
+            SpellCheckConfig::new().with_min_confidence(0.7),
+        )
+        .lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert!(lints[0].suggestions.is_empty());
+    }
+
+    #[test]
+    fn min_confidence_keeps_strong_suggestions() {
+        assert_suggestion_result(
+            "We climbed the mountian.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_min_confidence(0.7),
+            ),
+            "We climbed the mountain.",
+        );
+    }
+
+    #[test]
+    fn lints_carry_candidates_and_metadata() {
+        let dict = FstDictionary::curated();
+        let document = Document::new_plain_english_curated("Thiz is fine.");
+        let lints = SpellCheck::new(dict.clone()).lint(&document);
+
+        assert_eq!(lints.len(), 1);
+
+        let lint = &lints[0];
+
+        assert_eq!(lint.candidates.len(), lint.suggestions.len());
+        assert_eq!(lint.candidates[0].0, "This");
+        assert_eq!(lint.candidates[0].1, 1);
+        assert_eq!(lint.metadata, Some(dict.get_word_metadata_str("this")));
+    }
+
+    #[test]
+    fn typos_are_errors_without_a_lint_group() {
+        let document = Document::new_plain_english_curated("This is a tset.");
+        let lints = SpellCheck::new(FstDictionary::curated()).lint(&document);
+
+        assert_eq!(lints[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn flags_all_caps_by_default() {
+        assert_lint_count(
+            "The XKCDQ format is popular.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn can_skip_all_caps() {
+        assert_lint_count(
+            "The XKCDQ format is popular, but the formatt is not.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_skip_all_caps(true),
+            ),
+            1,
+        );
+    }
+
+    #[test]
+    fn flags_identifiers_by_default() {
+        assert_lint_count(
+            "Call getUserId first.",
+            SpellCheck::new(FstDictionary::curated()),
+            1,
+        );
+    }
+
+    #[test]
+    fn can_skip_identifiers() {
+        assert_lint_count(
+            "Call getUserId or fetch_user_id, then retrun.",
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_skip_identifiers(true),
+            ),
+            1,
+        );
+    }
+
+    #[test]
+    fn never_flags_numbers() {
+        assert_lint_count(
+            "It happened in 2024.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn suggestions_match_title_case() {
+        assert_suggestion_result(
+            "Thw cat sat.",
+            SpellCheck::new(FstDictionary::curated()),
+            "The cat sat.",
+        );
+    }
+
+    #[test]
+    fn suggestions_match_all_caps() {
+        assert_suggestion_result(
+            "A SPELING ERROR.",
+            SpellCheck::new(FstDictionary::curated()),
+            "A SPELLING ERROR.",
+        );
+    }
+
+    #[test]
+    fn bigrams_rerank_suggestions() {
+        let spell_check = || {
+            SpellCheck::with_config(
+                FstDictionary::curated(),
+                SpellCheckConfig::new().with_bigrams(BigramModel::curated()),
+            )
+        };
+
+        assert_suggestion_result(
+            "They came frmo home.",
+            spell_check(),
+            "They came from home.",
+        );
+        assert_suggestion_result("Fill out the frmo.", spell_check(), "Fill out the form.");
+    }
+
+    #[test]
+    fn accepts_contractions() {
+        assert_lint_count(
+            "You're sure we can't leave at five o'clock? Y'all know I won't.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+        assert_lint_count(
+            "They’re sure it isn’t.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn accepts_rock_n_roll() {
+        assert_lint_count(
+            "She loves rock'n'roll.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn accepts_possessives() {
+        assert_lint_count(
+            "James's car is next to the dog's bed.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn checks_hyphenated_words_by_part() {
+        assert_lint_count(
+            "A well-known, state-of-the-art tool.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+        assert_suggestion_result(
+            "A well-knwn tool.",
+            SpellCheck::new(FstDictionary::curated()),
+            "A well-known tool.",
+        );
+    }
+
+    fn with_variant(spelling_variant: SpellingVariant) -> SpellCheck<Arc<FstDictionary>> {
+        SpellCheck::with_config(
+            FstDictionary::curated(),
+            SpellCheckConfig::new().with_spelling_variant(spelling_variant),
+        )
+    }
+
+    #[test]
+    fn accepts_either_spelling_by_default() {
+        assert_lint_count(
+            "The colour of the color wheel.",
+            SpellCheck::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn british_prefers_colour() {
+        assert_suggestion_result(
+            "The color is nice.",
+            with_variant(SpellingVariant::BritishEnglish),
+            "The colour is nice.",
+        );
+        assert_lint_count(
+            "The colour is nice.",
+            with_variant(SpellingVariant::BritishEnglish),
+            0,
+        );
+    }
+
+    #[test]
+    fn american_prefers_color() {
+        assert_suggestion_result(
+            "Colour me impressed.",
+            with_variant(SpellingVariant::AmericanEnglish),
+            "Color me impressed.",
+        );
+    }
+
+    #[test]
+    fn variant_lints_are_not_spelling_errors() {
+        let document = Document::new_plain_english_curated("We analyzed it.");
+        let lints = with_variant(SpellingVariant::BritishEnglish).lint(&document);
+
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].lint_kind, LintKind::Enhancement);
+    }
+}
@@ -1,13 +1,22 @@
-use crate::linting::{LintKind, Linter, Suggestion};
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use crate::linting::{LintKind, Rule, Suggestion};
 use crate::{Document, Lint, TokenStringExt};
 
+lazy_static! {
+    static ref SPELLED_UP_TO_HUNDRED: HashMap<String, u64> = (0..=100)
+        .map(|num| (spell_out_number(num).unwrap(), num))
+        .collect();
+}
+
 /// Linter that checks to make sure small integers (< 10) are spelled
 /// out.
 #[derive(Default, Clone, Copy)]
 pub struct SpelledNumbers;
 
-impl Linter for SpelledNumbers {
-    fn lint(&mut self, document: &Document) -> Vec<crate::Lint> {
+impl Rule for SpelledNumbers {
+    fn check(&self, document: &Document) -> Vec<crate::Lint> {
         let mut lints = Vec::new();
 
         for number_tok in document.iter_numbers() {
@@ -23,6 +32,8 @@ impl Linter for SpelledNumbers {
                     )],
                     message: "Try to spell out numbers less than ten.".to_string(),
                     priority: 63,
+                    severity: LintKind::Readability.default_severity(),
+                    ..Default::default()
                 })
             }
         }
@@ -30,6 +41,10 @@ impl Linter for SpelledNumbers {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Readability
+    }
+
     fn description(&self) -> &'static str {
         "Most style guides recommend that you spell out numbers less than ten."
     }
@@ -40,7 +55,7 @@ impl Linter for SpelledNumbers {
 /// For example: 100 -> one hundred.
 ///
 /// Works for numbers up to 999, but can be expanded to include more powers of 10.
-fn spell_out_number(num: u64) -> Option<String> {
+pub(super) fn spell_out_number(num: u64) -> Option<String> {
     if num > 999 {
         return None;
     }
@@ -92,11 +107,19 @@ fn spell_out_number(num: u64) -> Option<String> {
     })
 }
 
+/// The inverse of [`spell_out_number`], for lowercase numbers from zero to
+/// one hundred.
+///
+/// For example: twenty-five -> 25.
+pub(super) fn parse_spelled_number(words: &str) -> Option<u64> {
+    SPELLED_UP_TO_HUNDRED.get(words).copied()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::linting::tests::assert_suggestion_result;
 
-    use super::{spell_out_number, SpelledNumbers};
+    use super::{parse_spelled_number, spell_out_number, SpelledNumbers};
 
     #[test]
     fn produces_zero() {
@@ -116,6 +139,22 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parses_spelled_numbers() {
+        assert_eq!(parse_spelled_number("zero"), Some(0));
+        assert_eq!(parse_spelled_number("eighty-two"), Some(82));
+        assert_eq!(parse_spelled_number("one hundred"), Some(100));
+        assert_eq!(parse_spelled_number("one hundred one"), None);
+        assert_eq!(parse_spelled_number("pigs"), None);
+    }
+
+    #[test]
+    fn round_trips_up_to_hundred() {
+        for i in 0..=100 {
+            assert_eq!(parse_spelled_number(&spell_out_number(i).unwrap()), Some(i));
+        }
+    }
+
     #[test]
     fn corrects_nine() {
         assert_suggestion_result("There are 9 pigs.", SpelledNumbers, "There are nine pigs.");
@@ -0,0 +1,163 @@
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::{Dictionary, Document, TokenStringExt};
+
+/// Looks for unknown words that are actually two known words run together,
+/// like "alot" or "infact".
+pub struct SplitWords<T>
+where
+    T: Dictionary,
+{
+    dictionary: T,
+}
+
+impl<T: Dictionary> SplitWords<T> {
+    pub fn new(dictionary: T) -> Self {
+        Self { dictionary }
+    }
+
+    /// Check whether a word can stand as one half of a split.
+    ///
+    /// Single letters are only accepted if they are words in their own
+    /// right ("a" and "I"), to avoid noise.
+    fn is_valid_half(&self, half: &[char]) -> bool {
+        match half {
+            [] => false,
+            [c] => matches!(c, 'a' | 'A' | 'I') && self.dictionary.contains_word(half),
+            _ => self.dictionary.contains_word(half),
+        }
+    }
+}
+
+/// "a" directly before a vowel would be "an", so a split like "aadd" → "a add"
+/// is almost certainly wrong.
+fn is_article_before_vowel(left: &[char], right: &[char]) -> bool {
+    matches!(left, ['a' | 'A'])
+        && right
+            .first()
+            .is_some_and(|c| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u'))
+}
+
+impl<T: Dictionary> Rule for SplitWords<T> {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        let mut lints = Vec::new();
+
+        for word in document.iter_words() {
+            let word_chars = document.get_span_content(word.span);
+
+            if word_chars.len() < 3 || self.dictionary.contains_word(word_chars) {
+                continue;
+            }
+
+            let mut splits: Vec<_> = (1..word_chars.len())
+                .map(|split_at| word_chars.split_at(split_at))
+                .filter(|(a, b)| self.is_valid_half(a) && self.is_valid_half(b))
+                .filter(|(a, b)| !is_article_before_vowel(a, b))
+                .collect();
+
+            // Splits that leave a lone letter behind are less likely to be
+            // what the author meant ("a swell" vs. "as well").
+            splits.sort_by_key(|(a, b)| (a.len() == 1) as u8 + (b.len() == 1) as u8);
+
+            let suggestions: Vec<_> = splits
+                .into_iter()
+                .map(|(a, b)| {
+                    let mut split = a.to_vec();
+                    split.push(' ');
+                    split.extend_from_slice(b);
+                    Suggestion::ReplaceWith(split)
+                })
+                .collect();
+
+            if suggestions.is_empty() {
+                continue;
+            }
+
+            lints.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::Spelling,
+                suggestions,
+                message: format!(
+                    "“{}” looks like two words that were run together.",
+                    document.get_span_content_str(word.span)
+                ),
+                // Less important than a `SpellCheck` lint for the same word,
+                // so a real correction ("adress" to "address") isn't dropped
+                // in favor of a split ("a dress").
+                priority: 95,
+                severity: LintKind::Spelling.default_severity(),
+                ..Default::default()
+            })
+        }
+
+        lints
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Spelling
+    }
+
+    fn description(&self) -> &'static str {
+        "Looks for unknown words that are made of two known words missing a space between them."
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitWords;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+    use crate::FstDictionary;
+
+    #[test]
+    fn splits_alot() {
+        assert_suggestion_result(
+            "I have alot of work.",
+            SplitWords::new(FstDictionary::curated()),
+            "I have a lot of work.",
+        );
+    }
+
+    #[test]
+    fn splits_infact() {
+        assert_suggestion_result(
+            "Infact, it works.",
+            SplitWords::new(FstDictionary::curated()),
+            "In fact, it works.",
+        );
+    }
+
+    #[test]
+    fn splits_aswell() {
+        assert_suggestion_result(
+            "It works aswell.",
+            SplitWords::new(FstDictionary::curated()),
+            "It works as well.",
+        );
+    }
+
+    #[test]
+    fn no_article_before_vowel() {
+        assert_lint_count(
+            "Let's aadd one.",
+            SplitWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_known_words() {
+        assert_lint_count(
+            "This notebook is fine.",
+            SplitWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+
+    #[test]
+    fn ignores_unsplittable_words() {
+        assert_lint_count(
+            "Xqzvt is noise.",
+            SplitWords::new(FstDictionary::curated()),
+            0,
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::parsers::Markdown;
+use crate::{Document, Punctuation, Span, Token, TokenKind, TokenStringExt};
+
+/// Flag paragraphs whose final sentence doesn't end with a period, question
+/// mark or exclamation point, and suggest adding a period.
+///
+/// Headings, list items and tables aren't prose, so they are never flagged,
+/// and neither are paragraphs that end with a colon, since they usually
+/// introduce what comes after them.
+/// Paragraphs that end in something other than a word or number, like
+/// inline code, are left alone as well.
+pub fn terminal_punctuation_check(document: &Document) -> Vec<Lint> {
+    let source = document.get_source();
+
+    let structural: Vec<Span> = Markdown::heading_spans(source)
+        .into_iter()
+        .chain(Markdown::list_item_spans(source))
+        .chain(Markdown::table_spans(source))
+        .collect();
+
+    let mut output = Vec::new();
+
+    for paragraph in document.iter_paragraphs() {
+        let content: Vec<&Token> = paragraph
+            .iter()
+            .filter(|token| !token.kind.is_whitespace() && !token.kind.is_paragraph_break())
+            .collect();
+
+        let (Some(first), Some(last)) = (content.first(), content.last()) else {
+            continue;
+        };
+
+        let span = Span::new(first.span.start, last.span.end);
+
+        if structural.iter().any(|element| element.overlaps_with(span)) {
+            continue;
+        }
+
+        // Look past closing quotes and brackets, so `(like this.)` counts as
+        // terminated.
+        let Some(last) = content.iter().rev().find(|token| !is_closing(token.kind)) else {
+            continue;
+        };
+
+        if !(last.kind.is_word() || last.kind.is_number()) {
+            continue;
+        }
+
+        let mut replacement = document.get_span_content(last.span).to_vec();
+        replacement.push('.');
+
+        output.push(Lint {
+            span: last.span,
+            lint_kind: LintKind::Formatting,
+            suggestions: vec![Suggestion::ReplaceWith(replacement)],
+            message: "This sentence is missing its terminal punctuation.".to_string(),
+            severity: LintKind::Formatting.default_severity(),
+            ..Default::default()
+        });
+    }
+
+    output
+}
+
+/// Looks for paragraphs that don't end with a period, question mark or
+/// exclamation point.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TerminalPunctuation;
+
+impl Rule for TerminalPunctuation {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        terminal_punctuation_check(document)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
+    fn description(&self) -> &'static str {
+        "Sentences should end with a period, question mark or exclamation point. This rule flags paragraphs whose last sentence doesn't."
+    }
+}
+
+fn is_closing(kind: TokenKind) -> bool {
+    matches!(
+        kind,
+        TokenKind::Punctuation(
+            Punctuation::Quote(_)
+                | Punctuation::CloseRound
+                | Punctuation::CloseSquare
+                | Punctuation::CloseCurly
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TerminalPunctuation;
+    use crate::linting::tests::{assert_lint_count, assert_suggestion_result};
+
+    #[test]
+    fn adds_missing_period() {
+        assert_suggestion_result(
+            "This is the first sentence. This one just stops",
+            TerminalPunctuation,
+            "This is the first sentence. This one just stops.",
+        );
+    }
+
+    #[test]
+    fn accepts_abbreviations_that_end_sentences() {
+        assert_lint_count(
+            "We packed tents, stoves, etc. Then we left.",
+            TerminalPunctuation,
+            0,
+        );
+        assert_lint_count("We packed tents, stoves, etc.", TerminalPunctuation, 0);
+        assert_lint_count(
+            "We packed tents, stoves, etc.\n\nThen we left.",
+            TerminalPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn checks_each_paragraph() {
+        assert_lint_count(
+            "The first paragraph ends well.\n\nThe second does not\n\nNor does the third",
+            TerminalPunctuation,
+            2,
+        );
+    }
+
+    #[test]
+    fn accepts_terminated_paragraphs() {
+        assert_lint_count(
+            "Is this a question? It is! And it ends properly.",
+            TerminalPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn looks_past_closing_quotes_and_brackets() {
+        assert_lint_count(
+            "She said \"it works.\"\n\n(This is an aside.)",
+            TerminalPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_headings() {
+        assert_lint_count(
+            "# Getting Started\n\nSome text.\n\nAnother Heading\n---\n",
+            TerminalPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_list_items() {
+        assert_lint_count(
+            "Things to pack:\n\n- A warm coat\n- Some snacks\n\n1. First step\n2. Second step\n",
+            TerminalPunctuation,
+            0,
+        );
+    }
+
+    #[test]
+    fn skips_colons() {
+        assert_lint_count("Here is what happened next:", TerminalPunctuation, 0);
+    }
+
+    #[test]
+    fn skips_trailing_code() {
+        assert_lint_count("Run the command `cargo test`", TerminalPunctuation, 0);
+    }
+
+    #[test]
+    fn skips_tables() {
+        assert_lint_count(
+            "| Name | Role |\n| --- | --- |\n| Ada | Engineer |\n",
+            TerminalPunctuation,
+            0,
+        );
+    }
+}
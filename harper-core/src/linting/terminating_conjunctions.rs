@@ -66,9 +66,15 @@ impl PatternLinter for TerminatingConjunctions {
                  clause."
             ),
             priority: 63,
+            severity: LintKind::Miscellaneous.default_severity(),
+            ..Default::default()
         }
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "Subordinating conjunctions are words that create a grammatical space for another idea or clause. As such, they should never appear at the end of a clause."
     }
@@ -54,9 +54,15 @@ impl PatternLinter for ThatWhich {
             suggestions: vec![Suggestion::ReplaceWith(suggestion)],
             message: "“that that” sometimes means “that which”, which is clearer.".to_string(),
             priority: 126,
+            severity: LintKind::Repetition.default_severity(),
+            ..Default::default()
         }
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Repetition
+    }
+
     fn description(&self) -> &'static str {
         "Repeating the word \"that\" twice is often redundent. \"That which\" is easier to read."
     }
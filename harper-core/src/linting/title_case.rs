@@ -0,0 +1,294 @@
+use hashbrown::HashSet;
+use serde::{Deserialize, Serialize};
+
+use super::{Lint, LintKind, Rule, Suggestion};
+use crate::parsers::Markdown;
+use crate::{CharStringExt, Document, Token, TokenStringExt};
+
+/// Articles and coordinating conjunctions, which every style keeps lowercase.
+const ARTICLES_AND_CONJUNCTIONS: &[&str] = &["a", "an", "the", "and", "but", "for", "or", "nor"];
+
+/// Common prepositions, which Chicago style keeps lowercase regardless of
+/// their length.
+const PREPOSITIONS: &[&str] = &[
+    "about",
+    "above",
+    "across",
+    "after",
+    "against",
+    "along",
+    "among",
+    "around",
+    "as",
+    "at",
+    "before",
+    "behind",
+    "below",
+    "beneath",
+    "beside",
+    "between",
+    "beyond",
+    "by",
+    "down",
+    "during",
+    "except",
+    "from",
+    "in",
+    "inside",
+    "into",
+    "near",
+    "of",
+    "off",
+    "on",
+    "onto",
+    "out",
+    "outside",
+    "over",
+    "past",
+    "per",
+    "since",
+    "through",
+    "throughout",
+    "to",
+    "toward",
+    "towards",
+    "under",
+    "until",
+    "up",
+    "upon",
+    "via",
+    "with",
+    "within",
+    "without",
+];
+
+/// Which rules [`title_case_check`] follows.
+///
+/// Both capitalize the first and last words of a heading, and keep articles
+/// and coordinating conjunctions lowercase elsewhere.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TitleCaseStyle {
+    /// The Chicago Manual of Style, which keeps every preposition lowercase.
+    #[default]
+    Chicago,
+    /// The Associated Press Stylebook, which capitalizes prepositions of four
+    /// letters or more.
+    Ap,
+}
+
+impl TitleCaseStyle {
+    /// The words this style keeps lowercase, unless they start or end a
+    /// heading.
+    pub fn minor_words(&self) -> HashSet<String> {
+        let prepositions = PREPOSITIONS.iter().filter(|word| match self {
+            Self::Chicago => true,
+            Self::Ap => word.len() <= 3,
+        });
+
+        ARTICLES_AND_CONJUNCTIONS
+            .iter()
+            .chain(prepositions)
+            .map(|word| word.to_string())
+            .collect()
+    }
+}
+
+/// Flag the words of Markdown headings that aren't title-cased according to
+/// `style`.
+///
+/// Only the first letter of each word is checked, so words with unusual
+/// casing, like "iPhone" or "API", are left alone.
+pub fn title_case_check(document: &Document, style: TitleCaseStyle) -> Vec<Lint> {
+    lint_with_minor_words(document, &style.minor_words())
+}
+
+/// Looks for Markdown headings that aren't consistently title-cased.
+#[derive(Debug, Clone)]
+pub struct TitleCase {
+    minor_words: HashSet<String>,
+}
+
+impl TitleCase {
+    pub fn new(style: TitleCaseStyle) -> Self {
+        Self {
+            minor_words: style.minor_words(),
+        }
+    }
+
+    /// Use a custom list of words to keep lowercase, instead of the one from
+    /// the style.
+    pub fn with_minor_words(mut self, words: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.minor_words = words
+            .into_iter()
+            .map(|word| word.as_ref().to_lowercase())
+            .collect();
+        self
+    }
+}
+
+impl Default for TitleCase {
+    fn default() -> Self {
+        Self::new(TitleCaseStyle::default())
+    }
+}
+
+impl Rule for TitleCase {
+    fn check(&self, document: &Document) -> Vec<Lint> {
+        lint_with_minor_words(document, &self.minor_words)
+    }
+
+    fn kind(&self) -> LintKind {
+        LintKind::Capitalization
+    }
+
+    fn description(&self) -> &'static str {
+        "Headings read best when they are capitalized consistently. This rule checks that Markdown headings are in title case."
+    }
+}
+
+fn lint_with_minor_words(document: &Document, minor_words: &HashSet<String>) -> Vec<Lint> {
+    let mut output = Vec::new();
+
+    for heading in Markdown::heading_spans(document.get_source()) {
+        let words: Vec<Token> = document
+            .get_tokens()
+            .iter_words()
+            .filter(|word| heading.start <= word.span.start && word.span.end <= heading.end)
+            .collect();
+
+        for (idx, word) in words.iter().enumerate() {
+            let chars = document.get_span_content(word.span);
+
+            let Some((first, rest)) = chars.split_first() else {
+                continue;
+            };
+
+            // Leave words like "iPhone" and "API" alone.
+            if rest.iter().any(|c| c.is_uppercase()) {
+                continue;
+            }
+
+            let is_edge = idx == 0 || idx == words.len() - 1;
+            let is_minor = minor_words.contains(&chars.to_lower().to_string());
+
+            let (replacement, message) = if is_edge || !is_minor {
+                if !first.is_lowercase() {
+                    continue;
+                }
+
+                (
+                    first.to_uppercase().chain(rest.iter().copied()).collect(),
+                    "In a title-cased heading, this word should be capitalized.",
+                )
+            } else {
+                if !first.is_uppercase() {
+                    continue;
+                }
+
+                (
+                    chars.to_lower().to_vec(),
+                    "In a title-cased heading, this word should be lowercase.",
+                )
+            };
+
+            output.push(Lint {
+                span: word.span,
+                lint_kind: LintKind::Capitalization,
+                suggestions: vec![Suggestion::ReplaceWith(replacement)],
+                message: message.to_string(),
+                severity: LintKind::Capitalization.default_severity(),
+                ..Default::default()
+            });
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{title_case_check, TitleCase, TitleCaseStyle};
+    use crate::linting::tests::assert_suggestion_result;
+    use crate::linting::Linter;
+    use crate::Document;
+
+    fn lint_count(source: &str, mut linter: TitleCase) -> usize {
+        linter.lint(&Document::new_markdown_curated(source)).len()
+    }
+
+    #[test]
+    fn capitalizes_major_words() {
+        assert_suggestion_result(
+            "# Getting started with harper",
+            TitleCase::default(),
+            "# Getting Started with Harper",
+        );
+    }
+
+    #[test]
+    fn lowercases_minor_words() {
+        assert_suggestion_result(
+            "## The Lord Of The Rings",
+            TitleCase::default(),
+            "## The Lord of the Rings",
+        );
+    }
+
+    #[test]
+    fn capitalizes_first_and_last_words() {
+        assert_suggestion_result(
+            "# the place we come from",
+            TitleCase::default(),
+            "# The Place We Come From",
+        );
+    }
+
+    #[test]
+    fn ignores_body_text() {
+        assert_eq!(
+            lint_count(
+                "# A Good Title\n\nthe body is not a heading.",
+                TitleCase::default()
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn ignores_unusual_casing() {
+        assert_eq!(
+            lint_count("# Using the API on an iPhone", TitleCase::default()),
+            0
+        );
+    }
+
+    #[test]
+    fn ap_capitalizes_long_prepositions() {
+        let document = Document::new_markdown_curated("# Walking Through the Park");
+
+        assert!(title_case_check(&document, TitleCaseStyle::Ap).is_empty());
+        assert_eq!(
+            title_case_check(&document, TitleCaseStyle::Chicago).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn setext_headings_are_checked() {
+        assert_eq!(
+            lint_count("A heading of sorts\n===\n", TitleCase::default()),
+            2
+        );
+    }
+
+    #[test]
+    fn minor_words_are_configurable() {
+        assert_eq!(
+            lint_count(
+                "# Tips and Tricks",
+                TitleCase::default().with_minor_words(["tips"])
+            ),
+            1
+        );
+    }
+}
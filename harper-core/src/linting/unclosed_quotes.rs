@@ -1,12 +1,12 @@
-use super::{Lint, LintKind, Linter};
+use super::{Lint, LintKind, Rule};
 use crate::document::Document;
 use crate::{Punctuation, Quote, TokenKind};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct UnclosedQuotes;
 
-impl Linter for UnclosedQuotes {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for UnclosedQuotes {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         let mut lints = Vec::new();
 
         // TODO: Try zipping quote positions
@@ -19,6 +19,8 @@ impl Linter for UnclosedQuotes {
                     suggestions: vec![],
                     message: "This quote has no termination.".to_string(),
                     priority: 255,
+                    severity: LintKind::Formatting.default_severity(),
+                    ..Default::default()
                 })
             }
         }
@@ -26,6 +28,10 @@ impl Linter for UnclosedQuotes {
         lints
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Formatting
+    }
+
     fn description(&self) -> &'static str {
         "Quotation marks should always be closed. Unpaired quotation marks are a hallmark of sloppy work."
     }
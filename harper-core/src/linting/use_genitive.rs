@@ -65,9 +65,15 @@ impl PatternLinter for UseGenitive {
             suggestions: vec![Suggestion::ReplaceWith(vec!['t', 'h', 'e', 'i', 'r'])],
             message: "Use the genitive case.".to_string(),
             priority: 31,
+            severity: LintKind::Miscellaneous.default_severity(),
+            ..Default::default()
         }
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "Looks situations where the genitive case of \"there\" should be used."
     }
@@ -1,12 +1,12 @@
-use super::{Lint, Linter, Suggestion};
+use super::{Lint, LintKind, Rule, Suggestion};
 use crate::document::Document;
 use crate::{Token, TokenStringExt};
 
 #[derive(Debug, Clone, Copy, Default)]
 pub struct WrongQuotes;
 
-impl Linter for WrongQuotes {
-    fn lint(&mut self, document: &Document) -> Vec<Lint> {
+impl Rule for WrongQuotes {
+    fn check(&self, document: &Document) -> Vec<Lint> {
         document
             .iter_quote_indices()
             .zip(document.iter_quotes())
@@ -14,6 +14,10 @@ impl Linter for WrongQuotes {
             .collect()
     }
 
+    fn kind(&self) -> LintKind {
+        LintKind::Miscellaneous
+    }
+
     fn description(&self) -> &'static str {
         "The key on the keyboard often used as a quotation mark is actually a double-apostrophe. Use the correct character."
     }
@@ -0,0 +1,309 @@
+//! Conversions from Harper's types to those of the Language Server Protocol,
+//! for use in language servers.
+//!
+//! Requires the `lsp` feature.
+
+use lsp_types::{
+    CodeAction, CodeActionKind, Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range,
+    TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::linting::{Lint, Severity, Suggestion};
+use crate::{Document, Span};
+
+/// Converts between the char indices Harper uses and LSP positions, which
+/// count lines and UTF-16 code units within a line.
+///
+/// Line starts are found once up front, so each conversion only needs to
+/// scan a single line.
+pub struct PositionMapper<'a> {
+    source: &'a [char],
+    /// The char index each line starts at.
+    line_starts: Vec<usize>,
+}
+
+impl<'a> PositionMapper<'a> {
+    /// Map positions in the document's source.
+    ///
+    /// If [`Normalizations::nfc`](crate::Normalizations::nfc) changed the
+    /// text, its offsets no longer match the client's. Either build the
+    /// document with [`Normalizer::none`](crate::Normalizer::none), or use
+    /// [`Self::from_source`] on the original text along with
+    /// [`Normalizations::restore_spans`](crate::Normalizations::restore_spans).
+    pub fn new(document: &'a Document) -> Self {
+        Self::from_source(document.get_source())
+    }
+
+    pub fn from_source(source: &'a [char]) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                source
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, c)| **c == '\n')
+                    .map(|(idx, _)| idx + 1),
+            )
+            .collect();
+
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Indices past the end of the source map to the end of the source.
+    pub fn char_to_position(&self, index: usize) -> Position {
+        let index = index.min(self.source.len());
+        let line = self.line_starts.partition_point(|start| *start <= index) - 1;
+
+        let character: usize = self.source[self.line_starts[line]..index]
+            .iter()
+            .map(|c| c.len_utf16())
+            .sum();
+
+        Position {
+            line: line as u32,
+            character: character as u32,
+        }
+    }
+
+    /// Convert a byte offset into the UTF-8 encoding of the source.
+    ///
+    /// Offsets inside a multi-byte character map to the start of the next
+    /// character.
+    pub fn byte_to_position(&self, offset: usize) -> Position {
+        let mut bytes = 0;
+        let index = self
+            .source
+            .iter()
+            .take_while(|c| {
+                let before = bytes;
+                bytes += c.len_utf8();
+                before < offset
+            })
+            .count();
+
+        self.char_to_position(index)
+    }
+
+    /// Positions past the end of a line map to the end of that line, and
+    /// those past the last line map to the end of the source.
+    pub fn position_to_char(&self, position: Position) -> usize {
+        let Some(&line_start) = self.line_starts.get(position.line as usize) else {
+            return self.source.len();
+        };
+
+        let line_end = self
+            .line_starts
+            .get(position.line as usize + 1)
+            .map_or(self.source.len(), |next| next - 1);
+
+        let mut traversed_cols = 0;
+
+        for (idx, c) in self.source[line_start..line_end].iter().enumerate() {
+            if traversed_cols >= position.character as usize {
+                return line_start + idx;
+            }
+
+            traversed_cols += c.len_utf16();
+        }
+
+        line_end
+    }
+
+    pub fn span_to_range(&self, span: Span) -> Range {
+        Range {
+            start: self.char_to_position(span.start),
+            end: self.char_to_position(span.end),
+        }
+    }
+
+    pub fn range_to_span(&self, range: Range) -> Span {
+        Span::new(
+            self.position_to_char(range.start),
+            self.position_to_char(range.end),
+        )
+    }
+}
+
+impl From<Severity> for DiagnosticSeverity {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Info => DiagnosticSeverity::INFORMATION,
+            Severity::Hint => DiagnosticSeverity::HINT,
+        }
+    }
+}
+
+/// The lint's kind is used as the diagnostic's code.
+pub fn lint_to_diagnostic(lint: &Lint, mapper: &PositionMapper) -> Diagnostic {
+    Diagnostic {
+        range: mapper.span_to_range(lint.span),
+        severity: Some(lint.severity.into()),
+        code: Some(NumberOrString::String(lint.lint_kind.to_string())),
+        source: Some("Harper".to_string()),
+        message: lint.message.clone(),
+        ..Default::default()
+    }
+}
+
+/// The edit that applies `suggestion` to the text covered by `span`.
+pub fn suggestion_to_text_edit(
+    suggestion: &Suggestion,
+    span: Span,
+    mapper: &PositionMapper,
+) -> TextEdit {
+    let new_text = match suggestion {
+        Suggestion::ReplaceWith(with) => with.iter().collect(),
+        Suggestion::Remove => String::new(),
+    };
+
+    TextEdit {
+        range: mapper.span_to_range(span),
+        new_text,
+    }
+}
+
+/// A quick fix that applies `suggestion` to the document at `uri`.
+pub fn suggestion_to_code_action(
+    lint: &Lint,
+    suggestion: &Suggestion,
+    uri: &Url,
+    mapper: &PositionMapper,
+) -> CodeAction {
+    CodeAction {
+        title: suggestion.to_string(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![lint_to_diagnostic(lint, mapper)]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(
+                [(
+                    uri.clone(),
+                    vec![suggestion_to_text_edit(suggestion, lint.span, mapper)],
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// A quick fix for each of the lint's suggestions, in order.
+pub fn lint_to_code_actions(lint: &Lint, uri: &Url, mapper: &PositionMapper) -> Vec<CodeAction> {
+    lint.suggestions
+        .iter()
+        .map(|suggestion| suggestion_to_code_action(lint, suggestion, uri, mapper))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use lsp_types::{DiagnosticSeverity, Position, Range, Url};
+
+    use super::{lint_to_code_actions, lint_to_diagnostic, PositionMapper};
+    use crate::linting::{Lint, LintKind, Suggestion};
+    use crate::{Document, Span};
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    fn pos(line: u32, character: u32) -> Position {
+        Position { line, character }
+    }
+
+    #[test]
+    fn positions_round_trip() {
+        let source = chars("There was a man,\n his voice had timbre,\n unlike a boy.");
+        let mapper = PositionMapper::from_source(&source);
+
+        assert_eq!(mapper.char_to_position(19), pos(1, 2));
+        assert_eq!(mapper.position_to_char(pos(1, 2)), 19);
+        assert_eq!(mapper.char_to_position(0), pos(0, 0));
+        assert_eq!(mapper.char_to_position(17), pos(1, 0));
+    }
+
+    #[test]
+    fn counts_utf16_code_units() {
+        // The emoji is one char but two UTF-16 code units.
+        let source = chars("a😀b\nc");
+        let mapper = PositionMapper::from_source(&source);
+
+        assert_eq!(mapper.char_to_position(2), pos(0, 3));
+        assert_eq!(mapper.position_to_char(pos(0, 3)), 2);
+        assert_eq!(mapper.char_to_position(4), pos(1, 0));
+    }
+
+    #[test]
+    fn converts_byte_offsets() {
+        let source = chars("café\nbar");
+        let mapper = PositionMapper::from_source(&source);
+
+        // "é" takes two bytes, so "bar" starts at byte 6.
+        assert_eq!(mapper.byte_to_position(6), pos(1, 0));
+        assert_eq!(mapper.byte_to_position(3), pos(0, 3));
+    }
+
+    #[test]
+    fn restored_spans_match_decomposed_text() {
+        let text = "cafe\u{301} tpyo";
+        let document = Document::new_plain_english_curated(text);
+        let mut lints = vec![Lint::new(Span::new(5, 9), LintKind::Spelling, Vec::new())];
+        document.normalizations().restore_spans(&mut lints);
+
+        let source = chars(text);
+        let mapper = PositionMapper::from_source(&source);
+
+        assert_eq!(
+            lint_to_diagnostic(&lints[0], &mapper).range,
+            Range {
+                start: pos(0, 6),
+                end: pos(0, 10)
+            }
+        );
+    }
+
+    #[test]
+    fn clamps_out_of_range_positions() {
+        let source = chars("short\nline");
+        let mapper = PositionMapper::from_source(&source);
+
+        assert_eq!(mapper.position_to_char(pos(0, 50)), 5);
+        assert_eq!(mapper.position_to_char(pos(7, 0)), source.len());
+        assert_eq!(
+            mapper.range_to_span(Range {
+                start: pos(1, 0),
+                end: pos(1, 50)
+            }),
+            Span::new(6, 10)
+        );
+    }
+
+    #[test]
+    fn lint_becomes_diagnostic_and_actions() {
+        let document = Document::new_plain_english_curated("Hello\nwrold!");
+        let mapper = PositionMapper::new(&document);
+        let lint = Lint::new(
+            Span::new(6, 11),
+            LintKind::Spelling,
+            vec![Suggestion::ReplaceWith(chars("world")), Suggestion::Remove],
+        );
+
+        let diagnostic = lint_to_diagnostic(&lint, &mapper);
+        assert_eq!(diagnostic.range.start, pos(1, 0));
+        assert_eq!(diagnostic.range.end, pos(1, 5));
+        assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::ERROR));
+
+        let uri = Url::parse("file:///tmp/test.md").unwrap();
+        let actions = lint_to_code_actions(&lint, &uri, &mapper);
+        assert_eq!(actions.len(), 2);
+
+        let edits = &actions[1].edit.as_ref().unwrap().changes.as_ref().unwrap()[&uri];
+        assert_eq!(edits[0].new_text, "");
+        assert_eq!(edits[0].range, diagnostic.range);
+    }
+}
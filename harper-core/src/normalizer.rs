@@ -0,0 +1,395 @@
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+
+use crate::linting::Lint;
+use crate::Span;
+
+/// Rewrites text before it is lexed, so that equivalent spellings of a word
+/// are looked up the same way.
+///
+/// By default, only Unicode NFC is applied, so an "é" written as an "e"
+/// followed by a combining accent becomes a single character.
+/// Folding curly quotes and unusual dashes into their ASCII forms is opt-in,
+/// since it changes how the text is punctuated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Normalizer {
+    nfc: bool,
+    fold_quotes: bool,
+    fold_dashes: bool,
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self {
+            nfc: true,
+            fold_quotes: false,
+            fold_dashes: false,
+        }
+    }
+}
+
+impl Normalizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A normalizer that leaves text exactly as it is.
+    pub fn none() -> Self {
+        Self {
+            nfc: false,
+            fold_quotes: false,
+            fold_dashes: false,
+        }
+    }
+
+    /// Compose characters into Unicode Normalization Form C.
+    /// On by default.
+    ///
+    /// This is the only normalization that can change the length of the
+    /// text.
+    pub fn with_nfc(mut self, nfc: bool) -> Self {
+        self.nfc = nfc;
+        self
+    }
+
+    /// Replace curly and full-width quotes with `'` and `"`.
+    /// Off by default.
+    pub fn with_fold_quotes(mut self, fold_quotes: bool) -> Self {
+        self.fold_quotes = fold_quotes;
+        self
+    }
+
+    /// Replace hyphen and dash variants, including en and em dashes, with
+    /// `-`.
+    /// Off by default.
+    pub fn with_fold_dashes(mut self, fold_dashes: bool) -> Self {
+        self.fold_dashes = fold_dashes;
+        self
+    }
+
+    /// Normalize `text`, returning the result and which normalizations
+    /// changed it.
+    pub fn normalize(&self, text: &str) -> (Vec<char>, Normalizations) {
+        let mut ran = Normalizations::default();
+
+        let mut chars: Vec<char> = if self.nfc && !is_nfc(text) {
+            let (chars, original_offsets) = compose(text);
+            ran.nfc = true;
+            ran.original_offsets = original_offsets;
+            chars
+        } else {
+            text.chars().collect()
+        };
+
+        for c in chars.iter_mut() {
+            if self.fold_quotes {
+                if let Some(folded) = fold_quote(*c) {
+                    *c = folded;
+                    ran.quotes = true;
+                }
+            }
+
+            if self.fold_dashes && is_dash(*c) {
+                *c = '-';
+                ran.dashes = true;
+            }
+        }
+
+        (chars, ran)
+    }
+}
+
+/// Which of a [`Normalizer`]'s normalizations changed a piece of text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Normalizations {
+    /// Characters were composed into Unicode NFC, so offsets may not line up
+    /// with the original text.
+    /// Use [`Self::original_span`] to map them back.
+    pub nfc: bool,
+    /// Curly or full-width quotes were folded to `'` and `"`.
+    pub quotes: bool,
+    /// Hyphen and dash variants were folded to `-`.
+    pub dashes: bool,
+    /// The offset in the original text of each char of the normalized text,
+    /// followed by the length of the original text.
+    /// Empty if the offsets are the same in both.
+    original_offsets: Vec<usize>,
+}
+
+impl Normalizations {
+    /// Whether the text was left exactly as it was.
+    pub fn is_empty(&self) -> bool {
+        !self.nfc && !self.quotes && !self.dashes
+    }
+
+    /// The offset in the original text of a char offset in the normalized
+    /// text.
+    ///
+    /// Offsets past the end of the normalized text are moved by the same
+    /// amount as its end.
+    pub fn original_offset(&self, offset: usize) -> usize {
+        let Some((&end, _)) = self.original_offsets.split_last() else {
+            return offset;
+        };
+
+        self.original_offsets
+            .get(offset)
+            .copied()
+            .unwrap_or_else(|| end + offset - (self.original_offsets.len() - 1))
+    }
+
+    /// The offset in the normalized text of a char offset in the original
+    /// text.
+    ///
+    /// An offset inside a run of chars that were composed together moves to
+    /// the end of the composed char.
+    pub fn normalized_offset(&self, offset: usize) -> usize {
+        let Some((&end, _)) = self.original_offsets.split_last() else {
+            return offset;
+        };
+
+        if offset >= end {
+            return self.original_offsets.len() - 1 + offset - end;
+        }
+
+        self.original_offsets
+            .partition_point(|original| *original < offset)
+    }
+
+    /// Map a span of the normalized text onto the original text.
+    pub fn original_span(&self, span: Span) -> Span {
+        Span::new(
+            self.original_offset(span.start),
+            self.original_offset(span.end),
+        )
+    }
+
+    /// Map a span of the original text onto the normalized text.
+    pub fn normalized_span(&self, span: Span) -> Span {
+        Span::new(
+            self.normalized_offset(span.start),
+            self.normalized_offset(span.end),
+        )
+    }
+
+    /// Keep the offsets in line with an edit that replaced `span` of the
+    /// normalized text with `replacement_len` chars, and made the same change
+    /// to the original text.
+    pub(crate) fn replace(&mut self, span: Span, replacement_len: usize) {
+        if self.original_offsets.is_empty() {
+            return;
+        }
+
+        let original = self.original_span(span);
+
+        for offset in &mut self.original_offsets[span.end..] {
+            *offset = *offset + replacement_len - original.len();
+        }
+
+        self.original_offsets.splice(
+            span.start..span.end,
+            original.start..original.start + replacement_len,
+        );
+    }
+
+    /// Move the spans of lints found in the normalized text onto the original
+    /// text, so they can be shown or applied there.
+    pub fn restore_spans(&self, lints: &mut [Lint]) {
+        if self.original_offsets.is_empty() {
+            return;
+        }
+
+        for lint in lints {
+            lint.span = self.original_span(lint.span);
+        }
+    }
+
+    /// Put the original punctuation style back into a replacement built from
+    /// normalized text, so a suggestion matches the text around it.
+    ///
+    /// If quotes were folded, straight quotes become curly ones: `'` becomes
+    /// `’`, and `"` becomes `“` at the start of a word or `”` otherwise.
+    /// Folded dashes can't be told apart, so they are left as `-`.
+    pub fn restore_style(&self, replacement: &[char]) -> Vec<char> {
+        if !self.quotes {
+            return replacement.to_vec();
+        }
+
+        replacement
+            .iter()
+            .enumerate()
+            .map(|(idx, c)| match c {
+                '\'' => '’',
+                '"' if idx == 0 || replacement[idx - 1].is_whitespace() => '“',
+                '"' => '”',
+                _ => *c,
+            })
+            .collect()
+    }
+}
+
+/// Compose `text` into Unicode NFC, returning the composed chars and the
+/// offset in `text` of each one, followed by the length of `text`.
+///
+/// Text is composed a segment at a time. Each segment starts at an ASCII or
+/// whitespace char, since those never compose with the chars before them.
+/// Within a segment that changed length, chars are matched up in order.
+fn compose(text: &str) -> (Vec<char>, Vec<usize>) {
+    let original: Vec<char> = text.chars().collect();
+    let mut chars = Vec::with_capacity(original.len());
+    let mut offsets = Vec::with_capacity(original.len() + 1);
+
+    let mut start = 0;
+
+    while start < original.len() {
+        let end = original[start + 1..]
+            .iter()
+            .position(|c| c.is_ascii() || c.is_whitespace())
+            .map_or(original.len(), |idx| start + 1 + idx);
+
+        let composed_start = chars.len();
+        chars.extend(original[start..end].iter().copied().nfc());
+        offsets.extend((0..chars.len() - composed_start).map(|idx| (start + idx).min(end - 1)));
+
+        start = end;
+    }
+
+    offsets.push(original.len());
+
+    (chars, offsets)
+}
+
+fn fold_quote(c: char) -> Option<char> {
+    match c {
+        '‘' | '’' | '‚' | '‛' | '＇' => Some('\''),
+        '“' | '”' | '„' | '‟' | '＂' => Some('"'),
+        _ => None,
+    }
+}
+
+fn is_dash(c: char) -> bool {
+    matches!(
+        c,
+        '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' | '﹘' | '﹣' | '－'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Normalizations, Normalizer};
+    use crate::linting::{Lint, LintKind};
+    use crate::Span;
+
+    fn normalize(normalizer: Normalizer, text: &str) -> (String, Normalizations) {
+        let (chars, ran) = normalizer.normalize(text);
+        (chars.into_iter().collect(), ran)
+    }
+
+    #[test]
+    fn composes_combining_marks_by_default() {
+        let (text, ran) = normalize(Normalizer::default(), "cafe\u{301}");
+
+        assert_eq!(text, "café");
+        assert_eq!(text.chars().count(), 4);
+        assert!(ran.nfc && !ran.quotes && !ran.dashes);
+    }
+
+    #[test]
+    fn maps_offsets_across_composed_chars() {
+        let (_, ran) = Normalizer::default().normalize("cafe\u{301} tpyo");
+
+        assert_eq!(ran.original_span(Span::new(0, 4)), Span::new(0, 5));
+        assert_eq!(ran.original_span(Span::new(5, 9)), Span::new(6, 10));
+        assert_eq!(ran.normalized_span(Span::new(6, 10)), Span::new(5, 9));
+        assert_eq!(ran.normalized_offset(4), 4);
+        assert_eq!(ran.original_offset(12), 13);
+        assert_eq!(ran.normalized_offset(13), 12);
+    }
+
+    #[test]
+    fn maps_offsets_in_runs_of_non_ascii_text() {
+        let (text, ran) = normalize(Normalizer::default(), "ἀ\u{301}λλὰ ἐ\u{301}");
+
+        assert_eq!(text, "ἄλλὰ ἔ");
+        assert_eq!(ran.original_span(Span::new(0, 4)), Span::new(0, 5));
+        assert_eq!(ran.original_span(Span::new(5, 6)), Span::new(6, 8));
+    }
+
+    #[test]
+    fn restores_lint_spans() {
+        let (_, ran) = Normalizer::default().normalize("cafe\u{301} tpyo");
+        let mut lints = vec![Lint::new(Span::new(5, 9), LintKind::Spelling, Vec::new())];
+
+        ran.restore_spans(&mut lints);
+
+        assert_eq!(lints[0].span, Span::new(6, 10));
+    }
+
+    #[test]
+    fn replacing_text_moves_later_offsets() {
+        let (_, mut ran) = Normalizer::default().normalize("cafe\u{301} tpyo");
+
+        ran.replace(Span::new(0, 4), 2);
+
+        assert_eq!(ran.original_span(Span::new(0, 2)), Span::new(0, 2));
+        assert_eq!(ran.original_span(Span::new(3, 7)), Span::new(3, 7));
+    }
+
+    #[test]
+    fn offsets_are_unchanged_without_nfc() {
+        let (_, ran) = Normalizer::none().normalize("cafe\u{301} tpyo");
+
+        assert_eq!(ran.original_span(Span::new(6, 10)), Span::new(6, 10));
+        assert_eq!(ran.normalized_offset(20), 20);
+    }
+
+    #[test]
+    fn leaves_punctuation_by_default() {
+        let (text, ran) = normalize(Normalizer::default(), "don’t—ever");
+
+        assert_eq!(text, "don’t—ever");
+        assert!(ran.is_empty());
+    }
+
+    #[test]
+    fn folds_quotes_and_dashes() {
+        let normalizer = Normalizer::new()
+            .with_fold_quotes(true)
+            .with_fold_dashes(true);
+        let (text, ran) = normalize(normalizer, "“Don’t” – well‐known");
+
+        assert_eq!(text, "\"Don't\" - well-known");
+        assert!(ran.quotes && ran.dashes && !ran.nfc);
+    }
+
+    #[test]
+    fn none_changes_nothing() {
+        let (text, ran) = normalize(Normalizer::none(), "cafe\u{301} “ok”");
+
+        assert_eq!(text, "cafe\u{301} “ok”");
+        assert!(ran.is_empty());
+    }
+
+    #[test]
+    fn restores_curly_quotes() {
+        let ran = Normalizations {
+            quotes: true,
+            ..Default::default()
+        };
+        let restored: String = ran
+            .restore_style(&"\"I don't\" she said".chars().collect::<Vec<_>>())
+            .into_iter()
+            .collect();
+
+        assert_eq!(restored, "“I don’t” she said");
+    }
+
+    #[test]
+    fn restore_is_noop_without_folded_quotes() {
+        let replacement: Vec<char> = "don't".chars().collect();
+
+        assert_eq!(
+            Normalizations::default().restore_style(&replacement),
+            replacement
+        );
+    }
+}
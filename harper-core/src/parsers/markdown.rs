@@ -25,11 +25,7 @@ impl Markdown {
             let mut cursor = pipe_idx - 2;
             let mut open_bracket = None;
 
-            loop {
-                let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) else {
-                    break;
-                };
-
+            while let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) {
                 if a.kind.is_newline() {
                     break;
                 }
@@ -48,11 +44,7 @@ impl Markdown {
             cursor = pipe_idx + 1;
             let mut close_bracket = None;
 
-            loop {
-                let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) else {
-                    break;
-                };
-
+            while let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) {
                 if a.kind.is_newline() {
                     break;
                 }
@@ -85,11 +77,7 @@ impl Markdown {
 
         let mut cursor = 0;
 
-        loop {
-            let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) else {
-                break;
-            };
-
+        while let Some((a, b)) = tokens.get(cursor).zip(tokens.get(cursor + 1)) {
             if let Some(open_brackets_idx) = open_brackets {
                 if a.kind.is_newline() {
                     open_brackets = None;
@@ -115,6 +103,60 @@ impl Markdown {
 
         tokens.remove_indices(to_remove);
     }
+
+    /// Find the headings of a CommonMark document, including their markers
+    /// (like `#`), as char spans of `source`.
+    pub fn heading_spans(source: &[char]) -> Vec<Span> {
+        Self::tag_spans(source, |tag| {
+            matches!(tag, pulldown_cmark::Tag::Heading { .. })
+        })
+    }
+
+    /// Find the list items of a CommonMark document, including their markers
+    /// (like `-` or `1.`), as char spans of `source`.
+    ///
+    /// Nested items are covered by the items that contain them, as well as
+    /// having spans of their own.
+    pub fn list_item_spans(source: &[char]) -> Vec<Span> {
+        Self::tag_spans(source, |tag| matches!(tag, pulldown_cmark::Tag::Item))
+    }
+
+    /// Find the tables of a CommonMark document as char spans of `source`.
+    pub fn table_spans(source: &[char]) -> Vec<Span> {
+        Self::tag_spans(source, |tag| matches!(tag, pulldown_cmark::Tag::Table(_)))
+    }
+
+    /// The char spans of every element of `source` whose tag matches
+    /// `predicate`.
+    fn tag_spans(source: &[char], predicate: impl Fn(&pulldown_cmark::Tag) -> bool) -> Vec<Span> {
+        let source_str: String = source.iter().collect();
+        let md_parser = pulldown_cmark::Parser::new_ext(
+            &source_str,
+            pulldown_cmark::Options::all()
+                .difference(pulldown_cmark::Options::ENABLE_SMART_PUNCTUATION),
+        );
+
+        let mut spans = Vec::new();
+
+        let mut traversed_bytes = 0;
+        let mut traversed_chars = 0;
+
+        for (event, range) in md_parser.into_offset_iter() {
+            if let pulldown_cmark::Event::Start(tag) = event {
+                if !predicate(&tag) {
+                    continue;
+                }
+
+                traversed_chars += source_str[traversed_bytes..range.start].chars().count();
+                traversed_bytes = range.start;
+
+                let len = source_str[range].chars().count();
+                spans.push(Span::new_with_len(traversed_chars, len));
+            }
+        }
+
+        spans
+    }
 }
 
 impl Parser for Markdown {
@@ -179,10 +221,12 @@ impl Parser for Markdown {
                 pulldown_cmark::Event::End(_) => {
                     stack.pop();
                 }
-                pulldown_cmark::Event::InlineMath(code)
-                | pulldown_cmark::Event::DisplayMath(code)
-                | pulldown_cmark::Event::Code(code) => {
-                    let chunk_len = code.chars().count();
+                pulldown_cmark::Event::InlineMath(_)
+                | pulldown_cmark::Event::DisplayMath(_)
+                | pulldown_cmark::Event::Code(_) => {
+                    // The event only holds the contents, but the delimiters
+                    // (like backticks) should be covered too.
+                    let chunk_len = source_str[range].chars().count();
 
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, chunk_len),
@@ -226,9 +270,8 @@ impl Parser for Markdown {
                     tokens.append(&mut new_tokens);
                 }
                 // TODO: Support via `harper-html`
-                pulldown_cmark::Event::Html(_content)
-                | pulldown_cmark::Event::InlineHtml(_content) => {
-                    let size = _content.chars().count();
+                pulldown_cmark::Event::Html(_) | pulldown_cmark::Event::InlineHtml(_) => {
+                    let size = source_str[range].chars().count();
                     tokens.push(Token {
                         span: Span::new_with_len(traversed_chars, size),
                         kind: TokenKind::Unlintable,
@@ -262,6 +305,30 @@ mod tests {
     use super::Markdown;
     use crate::{Punctuation, TokenKind, TokenStringExt};
 
+    #[test]
+    fn finds_heading_spans() {
+        let source: Vec<char> = "# Café\n\nSome text.\n\nSub\n---\n".chars().collect();
+
+        let headings: Vec<String> = Markdown::heading_spans(&source)
+            .into_iter()
+            .map(|span| span.get_content(&source).iter().collect())
+            .collect();
+
+        assert_eq!(headings, vec!["# Café\n", "Sub\n---\n"]);
+    }
+
+    #[test]
+    fn finds_list_item_spans() {
+        let source: Vec<char> = "Intro:\n\n- One\n- Two\n\n1. Three\n".chars().collect();
+
+        let items: Vec<String> = Markdown::list_item_spans(&source)
+            .into_iter()
+            .map(|span| span.get_content(&source).iter().collect())
+            .collect();
+
+        assert_eq!(items, vec!["- One\n", "- Two\n\n", "1. Three\n"]);
+    }
+
     #[test]
     fn survives_emojis() {
         let source = r#"🤷."#;
@@ -363,6 +430,52 @@ mod tests {
         assert!(matches!(token_kinds.as_slice(), &[TokenKind::Word(_)]))
     }
 
+    #[track_caller]
+    fn assert_words(source: &str, expected: &[&str]) {
+        let chars: Vec<char> = source.chars().collect();
+        let tokens = Markdown.parse_str(source);
+
+        let words: Vec<String> = tokens
+            .iter_words()
+            .map(|t| t.span.get_content(&chars).iter().collect())
+            .collect();
+
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn inline_code_is_fully_unlintable() {
+        let source = "Run `cdoe here` now.";
+        let chars: Vec<char> = source.chars().collect();
+
+        let tokens = Markdown.parse_str(source);
+        let unlintable = tokens.iter_unlintables().next().unwrap();
+
+        assert_eq!(unlintable.span.get_content(&chars), &chars[4..15]);
+        assert_words(source, &["Run", "now"]);
+    }
+
+    #[test]
+    fn skips_fenced_code_blocks() {
+        assert_words(
+            "Before.\n\n```rust\nfenced cdoe\n```\n\nAfter.",
+            &["Before", "After"],
+        );
+    }
+
+    #[test]
+    fn checks_link_text_but_not_targets() {
+        assert_words(
+            "See [the dcos](https://exmaple.com/pth) and **bld** text.",
+            &["See", "the", "dcos", "and", "bld", "text"],
+        );
+    }
+
+    #[test]
+    fn spans_survive_multibyte_characters() {
+        assert_words("Café `x` wrld.", &["Café", "wrld"]);
+    }
+
     #[test]
     fn html_is_unlintable() {
         let source = r#"The range of inputs from <ctrl-g> to ctrl-z"#;
@@ -96,7 +96,9 @@ impl Punctuation {
             ':' => Punctuation::Colon,
             ';' => Punctuation::Semicolon,
             ',' => Punctuation::Comma,
-            '-' => Punctuation::Hyphen,
+            // The ASCII hyphen-minus, along with the Unicode hyphen and
+            // non-breaking hyphen.
+            '-' | '‐' | '‑' => Punctuation::Hyphen,
             '[' => Punctuation::OpenSquare,
             ']' => Punctuation::CloseSquare,
             '{' => Punctuation::OpenCurly,
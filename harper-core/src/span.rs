@@ -3,7 +3,7 @@ use std::ops::Range;
 use serde::{Deserialize, Serialize};
 
 /// A window in a [`char`] sequence.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -38,10 +38,24 @@ impl Span {
         self.start <= idx && idx < self.end
     }
 
+    /// Whether `other` lies entirely within this span.
+    /// Every span contains itself, and an empty span at either edge.
+    pub fn contains_span(&self, other: Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether the two spans share at least one [`char`].
+    /// Spans that merely touch, like `0..3` and `3..5`, don't overlap.
     pub fn overlaps_with(&self, other: Self) -> bool {
         (self.start < other.end) && (other.start < self.end)
     }
 
+    /// The smallest span that covers both spans, including any gap between
+    /// them.
+    pub fn merge(&self, other: Self) -> Self {
+        Self::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
     /// Get the associated content. Will return [`None`] if any aspect is
     /// invalid.
     pub fn try_get_content<'a>(&self, source: &'a [char]) -> Option<&'a [char]> {
@@ -144,5 +158,30 @@ mod tests {
         assert!(Span::new(0, 5).overlaps_with(Span::new(4, 4)));
 
         assert!(!Span::new(0, 3).overlaps_with(Span::new(3, 5)));
+        assert!(!Span::new(3, 5).overlaps_with(Span::new(0, 3)));
+    }
+
+    #[test]
+    fn contains_span() {
+        assert!(Span::new(0, 5).contains_span(Span::new(1, 4)));
+        assert!(Span::new(0, 5).contains_span(Span::new(0, 5)));
+        assert!(Span::new(0, 5).contains_span(Span::new(5, 5)));
+
+        assert!(!Span::new(0, 5).contains_span(Span::new(3, 6)));
+        assert!(!Span::new(0, 3).contains_span(Span::new(3, 5)));
+    }
+
+    #[test]
+    fn merge() {
+        assert_eq!(Span::new(0, 3).merge(Span::new(2, 6)), Span::new(0, 6));
+        assert_eq!(Span::new(3, 5).merge(Span::new(0, 3)), Span::new(0, 5));
+        assert_eq!(Span::new(0, 2).merge(Span::new(4, 6)), Span::new(0, 6));
+        assert_eq!(Span::new(1, 5).merge(Span::new(2, 3)), Span::new(1, 5));
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(Span::new(2, 7).len(), 5);
+        assert!(Span::new(3, 3).is_empty());
     }
 }
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use hashbrown::{HashMap, HashSet};
+use lazy_static::lazy_static;
+
+use crate::{CharString, CharStringExt};
+
+lazy_static! {
+    static ref CURATED: Arc<BigramModel> =
+        Arc::new(BigramModel::parse(include_str!("../../bigrams.tsv")));
+}
+
+/// How often pairs of words appear next to each other, used to rank spelling
+/// suggestions by how well they fit the previous word.
+///
+/// Words are compared case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BigramModel {
+    /// Maps a word to the words that follow it, and how often they do.
+    followers: HashMap<CharString, HashMap<CharString, u32>>,
+    /// The total count of all followers of each word.
+    totals: HashMap<CharString, u32>,
+    /// How many distinct words are ever seen following another.
+    vocabulary: HashSet<CharString>,
+}
+
+impl BigramModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The small table of common English word pairs included in the Harper
+    /// binary.
+    pub fn curated() -> Arc<Self> {
+        CURATED.clone()
+    }
+
+    /// Parse a table of bigram counts, one `previous<TAB>word<TAB>count`
+    /// triple per line.
+    ///
+    /// Blank lines and lines that fail to parse are skipped.
+    pub fn parse(source: &str) -> Self {
+        let mut model = Self::new();
+
+        for line in source.lines() {
+            let mut fields = line.split('\t');
+
+            let (Some(prev), Some(word), Some(count)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+
+            let Ok(count) = count.trim().parse() else {
+                continue;
+            };
+
+            let prev: CharString = prev.chars().collect();
+            let word: CharString = word.chars().collect();
+            model.add(&prev, &word, count);
+        }
+
+        model
+    }
+
+    /// Record that `word` followed `prev` another `count` times.
+    pub fn add(&mut self, prev: &[char], word: &[char], count: u32) {
+        let prev = prev.to_lower();
+        let word = word.to_lower();
+
+        *self.totals.entry(prev.clone()).or_default() += count;
+        *self
+            .followers
+            .entry(prev)
+            .or_default()
+            .entry(word.clone())
+            .or_default() += count;
+        self.vocabulary.insert(word);
+    }
+
+    /// How many times `word` was recorded right after `prev`.
+    pub fn count(&self, prev: &[char], word: &[char]) -> u32 {
+        self.followers
+            .get(&prev.to_lower())
+            .and_then(|followers| followers.get(&word.to_lower()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// The estimated probability that `word` comes right after `prev`.
+    ///
+    /// Counts are smoothed (add-one), so unseen pairs get a small, non-zero
+    /// probability, and every word is equally likely after a `prev` the
+    /// model knows nothing about.
+    pub fn probability(&self, prev: &[char], word: &[char]) -> f32 {
+        let count = self.count(prev, word);
+        let total = self.totals.get(&prev.to_lower()).copied().unwrap_or(0);
+
+        (count as f32 + 1.0) / (total as f32 + self.vocabulary.len() as f32 + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BigramModel;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn parses_simple_table() {
+        let model = BigramModel::parse("came\tfrom\t3\n\nbroken line\nthe\tform\tx\n");
+
+        assert!(
+            model.probability(&chars("came"), &chars("from"))
+                > model.probability(&chars("came"), &chars("form"))
+        );
+        assert_eq!(
+            model.probability(&chars("the"), &chars("form")),
+            model.probability(&chars("the"), &chars("from"))
+        );
+    }
+
+    #[test]
+    fn is_case_insensitive() {
+        let model = BigramModel::parse("came\tfrom\t3\n");
+
+        assert_eq!(
+            model.probability(&chars("Came"), &chars("From")),
+            model.probability(&chars("came"), &chars("from"))
+        );
+    }
+
+    #[test]
+    fn counts_pairs() {
+        let model = BigramModel::parse("set\tup\t3\nset\tup\t2\n");
+
+        assert_eq!(model.count(&chars("Set"), &chars("up")), 5);
+        assert_eq!(model.count(&chars("set"), &chars("down")), 0);
+    }
+
+    #[test]
+    fn unseen_pairs_are_not_impossible() {
+        let model = BigramModel::curated();
+
+        assert!(model.probability(&chars("zebra"), &chars("quantum")) > 0.0);
+    }
+
+    #[test]
+    fn curated_knows_common_pairs() {
+        let model = BigramModel::curated();
+
+        assert!(
+            model.probability(&chars("came"), &chars("from"))
+                > model.probability(&chars("came"), &chars("form"))
+        );
+    }
+}
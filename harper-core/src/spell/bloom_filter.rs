@@ -0,0 +1,116 @@
+/// A probabilistic set of words that can say for certain that a word is
+/// _not_ present, without the cost of a full lookup.
+///
+/// Words that were inserted always test positive. Words that weren't usually
+/// test negative, but may occasionally test positive too.
+///
+/// This is a blocked filter: all of a word's bits live in the same `u64`, so
+/// each check is a single memory access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct BloomFilter {
+    /// Always a power of two in length, so blocks can be picked with a mask.
+    blocks: Vec<u64>,
+}
+
+impl BloomFilter {
+    /// How many bits to set per word.
+    const NUM_HASHES: u32 = 6;
+    /// Gives a false-positive rate of roughly 1% when full.
+    const BITS_PER_WORD: usize = 16;
+
+    /// Create an empty filter sized for `capacity` words.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let num_blocks = (capacity * Self::BITS_PER_WORD)
+            .div_ceil(64)
+            .next_power_of_two();
+
+        Self {
+            blocks: vec![0; num_blocks],
+        }
+    }
+
+    pub fn from_words(words: impl ExactSizeIterator<Item = impl AsRef<[char]>>) -> Self {
+        let mut filter = Self::with_capacity(words.len());
+
+        for word in words {
+            filter.insert(word.as_ref());
+        }
+
+        filter
+    }
+
+    /// The number of bytes used to store the filter's bits.
+    pub fn memory_footprint(&self) -> usize {
+        self.blocks.capacity() * size_of::<u64>()
+    }
+
+    pub fn insert(&mut self, word: &[char]) {
+        let (block, mask) = self.block_and_mask(word);
+        self.blocks[block] |= mask;
+    }
+
+    /// Returns `false` only if `word` was definitely never inserted.
+    pub fn may_contain(&self, word: &[char]) -> bool {
+        let (block, mask) = self.block_and_mask(word);
+        self.blocks[block] & mask == mask
+    }
+
+    /// Pick a block with the low bits of the word's hash, and which bits to
+    /// set within it with the high bits.
+    fn block_and_mask(&self, word: &[char]) -> (usize, u64) {
+        let hash = fnv1a(word);
+        let block = (hash as usize) & (self.blocks.len() - 1);
+
+        let mut bits = hash.rotate_left(32).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let mut mask = 0;
+
+        for _ in 0..Self::NUM_HASHES {
+            mask |= 1 << (bits & 63);
+            bits >>= 6;
+        }
+
+        (block, mask)
+    }
+}
+
+fn fnv1a(word: &[char]) -> u64 {
+    word.iter().fold(0xCBF2_9CE4_8422_2325, |hash, c| {
+        (hash ^ *c as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BloomFilter;
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn has_no_false_negatives() {
+        let words: Vec<Vec<char>> = (0..10_000).map(|i| chars(&format!("word{i}"))).collect();
+        let filter = BloomFilter::from_words(words.iter());
+
+        assert!(words.iter().all(|word| filter.may_contain(word)));
+    }
+
+    #[test]
+    fn rejects_most_missing_words() {
+        let words: Vec<Vec<char>> = (0..10_000).map(|i| chars(&format!("word{i}"))).collect();
+        let filter = BloomFilter::from_words(words.iter());
+
+        let false_positives = (0..10_000)
+            .filter(|i| filter.may_contain(&chars(&format!("missing{i}"))))
+            .count();
+
+        assert!(false_positives < 300, "{false_positives} false positives");
+    }
+
+    #[test]
+    fn empty_filter_contains_nothing() {
+        let filter = BloomFilter::with_capacity(0);
+
+        assert!(!filter.may_contain(&chars("anything")));
+    }
+}
@@ -0,0 +1,268 @@
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+
+use lru::LruCache;
+
+use super::{Dictionary, FstDictionary, FuzzyMatchResult};
+use crate::{CharString, WordMetadata};
+
+/// `(word, max_distance, max_results)`
+type CacheKey = (CharString, u8, usize);
+
+/// The positions of each match in the inner [`FstDictionary`], along with
+/// their edit distances.
+type CachedMatches = Arc<[(usize, u8)]>;
+
+/// Wraps an [`FstDictionary`], remembering the results of recent
+/// [`Dictionary::fuzzy_match`] queries.
+///
+/// In an editor, the same misspelling is usually checked again on every
+/// keystroke, so this avoids repeating the most expensive lookup.
+/// The least recently used results are forgotten once the cache is full.
+/// Since the wrapped dictionary can't change, the cache never needs to be
+/// invalidated.
+///
+/// The cache is behind a lock, so a single [`CachedDictionary`] can be shared
+/// between threads (e.g. in an [`Arc`]).
+pub struct CachedDictionary {
+    inner: Arc<FstDictionary>,
+    cache: Mutex<LruCache<CacheKey, CachedMatches>>,
+}
+
+impl CachedDictionary {
+    /// How many queries are remembered by default.
+    pub const DEFAULT_CAPACITY: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
+
+    pub fn new(inner: Arc<FstDictionary>) -> Self {
+        Self::with_capacity(inner, Self::DEFAULT_CAPACITY)
+    }
+
+    /// Remember the results of up to `capacity` distinct queries.
+    pub fn with_capacity(inner: Arc<FstDictionary>, capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Create a cached copy of the curated dictionary.
+    pub fn curated() -> Self {
+        Self::new(FstDictionary::curated())
+    }
+
+    /// The number of queries currently remembered.
+    pub fn cache_len(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    fn to_results(&self, matches: &[(usize, u8)]) -> Vec<FuzzyMatchResult<'_>> {
+        matches
+            .iter()
+            .map(|&(index, edit_distance)| {
+                let (word, metadata) = self.inner.word_at(index);
+
+                FuzzyMatchResult {
+                    word,
+                    edit_distance,
+                    metadata,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Dictionary for CachedDictionary {
+    fn contains_word(&self, word: &[char]) -> bool {
+        self.inner.contains_word(word)
+    }
+
+    fn contains_word_str(&self, word: &str) -> bool {
+        self.inner.contains_word_str(word)
+    }
+
+    fn contains_words(&self, words: &[&[char]]) -> Vec<bool> {
+        self.inner.contains_words(words)
+    }
+
+    fn contains_word_case_sensitive(&self, word: &[char]) -> bool {
+        self.inner.contains_word_case_sensitive(word)
+    }
+
+    fn contains_word_ignore_case(&self, word: &[char]) -> bool {
+        self.inner.contains_word_ignore_case(word)
+    }
+
+    fn contains_word_fold_diacritics(&self, word: &[char]) -> bool {
+        self.inner.contains_word_fold_diacritics(word)
+    }
+
+    fn get_word_metadata(&self, word: &[char]) -> WordMetadata {
+        self.inner.get_word_metadata(word)
+    }
+
+    fn get_word_metadata_str(&self, word: &str) -> WordMetadata {
+        self.inner.get_word_metadata_str(word)
+    }
+
+    fn word_frequency(&self, word: &[char]) -> Option<u32> {
+        self.inner.word_frequency(word)
+    }
+
+    fn fuzzy_match(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let key = (CharString::from(word), max_distance, max_results);
+
+        if let Some(matches) = self.cache.lock().unwrap().get(&key).cloned() {
+            return self.to_results(&matches);
+        }
+
+        // Computed without holding the lock, so other threads aren't blocked
+        // by a slow query.
+        let results = self.inner.fuzzy_match(word, max_distance, max_results);
+
+        let matches: Option<CachedMatches> = results
+            .iter()
+            .map(|result| {
+                self.inner
+                    .word_index(result.word)
+                    .map(|index| (index, result.edit_distance))
+            })
+            .collect();
+
+        if let Some(matches) = matches {
+            self.cache.lock().unwrap().put(key, matches);
+        }
+
+        results
+    }
+
+    fn fuzzy_match_with_casing_variants(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        self.inner
+            .fuzzy_match_with_casing_variants(word, max_distance, max_results)
+    }
+
+    fn fuzzy_match_iter(
+        &self,
+        word: &[char],
+        max_distance: u8,
+    ) -> Box<dyn Iterator<Item = (&'_ [char], u8, WordMetadata)> + Send + '_> {
+        self.inner.fuzzy_match_iter(word, max_distance)
+    }
+
+    fn fuzzy_match_str(
+        &self,
+        word: &str,
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let word: CharString = word.chars().collect();
+        self.fuzzy_match(&word, max_distance, max_results)
+    }
+
+    fn phonetic_match(&self, word: &[char], max_results: usize) -> Vec<&'_ [char]> {
+        self.inner.phonetic_match(word, max_results)
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
+        self.inner.words_iter()
+    }
+
+    fn words_matching<'a>(
+        &'a self,
+        predicate: &'a (dyn Fn(&WordMetadata) -> bool + Sync),
+    ) -> Box<dyn Iterator<Item = &'a [char]> + Send + 'a> {
+        self.inner.words_matching(predicate)
+    }
+
+    fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
+        self.inner.words_with_len_iter(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::CachedDictionary;
+    use crate::{Dictionary, FstDictionary};
+
+    fn words(dict: &impl Dictionary, word: &str) -> Vec<String> {
+        dict.fuzzy_match_str(word, 2, 10)
+            .into_iter()
+            .map(|result| result.word.iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn agrees_with_inner_dictionary() {
+        let inner = FstDictionary::curated();
+        let cached = CachedDictionary::new(inner.clone());
+
+        for word in ["hvllo", "teh", "Recieve", "hvllo"] {
+            assert_eq!(words(&cached, word), words(&inner, word));
+        }
+    }
+
+    #[test]
+    fn remembers_queries() {
+        let cached = CachedDictionary::curated();
+
+        words(&cached, "hvllo");
+        words(&cached, "hvllo");
+        assert_eq!(cached.cache_len(), 1);
+
+        cached.fuzzy_match_str("hvllo", 1, 10);
+        assert_eq!(cached.cache_len(), 2);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cached = CachedDictionary::with_capacity(
+            FstDictionary::curated(),
+            NonZeroUsize::new(2).unwrap(),
+        );
+
+        let first = words(&cached, "hvllo");
+        words(&cached, "teh");
+        words(&cached, "wrld");
+
+        assert_eq!(cached.cache_len(), 2);
+        assert_eq!(words(&cached, "hvllo"), first);
+    }
+
+    #[test]
+    fn can_be_shared_between_threads() {
+        let cached = Arc::new(CachedDictionary::curated());
+        let expected = words(&FstDictionary::curated(), "hvllo");
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let cached = cached.clone();
+                thread::spawn(move || words(&cached, "hvllo"))
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    }
+}
@@ -1,28 +1,330 @@
 use blanket::blanket;
+use hashbrown::HashSet;
 
-use super::FuzzyMatchResult;
-use crate::WordMetadata;
+use super::diacritics::fold_diacritics;
+use super::metaphone::metaphone;
+use super::stemmer::{base_form_candidates, porter_stem};
+use super::{edit_distance, seq_to_normalized, FuzzyMatchResult, KeyboardLayout};
+use crate::{CharStringExt, WordMetadata};
 
-#[blanket(derive(Arc))]
+/// A set of words, along with their metadata.
+///
+/// The trait is object safe, so it can be used as a `dyn Dictionary` to
+/// pick an implementation at runtime.
+/// Keep it that way: methods that need generics should take trait objects
+/// instead, as [`Self::words_matching`] does.
+#[blanket(derive(Arc, Ref))]
 pub trait Dictionary: Send + Sync {
     /// Check if the dictionary contains a given word.
     fn contains_word(&self, word: &[char]) -> bool;
     /// Check if the dictionary contains a given word.
     fn contains_word_str(&self, word: &str) -> bool;
+    /// Check if the dictionary contains each of the given words.
+    ///
+    /// The result has one entry per word, in the same order, each matching
+    /// what [`Self::contains_word`] would return.
+    /// This is useful for quickly validating a word list before deciding which
+    /// words need fuzzy suggestions.
+    ///
+    /// The default implementation calls [`Self::contains_word`] for each word.
+    fn contains_words(&self, words: &[&[char]]) -> Vec<bool> {
+        words.iter().map(|word| self.contains_word(word)).collect()
+    }
+    /// Check if the dictionary contains a given word with the exact same
+    /// casing.
+    ///
+    /// Unlike [`Self::contains_word`], this will not fall back to a lowercase
+    /// lookup, so "Polish" and "polish" are treated as distinct words.
+    /// The word is still normalized (e.g. curly apostrophes become straight
+    /// ones) before comparison, but normalization never changes case.
+    ///
+    /// The default implementation scans every word of the same length.
+    fn contains_word_case_sensitive(&self, word: &[char]) -> bool {
+        let normalized = seq_to_normalized(word);
+
+        self.words_with_len_iter(normalized.len())
+            .any(|w| w == normalized.as_ref())
+    }
+    /// Check if the dictionary contains a given word, ignoring case entirely.
+    ///
+    /// Unlike [`Self::contains_word`], which only falls back to the lowercase
+    /// form of `word`, this also finds dictionary words with capitals, so
+    /// "iphone" matches "iPhone".
+    ///
+    /// The default implementation checks the word as-is and lowercased, and
+    /// then scans every word of the same length.
+    fn contains_word_ignore_case(&self, word: &[char]) -> bool {
+        if self.contains_word(word) {
+            return true;
+        }
+
+        let lowercase = seq_to_normalized(word).to_lower();
+
+        self.words_with_len_iter(lowercase.len())
+            .any(|w| w.to_lower() == lowercase)
+    }
+    /// Check if the dictionary contains a given word, ignoring diacritics, so
+    /// "resume" matches "résumé" and vice versa.
+    ///
+    /// This is opt-in: [`Self::contains_word`] still treats accented letters
+    /// as distinct, since in some languages they tell words apart.
+    /// Casing is handled the same way as in [`Self::contains_word`].
+    ///
+    /// The default implementation checks the word as-is, and then scans every
+    /// word of the same length.
+    fn contains_word_fold_diacritics(&self, word: &[char]) -> bool {
+        if self.contains_word(word) {
+            return true;
+        }
+
+        let folded = fold_diacritics(&seq_to_normalized(word)).into_owned();
+        let folded_lower = folded.to_lower();
+
+        self.words_with_len_iter(folded.len()).any(|w| {
+            let w = fold_diacritics(w);
+            w.as_ref() == folded.as_slice() || w.as_ref() == folded_lower.as_ref()
+        })
+    }
+    /// Check if the dictionary contains a word, or every part of it when it
+    /// is split on hyphens, so "well-known" is found if "well" and "known"
+    /// are.
+    ///
+    /// Words the dictionary contains with their hyphens, like "e-mail", are
+    /// found as-is. Empty parts, as in "well--known" or "-known", are never
+    /// found.
+    fn contains_hyphenated(&self, word: &[char]) -> bool {
+        if self.contains_word(word) {
+            return true;
+        }
+
+        let is_hyphen = |c: &char| matches!(c, '-' | '‐' | '‑');
+
+        word.iter().any(is_hyphen)
+            && word
+                .split(is_hyphen)
+                .all(|part| !part.is_empty() && self.contains_word(part))
+    }
     /// Gets best fuzzy match from dictionary
+    ///
+    /// Words that only differ by casing are collapsed into the best-ranked
+    /// one, so "The" and "the" won't both appear.
+    /// Use [`Self::fuzzy_match_with_casing_variants`] to keep them.
     fn fuzzy_match(
         &self,
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult>;
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        fn collapse_casing_variants(matches: &mut Vec<FuzzyMatchResult<'_>>) {
+            let mut seen = HashSet::new();
+            matches.retain(|m| seen.insert(m.word.to_lower()));
+        }
+
+        let mut matches = self.fuzzy_match_with_casing_variants(word, max_distance, max_results);
+        let found_all = matches.len() < max_results;
+        collapse_casing_variants(&mut matches);
+
+        // Collapsing left us short, so the remaining results are further down
+        // the list.
+        if !found_all && matches.len() < max_results {
+            matches = self.fuzzy_match_with_casing_variants(word, max_distance, usize::MAX);
+            collapse_casing_variants(&mut matches);
+            matches.truncate(max_results);
+        }
+
+        matches
+    }
+    /// Gets best fuzzy match from dictionary, keeping every casing variant of
+    /// a word (e.g. both "Polish" and "polish").
+    fn fuzzy_match_with_casing_variants(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>>;
+    /// Gets best fuzzy match from dictionary, along with a confidence score
+    /// between `0.0` and `1.0` that UIs can use to threshold suggestions.
+    ///
+    /// The confidence is `1 - edit_distance / word_len`, where `word_len` is
+    /// the length of `word`, clamped so it never goes below zero.
+    /// Results are in the same order as [`Self::fuzzy_match`].
+    fn fuzzy_match_scored(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<(&'_ [char], f32, WordMetadata)> {
+        self.fuzzy_match(word, max_distance, max_results)
+            .into_iter()
+            .map(|m| (m.word, m.confidence(word.len()), m.metadata))
+            .collect()
+    }
+    /// Lazily iterate over the words within `max_distance` of `word`, along
+    /// with their edit distance and metadata.
+    ///
+    /// Unlike [`Self::fuzzy_match`], results are not ranked, so callers can
+    /// stop as soon as they find an acceptable match.
+    /// Each word is yielded at most once.
+    ///
+    /// The default implementation is not lazy and collects every match up
+    /// front.
+    fn fuzzy_match_iter(
+        &self,
+        word: &[char],
+        max_distance: u8,
+    ) -> Box<dyn Iterator<Item = (&'_ [char], u8, WordMetadata)> + Send + '_> {
+        Box::new(
+            self.fuzzy_match_with_casing_variants(word, max_distance, usize::MAX)
+                .into_iter()
+                .map(|m| (m.word, m.edit_distance, m.metadata)),
+        )
+    }
+    /// Gets best fuzzy match from dictionary, ranking candidates by how close
+    /// the mistyped keys are on the provided [`KeyboardLayout`].
+    ///
+    /// Candidates are found the same way as [`Self::fuzzy_match`], then
+    /// re-scored and re-sorted by their
+    /// [weighted distance](KeyboardLayout::weighted_edit_distance).
+    fn fuzzy_match_keyboard(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+        layout: KeyboardLayout,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let mut matches = self.fuzzy_match(word, max_distance, usize::MAX);
+
+        // The sort is stable, so equally weighted matches keep their original
+        // order.
+        matches.sort_by_cached_key(|m| layout.weighted_edit_distance_key(word, m.word));
+        matches.truncate(max_results);
+
+        matches
+    }
     /// Gets best fuzzy match from dictionary
     fn fuzzy_match_str(
         &self,
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult>;
+    ) -> Vec<FuzzyMatchResult<'_>>;
+    /// Find words that sound like the provided one, even if they are spelled
+    /// very differently (e.g. "fone" and "phone").
+    ///
+    /// Words are compared by their Metaphone code.
+    /// Ranking is left up to the implementation.
+    ///
+    /// The default implementation computes the code of every word in the
+    /// dictionary and orders matches by edit distance to `word`.
+    fn phonetic_match(&self, word: &[char], max_results: usize) -> Vec<&'_ [char]> {
+        let code = metaphone(word);
+        let lowercase = word.to_lower();
+
+        let mut found: Vec<&[char]> = self.words_iter().filter(|w| metaphone(w) == code).collect();
+
+        found.sort_by_cached_key(|w| edit_distance(&lowercase, &w.to_lower()));
+        found.truncate(max_results);
+
+        found
+    }
+    /// Reduce a word to its stem, so related words like "running" and "run"
+    /// can be compared.
+    ///
+    /// Stems are produced by the Porter stemming algorithm, which doesn't
+    /// consult the dictionary and doesn't always produce a real word (e.g.
+    /// "happiness" becomes "happi").
+    /// Returns [`None`] for words that aren't made up solely of ASCII letters.
+    /// See [`Self::lemma`] for the dictionary form of a word.
+    fn stem(&self, word: &[char]) -> Option<Vec<char>> {
+        porter_stem(word)
+    }
+    /// Find the dictionary base form of a word, so "running" becomes "run"
+    /// and "happier" becomes "happy".
+    ///
+    /// Common inflections are undone, and a candidate is only accepted if the
+    /// dictionary has it with a fitting part of speech (e.g. "-er" must come
+    /// from an adjective).
+    /// If no candidate fits, a word that is itself in the dictionary is its
+    /// own lemma, and anything else returns [`None`].
+    fn lemma(&self, word: &[char]) -> Option<Vec<char>> {
+        base_form_candidates(word)
+            .find(|(base, parts_of_speech)| {
+                self.contains_word(base)
+                    && self
+                        .get_word_metadata(base)
+                        .parts_of_speech()
+                        .any(|pos| parts_of_speech.contains(&pos))
+            })
+            .map(|(base, _)| base)
+            .or_else(|| self.contains_word(word).then(|| word.to_vec()))
+    }
+    /// Split an unknown word into a sequence of dictionary words, so
+    /// "grammarchecker" becomes "grammar" and "checker".
+    ///
+    /// Segments shorter than three characters are not allowed, to avoid
+    /// absurd decompositions.
+    /// See [`Self::decompose_compound_with_min_len`] for details.
+    fn decompose_compound(&self, word: &[char]) -> Option<Vec<Vec<char>>> {
+        self.decompose_compound_with_min_len(word, 3)
+    }
+    /// Split an unknown word into a sequence of dictionary words, each at
+    /// least `min_segment_len` characters long.
+    ///
+    /// When there are several ways to split the word, the one with the fewest
+    /// segments wins.
+    /// Returns [`None`] if the word is already in the dictionary or can't be
+    /// split into at least two dictionary words.
+    fn decompose_compound_with_min_len(
+        &self,
+        word: &[char],
+        min_segment_len: usize,
+    ) -> Option<Vec<Vec<char>>> {
+        if word.is_empty() || self.contains_word(word) {
+            return None;
+        }
+
+        let min_segment_len = min_segment_len.max(1);
+
+        // `best[i]` holds the fewest segments that cover `word[..i]`, and
+        // where the last of those segments starts.
+        let mut best: Vec<Option<(usize, usize)>> = vec![None; word.len() + 1];
+        best[0] = Some((0, 0));
+
+        for end in min_segment_len..=word.len() {
+            for start in 0..=end - min_segment_len {
+                let Some((segments, _)) = best[start] else {
+                    continue;
+                };
+
+                if best[end].is_some_and(|(best_segments, _)| best_segments <= segments + 1) {
+                    continue;
+                }
+
+                if self.contains_word(&word[start..end]) {
+                    best[end] = Some((segments + 1, start));
+                }
+            }
+        }
+
+        let (segments, _) = best[word.len()]?;
+
+        if segments < 2 {
+            return None;
+        }
+
+        let mut parts = Vec::with_capacity(segments);
+        let mut end = word.len();
+
+        while end > 0 {
+            let (_, start) = best[end]?;
+            parts.push(word[start..end].to_vec());
+            end = start;
+        }
+
+        parts.reverse();
+
+        Some(parts)
+    }
     /// Get the associated [`WordMetadata`] for a given word.
     /// If the word isn't in the dictionary, the resulting metadata will be
     /// empty.
@@ -31,10 +333,65 @@ pub trait Dictionary: Send + Sync {
     /// If the word isn't in the dictionary, the resulting metadata will be
     /// empty.
     fn get_word_metadata_str(&self, word: &str) -> WordMetadata;
+    /// Get how often a word occurs in English text, if known, for ranking
+    /// candidates.
+    ///
+    /// Frequencies in the curated dictionary are the approximate number of
+    /// occurrences per million words, so they can be compared across words
+    /// but aren't probabilities. Only common words have one; the rest are
+    /// [`None`], which is best treated as rarer than any known word.
+    ///
+    /// The default implementation has no frequency data and always returns
+    /// [`None`].
+    fn word_frequency(&self, word: &[char]) -> Option<u32> {
+        let _ = word;
+        None
+    }
+
+    /// The number of words in the dictionary.
+    ///
+    /// The default implementation counts the words from
+    /// [`Self::words_iter`], which takes time proportional to the size of the
+    /// dictionary.
+    fn len(&self) -> usize {
+        self.words_iter().count()
+    }
+
+    /// Whether the dictionary has no words at all.
+    fn is_empty(&self) -> bool {
+        self.words_iter().next().is_none()
+    }
 
     /// Iterate over the words in the dictionary.
     fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_>;
 
     /// Iterate over all the words in the dictionary of a given length
     fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_>;
+
+    /// Iterate over the words in the dictionary whose metadata satisfies
+    /// `predicate`.
+    ///
+    /// ```
+    /// use harper_core::{Dictionary, FullDictionary};
+    ///
+    /// let dict = FullDictionary::curated();
+    /// let mut adjectives = dict.words_matching(&|metadata| metadata.is_adjective());
+    ///
+    /// assert!(adjectives.any(|word| word == ['g', 'r', 'e', 'e', 'n']));
+    /// ```
+    ///
+    /// The predicate is a trait object, rather than a generic, so that this
+    /// can still be called on a `dyn Dictionary`.
+    ///
+    /// The default implementation looks up the metadata of each word from
+    /// [`Self::words_iter`].
+    fn words_matching<'a>(
+        &'a self,
+        predicate: &'a (dyn Fn(&WordMetadata) -> bool + Sync),
+    ) -> Box<dyn Iterator<Item = &'a [char]> + Send + 'a> {
+        Box::new(
+            self.words_iter()
+                .filter(move |word| predicate(&self.get_word_metadata(word))),
+        )
+    }
 }
@@ -0,0 +1,53 @@
+use std::fmt::Display;
+
+use crate::{CharString, CharStringExt, WordMetadata};
+
+/// How the words of a dictionary changed, as produced by
+/// [`FullDictionary::diff`](super::FullDictionary::diff).
+///
+/// Every list is sorted by word.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DictionaryDiff {
+    /// Words only in the new dictionary.
+    pub added: Vec<CharString>,
+    /// Words only in the old dictionary.
+    pub removed: Vec<CharString>,
+    /// Words in both dictionaries, with their old and new metadata, where the
+    /// metadata differs.
+    pub changed: Vec<(CharString, WordMetadata, WordMetadata)>,
+}
+
+impl DictionaryDiff {
+    /// Whether the dictionaries had the same words and metadata.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A summary line, followed by one line per word: `+` for added, `-` for
+/// removed and `~` for changed.
+impl Display for DictionaryDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        )?;
+
+        for word in &self.added {
+            writeln!(f, "+ {}", word.to_string())?;
+        }
+
+        for word in &self.removed {
+            writeln!(f, "- {}", word.to_string())?;
+        }
+
+        for (word, _, _) in &self.changed {
+            writeln!(f, "~ {}", word.to_string())?;
+        }
+
+        Ok(())
+    }
+}
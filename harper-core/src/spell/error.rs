@@ -0,0 +1,16 @@
+use super::Language;
+
+/// An error that can occur while building, loading or saving a dictionary.
+#[derive(Debug, thiserror::Error)]
+pub enum DictionaryError {
+    #[error("An I/O error occurred: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Unable to build or read the FST: {0}")]
+    Fst(#[from] fst::Error),
+    #[error("The FST contains {fst_len} entries, but the dictionary contains {dict_len} words.")]
+    LengthMismatch { fst_len: usize, dict_len: usize },
+    #[error("No dictionary is available for {0}.")]
+    UnsupportedLanguage(Language),
+    #[error("Entry {index} of the word list is empty.")]
+    EmptyWord { index: usize },
+}
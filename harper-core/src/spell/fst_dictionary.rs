@@ -1,12 +1,16 @@
 use super::{
-    hunspell::{parse_default_attribute_list, parse_default_word_list},
-    seq_to_normalized, FullDictionary,
+    char_to_normalized, edit_distance, edit_distance_min_alloc, full_dictionary::word_heap_size,
+    seq_to_normalized, DictionaryError, FullDictionary, Language,
 };
-use fst::{map::StreamWithState, IntoStreamer, Map as FstMap, Streamer};
-use hashbrown::HashMap;
+use fst::automaton::{Automaton, Str};
+use fst::{IntoStreamer, Map as FstMap, Streamer};
+use hashbrown::{HashMap, HashSet};
+use itertools::{EitherOrBoth, Itertools};
 use lazy_static::lazy_static;
 use levenshtein_automata::{LevenshteinAutomatonBuilder, DFA};
-use std::{cell::RefCell, sync::Arc};
+use smallvec::ToSmallVec;
+use std::sync::{Arc, Mutex};
+use std::{cell::RefCell, cmp::Reverse, fs, path::Path};
 
 use crate::{CharString, CharStringExt, WordMetadata};
 
@@ -17,29 +21,77 @@ pub struct FstDictionary {
     /// Underlying FullDictionary used for everything except fuzzy finding
     full_dict: Arc<FullDictionary>,
     /// Used for fuzzy-finding the index of words or metadata
-    word_map: FstMap<Vec<u8>>,
+    word_map: FstMap<FstBytes>,
     /// Used for fuzzy-finding the index of words or metadata
     words: Vec<(CharString, WordMetadata)>,
+    /// Buffers reused by [`Self::fuzzy_match_into`]
+    scratch: Mutex<FuzzyScratch>,
+}
+
+/// Intermediate buffers for fuzzy matching, kept between calls so they don't
+/// need to be reallocated each time.
+#[derive(Default)]
+struct FuzzyScratch {
+    normalized: Vec<char>,
+    query: String,
+    lowercase: String,
+    /// Index-edit distance pairs found by the automata
+    distances: Vec<(u64, u8)>,
+    /// Ranking keys and indices of each match, best first once sorted
+    candidates: Vec<(u8, u8, Reverse<u32>, usize)>,
+    /// Lowercase forms of the matches returned so far
+    seen: HashSet<CharString>,
+    rows: [Vec<u8>; 2],
+}
+
+/// The bytes backing an FST, either owned or mapped from disk.
+enum FstBytes {
+    Owned(Vec<u8>),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl AsRef<[u8]> for FstBytes {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            FstBytes::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            FstBytes::Mapped(mmap) => mmap,
+        }
+    }
 }
 
 /// The uncached function that is used to produce the original copy of the
 /// curated dictionary.
-fn uncached_inner_new() -> Arc<FstDictionary> {
-    let word_list = parse_default_word_list().unwrap();
-    let attr_list = parse_default_attribute_list();
+fn uncached_inner_new() -> Result<Arc<FstDictionary>, DictionaryError> {
+    Ok(Arc::new(FstDictionary::try_from_full_dictionary(
+        FullDictionary::curated(),
+    )?))
+}
 
-    // There will be at _least_ this number of words
-    let mut word_map = HashMap::with_capacity(word_list.len());
-    attr_list.expand_marked_words(word_list, &mut word_map);
+/// Build the French dictionary from the word list bundled with the `french`
+/// feature.
+#[cfg(feature = "french")]
+fn uncached_french_new() -> Result<Arc<FstDictionary>, DictionaryError> {
+    let word_list = super::word_frequencies::parse_french_word_frequencies()
+        .into_iter()
+        .map(|(word, count)| (word.iter().collect(), count));
 
-    Arc::new(FstDictionary::new(word_map))
+    Ok(Arc::new(FstDictionary::try_from_full_dictionary(
+        Arc::new(FullDictionary::from_word_list(word_list)?),
+    )?))
 }
 
 const EXPECTED_DISTANCE: u8 = 3;
 const TRANSPOSITION_COST_ONE: bool = false;
 
 lazy_static! {
-    static ref DICT: Arc<FstDictionary> = uncached_inner_new();
+    static ref DICT: Result<Arc<FstDictionary>, DictionaryError> = uncached_inner_new();
+}
+
+#[cfg(feature = "french")]
+lazy_static! {
+    static ref FRENCH_DICT: Result<Arc<FstDictionary>, DictionaryError> = uncached_french_new();
 }
 
 thread_local! {
@@ -60,36 +112,457 @@ impl PartialEq for FstDictionary {
 }
 
 impl FstDictionary {
+    /// Words longer than this are never fuzzy matched, and get no results.
+    ///
+    /// Building a Levenshtein automaton gets more expensive the longer the
+    /// word is, so a long run of garbage (like a pasted hash) could otherwise
+    /// stall spell checking. Real words are far shorter.
+    pub const MAX_FUZZY_MATCH_LEN: usize = 64;
+
+    /// Whether `word` is too long to fuzzy match, logging it if so.
+    fn exceeds_fuzzy_match_len(word: &[char]) -> bool {
+        let too_long = word.len() > Self::MAX_FUZZY_MATCH_LEN;
+
+        if too_long {
+            tracing::debug!(
+                "Skipped fuzzy matching a {} character word, which is longer than the limit of {}.",
+                word.len(),
+                Self::MAX_FUZZY_MATCH_LEN
+            );
+        }
+
+        too_long
+    }
+
     /// Create a dictionary from the curated dictionary included
     /// in the Harper binary.
+    ///
+    /// The dictionary is only built once per process, and every call
+    /// (from any thread) returns a handle to that same instance.
+    ///
+    /// Panics if the curated dictionary could not be built.
+    /// See [`Self::try_curated`] for a fallible alternative.
     pub fn curated() -> Arc<Self> {
-        (*DICT).clone()
+        Self::try_curated().expect("Unable to build the curated dictionary.")
+    }
+
+    /// Create a dictionary from the curated dictionary included
+    /// in the Harper binary, returning an error if it could not be built.
+    ///
+    /// Successful results are cached, so this is cheap to call repeatedly.
+    pub fn try_curated() -> Result<Arc<Self>, DictionaryError> {
+        match &*DICT {
+            Ok(dict) => Ok(dict.clone()),
+            // Errors aren't `Clone`, so rebuild to get an owned copy.
+            Err(_) => uncached_inner_new(),
+        }
     }
 
+    /// Load the curated dictionary for a language.
+    ///
+    /// English is always bundled with Harper. French is bundled when the
+    /// `french` feature is enabled; it is a plain word list, so its words
+    /// have no [`WordMetadata`]. Other languages return
+    /// [`DictionaryError::UnsupportedLanguage`].
+    /// [`Language::detect`] can pick a language for unknown text.
+    pub fn for_language(language: Language) -> Result<Arc<Self>, DictionaryError> {
+        match language {
+            Language::English => Self::try_curated(),
+            #[cfg(feature = "french")]
+            Language::French => match &*FRENCH_DICT {
+                Ok(dict) => Ok(dict.clone()),
+                // Errors aren't `Clone`, so rebuild to get an owned copy.
+                Err(_) => uncached_french_new(),
+            },
+            #[cfg(not(feature = "french"))]
+            Language::French => Err(DictionaryError::UnsupportedLanguage(language)),
+        }
+    }
+
+    /// Build a dictionary from a map of words.
+    ///
+    /// Panics if the FST could not be built.
+    /// See [`Self::try_new`] for a fallible alternative.
     pub fn new(new_words: HashMap<CharString, WordMetadata>) -> Self {
+        Self::try_new(new_words).expect("Unable to build FST map.")
+    }
+
+    /// Build a dictionary from a map of words, returning an error if the
+    /// FST could not be built.
+    pub fn try_new(new_words: HashMap<CharString, WordMetadata>) -> Result<Self, DictionaryError> {
         let mut words: Vec<(CharString, WordMetadata)> = new_words.into_iter().collect();
         words.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
         words.dedup_by(|(a, _), (b, _)| a == b);
 
+        let fst_bytes = Self::build_fst_bytes(&words)?;
+
+        let mut full_dict = FullDictionary::new();
+        full_dict.extend_words(words.iter().cloned());
+
+        Self::from_parts(Arc::new(full_dict), words, FstBytes::Owned(fst_bytes))
+    }
+
+    /// Build an FST over the words of an existing [`FullDictionary`].
+    ///
+    /// The [`FullDictionary`] is shared rather than copied.
+    pub fn try_from_full_dictionary(
+        full_dict: Arc<FullDictionary>,
+    ) -> Result<Self, DictionaryError> {
+        let words = Self::sorted_words(&full_dict);
+        let fst_bytes = Self::build_fst_bytes(&words)?;
+
+        Self::from_parts(full_dict, words, FstBytes::Owned(fst_bytes))
+    }
+
+    /// Build an FST from the words in a [`FullDictionary`] and write it to
+    /// disk.
+    ///
+    /// Each word is assigned its index in the lexicographically sorted word
+    /// list as its value.
+    /// The result can be loaded back with [`Self::from_path`].
+    pub fn build_from_dictionary(
+        dict: &FullDictionary,
+        path: &Path,
+    ) -> Result<(), DictionaryError> {
+        let words = Self::sorted_words(dict);
+        let fst_bytes = Self::build_fst_bytes(&words)?;
+
+        fs::write(path, fst_bytes)?;
+
+        Ok(())
+    }
+
+    /// Load an FST previously written by [`Self::build_from_dictionary`].
+    ///
+    /// The provided [`FullDictionary`] must be the same one the FST was built
+    /// from, since it is used to resolve the values stored in the FST.
+    pub fn from_path(path: &Path, full_dict: Arc<FullDictionary>) -> Result<Self, DictionaryError> {
+        let fst_bytes = fs::read(path)?;
+        let words = Self::sorted_words(&full_dict);
+
+        Self::from_parts(full_dict, words, FstBytes::Owned(fst_bytes))
+    }
+
+    /// Memory-map an FST previously written by
+    /// [`Self::build_from_dictionary`], rather than reading it into memory
+    /// like [`Self::from_path`] does.
+    ///
+    /// Only the header and footer are checked, so pages are read lazily as
+    /// lookups touch them. Use [`Self::from_mmap_verified`] to checksum the
+    /// whole file up front.
+    /// The file must not be modified while the dictionary is alive.
+    ///
+    /// Requires the `mmap` feature.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap(path: &Path, full_dict: Arc<FullDictionary>) -> Result<Self, DictionaryError> {
+        let mmap = Self::map_file(path)?;
+        let words = Self::sorted_words(&full_dict);
+
+        Self::from_parts(full_dict, words, FstBytes::Mapped(mmap))
+    }
+
+    /// Like [`Self::from_mmap`], but checksums the whole file first, so a
+    /// truncated or otherwise damaged file results in
+    /// [`DictionaryError::Fst`].
+    ///
+    /// This reads every page of the file once.
+    ///
+    /// Requires the `mmap` feature.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap_verified(
+        path: &Path,
+        full_dict: Arc<FullDictionary>,
+    ) -> Result<Self, DictionaryError> {
+        let mmap = Self::map_file(path)?;
+        fst::raw::Fst::new(&mmap[..])?.verify()?;
+
+        let words = Self::sorted_words(&full_dict);
+
+        Self::from_parts(full_dict, words, FstBytes::Mapped(mmap))
+    }
+
+    #[cfg(feature = "mmap")]
+    fn map_file(path: &Path) -> Result<memmap2::Mmap, DictionaryError> {
+        let file = fs::File::open(path)?;
+        // SAFETY: The map is read-only, and callers are told not to modify the
+        // file while the dictionary is alive.
+        Ok(unsafe { memmap2::Mmap::map(&file)? })
+    }
+
+    /// Find words that begin with a given prefix, for use in autocompletion.
+    ///
+    /// The prefix is normalized the same way as words passed to
+    /// [`Dictionary::fuzzy_match`]. Words matching either the prefix as typed
+    /// or its lowercase form are returned, most frequent first, then in
+    /// lexicographic order.
+    ///
+    /// An empty prefix returns no results.
+    pub fn complete_prefix(&self, prefix: &str, max_results: usize) -> Vec<&[char]> {
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+
+        let prefix: CharString = prefix.chars().collect();
+        let normalized = seq_to_normalized(&prefix).to_string();
+        let lowercase = normalized.to_lowercase();
+
+        let mut indices = Vec::new();
+
+        for query in [normalized.as_str(), lowercase.as_str()]
+            .into_iter()
+            .dedup()
+        {
+            let automaton = Str::new(query).starts_with();
+            let mut stream = self.word_map.search(automaton).into_stream();
+
+            while let Some((_, index)) = stream.next() {
+                indices.push(index as usize);
+            }
+        }
+
+        indices.sort_unstable();
+        indices.dedup();
+
+        let mut completions: Vec<&[char]> = indices
+            .into_iter()
+            .map(|index| self.words[index].0.as_slice())
+            .collect();
+
+        // The FST is lexicographic, so ties stay in that order.
+        completions
+            .sort_by_cached_key(|word| Reverse(self.full_dict.word_frequency(word).unwrap_or(0)));
+        completions.truncate(max_results);
+
+        completions
+    }
+
+    /// Run an arbitrary [`Automaton`] from the `fst` crate (version 0.4)
+    /// against the dictionary's words, returning up to `max_results` matches
+    /// in lexicographic order.
+    ///
+    /// The automaton sees each word as UTF-8, exactly as stored, so casing is
+    /// kept and characters like curly apostrophes have already been
+    /// normalized, as they are for [`Dictionary::fuzzy_match`].
+    /// Nothing is normalized in the query, so automata built from user input
+    /// should normalize it themselves.
+    ///
+    /// Each match comes with its index: its position among the dictionary's
+    /// words in lexicographic order, which is the value the FST maps it to.
+    /// Indices are stable for the lifetime of the dictionary, and the words
+    /// returned here are resolved from them, so no part of the FST is copied.
+    ///
+    /// ```
+    /// use fst::automaton::{Automaton, Str};
+    /// use harper_core::FstDictionary;
+    ///
+    /// let dict = FstDictionary::curated();
+    /// let matches = dict.search_automaton(Str::new("grammat").starts_with(), 10);
+    ///
+    /// assert!(matches
+    ///     .iter()
+    ///     .any(|(word, _)| word.iter().collect::<String>() == "grammatical"));
+    /// ```
+    pub fn search_automaton<A: Automaton>(
+        &self,
+        aut: A,
+        max_results: usize,
+    ) -> Vec<(&[char], u64)> {
+        let mut stream = self.word_map.search(aut).into_stream();
+        let mut found = Vec::new();
+
+        while found.len() < max_results {
+            let Some((_, index)) = stream.next() else {
+                break;
+            };
+
+            found.push((self.words[index as usize].0.as_slice(), index));
+        }
+
+        found
+    }
+
+    /// Estimate how many bytes of memory the dictionary uses: the FST itself
+    /// (whether owned or mapped from disk), the word list it indexes into,
+    /// and the wrapped [`FullDictionary`].
+    ///
+    /// The [`FullDictionary`] is counted in full even if it is shared with
+    /// other dictionaries, as the curated one is.
+    pub fn memory_footprint(&self) -> usize {
+        size_of::<Self>()
+            + self.word_map.as_fst().as_bytes().len()
+            + self.words.capacity() * size_of::<(CharString, WordMetadata)>()
+            + self
+                .words
+                .iter()
+                .map(|(word, _)| word_heap_size(word))
+                .sum::<usize>()
+            + self.full_dict.memory_footprint()
+    }
+
+    /// The position of `word` among this dictionary's words, if it is
+    /// present with exactly this casing.
+    ///
+    /// Positions are stable for the lifetime of the dictionary.
+    pub(super) fn word_index(&self, word: &[char]) -> Option<usize> {
+        self.word_map
+            .get(word.to_string())
+            .map(|index| index as usize)
+    }
+
+    /// The word at a position returned by [`Self::word_index`].
+    pub(super) fn word_at(&self, index: usize) -> (&[char], WordMetadata) {
+        let (word, metadata) = &self.words[index];
+        (word, *metadata)
+    }
+
+    /// Like [`Dictionary::fuzzy_match`], but writes the results into `out`
+    /// instead of a new vector.
+    ///
+    /// `out` is cleared first. Intermediate buffers are kept inside the
+    /// dictionary and reused, so a loop that also reuses `out` allocates very
+    /// little. If another thread is using those buffers, fresh ones are
+    /// allocated rather than waiting.
+    pub fn fuzzy_match_into<'a>(
+        &'a self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+        out: &mut Vec<FuzzyMatchResult<'a>>,
+    ) {
+        out.clear();
+
+        if Self::exceeds_fuzzy_match_len(word) {
+            return;
+        }
+
+        match self.scratch.try_lock() {
+            Ok(mut scratch) => {
+                self.fuzzy_match_with_scratch(word, max_distance, max_results, &mut scratch, out)
+            }
+            Err(_) => self.fuzzy_match_with_scratch(
+                word,
+                max_distance,
+                max_results,
+                &mut FuzzyScratch::default(),
+                out,
+            ),
+        }
+    }
+
+    fn fuzzy_match_with_scratch<'a>(
+        &'a self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+        scratch: &mut FuzzyScratch,
+        out: &mut Vec<FuzzyMatchResult<'a>>,
+    ) {
+        let FuzzyScratch {
+            normalized,
+            query,
+            lowercase,
+            distances,
+            candidates,
+            seen,
+            rows: [previous_row, current_row],
+        } = scratch;
+
+        normalized.clear();
+        normalized.extend(word.iter().copied().map(char_to_normalized));
+        query.clear();
+        query.extend(normalized.iter());
+        lowercase.clear();
+        lowercase.extend(query.chars().flat_map(char::to_lowercase));
+
+        distances.clear();
+        self.extend_distances(query, max_distance, distances);
+        if lowercase != query {
+            self.extend_distances(lowercase, max_distance, distances);
+        }
+
+        // A word found by both queries keeps its smaller distance.
+        distances.sort_unstable();
+        distances.dedup_by_key(|(i, _)| *i);
+
+        // Closer matches first. Ties go to words that keep the original
+        // casing, then to the more frequent word, then to the
+        // lexicographically first (which is the order of the indices).
+        candidates.clear();
+        candidates.extend(distances.iter().map(|&(i, dist)| {
+            let (word, _) = &self.words[i as usize];
+
+            (
+                dist,
+                edit_distance_min_alloc(normalized, word, previous_row, current_row),
+                Reverse(self.full_dict.word_frequency(word).unwrap_or(0)),
+                i as usize,
+            )
+        }));
+        candidates.sort_unstable();
+
+        // Collapse words that only differ by casing into the best-ranked one.
+        seen.clear();
+        for &(edit_distance, _, _, i) in candidates.iter() {
+            if out.len() >= max_results {
+                break;
+            }
+
+            let (word, metadata) = &self.words[i];
+
+            if seen.insert(word.to_lower()) {
+                out.push(FuzzyMatchResult {
+                    word,
+                    edit_distance,
+                    metadata: *metadata,
+                });
+            }
+        }
+    }
+
+    /// Collect the words and metadata of a [`FullDictionary`], sorted in the
+    /// order they are stored in the FST.
+    fn sorted_words(dict: &FullDictionary) -> Vec<(CharString, WordMetadata)> {
+        let mut words: Vec<(CharString, WordMetadata)> = dict
+            .words_iter()
+            .map(|word| (word.to_smallvec(), dict.get_word_metadata(word)))
+            .collect();
+        words.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        words
+    }
+
+    /// Serialize an FST mapping each word to its index in `words`.
+    /// `words` must be sorted and deduplicated.
+    fn build_fst_bytes(words: &[(CharString, WordMetadata)]) -> Result<Vec<u8>, DictionaryError> {
         let mut builder = fst::MapBuilder::memory();
         for (index, (word, _)) in words.iter().enumerate() {
             let word = word.iter().collect::<String>();
-            builder
-                .insert(word, index as u64)
-                .expect("Insertion not in lexicographical order!");
+            builder.insert(word, index as u64)?;
         }
 
-        let mut full_dict = FullDictionary::new();
-        full_dict.extend_words(words.iter().cloned());
+        Ok(builder.into_inner()?)
+    }
 
-        let fst_bytes = builder.into_inner().unwrap();
-        let word_map = FstMap::new(fst_bytes).expect("Unable to build FST map.");
+    fn from_parts(
+        full_dict: Arc<FullDictionary>,
+        words: Vec<(CharString, WordMetadata)>,
+        fst_bytes: FstBytes,
+    ) -> Result<Self, DictionaryError> {
+        let word_map = FstMap::new(fst_bytes)?;
 
-        FstDictionary {
-            full_dict: Arc::new(full_dict),
+        if word_map.len() != words.len() {
+            return Err(DictionaryError::LengthMismatch {
+                fst_len: word_map.len(),
+                dict_len: words.len(),
+            });
+        }
+
+        Ok(FstDictionary {
+            full_dict,
             word_map,
             words,
-        }
+            scratch: Mutex::default(),
+        })
     }
 }
 
@@ -114,16 +587,52 @@ fn build_dfa(max_distance: u8, query: &str) -> DFA {
 }
 
 /// Consumes a DFA stream and emits the index-edit distance pairs it produces.
-fn stream_distances_vec(stream: &mut StreamWithState<&DFA>, dfa: &DFA) -> Vec<(u64, u8)> {
-    let mut word_index_pairs = Vec::new();
-    while let Some((_, v, s)) = stream.next() {
-        word_index_pairs.push((v, dfa.distance(s).to_u8()));
+impl FstDictionary {
+    /// Lazily stream the index-edit distance pairs of words within
+    /// `max_distance` of `query`, in lexicographic order.
+    fn stream_distances(
+        &self,
+        query: &str,
+        max_distance: u8,
+    ) -> impl Iterator<Item = (u64, u8)> + Send + '_ {
+        // The stream takes ownership of its automaton, so we need a second
+        // copy to compute distances from the states it emits.
+        let dfa = build_dfa(max_distance, query);
+        let mut stream = self
+            .word_map
+            .search_with_state(build_dfa(max_distance, query))
+            .into_stream();
+
+        std::iter::from_fn(move || {
+            let (_, v, s) = stream.next()?;
+            Some((v, dfa.distance(s).to_u8()))
+        })
     }
 
-    word_index_pairs
+    /// Eagerly collect the same pairs as [`Self::stream_distances`] into
+    /// `out`.
+    ///
+    /// Since nothing outlives this call, the search can borrow the automaton
+    /// and only one needs to be built.
+    fn extend_distances(&self, query: &str, max_distance: u8, out: &mut Vec<(u64, u8)>) {
+        let dfa = build_dfa(max_distance, query);
+        let mut stream = self.word_map.search_with_state(&dfa).into_stream();
+
+        while let Some((_, v, s)) = stream.next() {
+            out.push((v, dfa.distance(s).to_u8()));
+        }
+    }
 }
 
 impl Dictionary for FstDictionary {
+    fn len(&self) -> usize {
+        self.full_dict.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.full_dict.is_empty()
+    }
+
     fn contains_word(&self, word: &[char]) -> bool {
         self.full_dict.contains_word(word)
     }
@@ -132,6 +641,22 @@ impl Dictionary for FstDictionary {
         self.full_dict.contains_word_str(word)
     }
 
+    fn contains_words(&self, words: &[&[char]]) -> Vec<bool> {
+        self.full_dict.contains_words(words)
+    }
+
+    fn contains_word_case_sensitive(&self, word: &[char]) -> bool {
+        self.full_dict.contains_word_case_sensitive(word)
+    }
+
+    fn contains_word_ignore_case(&self, word: &[char]) -> bool {
+        self.full_dict.contains_word_ignore_case(word)
+    }
+
+    fn contains_word_fold_diacritics(&self, word: &[char]) -> bool {
+        self.full_dict.contains_word_fold_diacritics(word)
+    }
+
     fn get_word_metadata(&self, word: &[char]) -> WordMetadata {
         self.full_dict.get_word_metadata(word)
     }
@@ -140,51 +665,53 @@ impl Dictionary for FstDictionary {
         self.full_dict.get_word_metadata_str(word)
     }
 
+    fn word_frequency(&self, word: &[char]) -> Option<u32> {
+        self.full_dict.word_frequency(word)
+    }
+
     fn fuzzy_match(
         &self,
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
-        let misspelled_word_charslice = seq_to_normalized(word);
-        let misspelled_word_string = misspelled_word_charslice.to_string();
-
-        // Actual FST search
-        let dfa = build_dfa(max_distance, &misspelled_word_string);
-        let dfa_lowercase = build_dfa(max_distance, &misspelled_word_string.to_lowercase());
-        let mut word_indexes_stream = self.word_map.search_with_state(&dfa).into_stream();
-        let mut word_indexes_lowercase_stream = self
-            .word_map
-            .search_with_state(&dfa_lowercase)
-            .into_stream();
-
-        let upper_dists = stream_distances_vec(&mut word_indexes_stream, &dfa);
-        let lower_dists = stream_distances_vec(&mut word_indexes_lowercase_stream, &dfa_lowercase);
-
-        let mut merged = Vec::with_capacity(upper_dists.len());
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let mut out = Vec::new();
+        self.fuzzy_match_into(word, max_distance, max_results, &mut out);
+        out
+    }
 
-        // Merge the two results
-        for ((i_u, dist_u), (i_l, dist_l)) in upper_dists.into_iter().zip(lower_dists.into_iter()) {
-            let (chosen_index, edit_distance) = if dist_u <= dist_l {
-                (i_u, dist_u)
-            } else {
-                (i_l, dist_l)
-            };
+    fn fuzzy_match_with_casing_variants(
+        &self,
+        word: &[char],
+        max_distance: u8,
+        max_results: usize,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let misspelled_word_charslice = seq_to_normalized(word);
 
-            let (word, metadata) = &self.words[chosen_index as usize];
+        // Closer matches first. Ties go to words that keep the original
+        // casing, then to the more frequent word.
+        // Only the best `max_results` candidates are kept as we go.
+        self.fuzzy_match_iter(word, max_distance)
+            .map(|(word, dist, metadata)| {
+                let key = (
+                    dist,
+                    edit_distance(&misspelled_word_charslice, word),
+                    Reverse(self.full_dict.word_frequency(word).unwrap_or(0)),
+                    word,
+                );
 
-            merged.push(FuzzyMatchResult {
-                word,
-                edit_distance,
-                metadata: *metadata,
+                (
+                    key,
+                    FuzzyMatchResult {
+                        word,
+                        edit_distance: dist,
+                        metadata,
+                    },
+                )
             })
-        }
-
-        merged.sort_unstable_by_key(|v| v.word);
-        merged.sort_unstable_by_key(|v| v.edit_distance);
-        merged.truncate(max_results);
-
-        merged
+            .k_smallest_by_key(max_results, |(key, _)| *key)
+            .map(|(_, result)| result)
+            .collect()
     }
 
     fn fuzzy_match_str(
@@ -192,7 +719,7 @@ impl Dictionary for FstDictionary {
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
         self.fuzzy_match(
             word.chars().collect::<Vec<_>>().as_slice(),
             max_distance,
@@ -200,10 +727,55 @@ impl Dictionary for FstDictionary {
         )
     }
 
+    fn fuzzy_match_iter(
+        &self,
+        word: &[char],
+        max_distance: u8,
+    ) -> Box<dyn Iterator<Item = (&'_ [char], u8, WordMetadata)> + Send + '_> {
+        if Self::exceeds_fuzzy_match_len(word) {
+            return Box::new(std::iter::empty());
+        }
+
+        let misspelled_word_string = seq_to_normalized(word).to_string();
+        let misspelled_lowercase = misspelled_word_string.to_lowercase();
+
+        let upper_dists = self.stream_distances(&misspelled_word_string, max_distance);
+        let lower_dists = (misspelled_lowercase != misspelled_word_string)
+            .then(|| self.stream_distances(&misspelled_lowercase, max_distance))
+            .into_iter()
+            .flatten();
+
+        // Both streams are sorted by index, so they can be merged and
+        // deduplicated without collecting either of them.
+        Box::new(
+            upper_dists
+                .merge_join_by(lower_dists, |(i_u, _), (i_l, _)| i_u.cmp(i_l))
+                .map(|pair| match pair {
+                    EitherOrBoth::Both((i, dist_u), (_, dist_l)) => (i, dist_u.min(dist_l)),
+                    EitherOrBoth::Left(found) | EitherOrBoth::Right(found) => found,
+                })
+                .map(|(i, edit_distance)| {
+                    let (word, metadata) = &self.words[i as usize];
+                    (word.as_slice(), edit_distance, *metadata)
+                }),
+        )
+    }
+
+    fn phonetic_match(&self, word: &[char], max_results: usize) -> Vec<&'_ [char]> {
+        self.full_dict.phonetic_match(word, max_results)
+    }
+
     fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
         self.full_dict.words_iter()
     }
 
+    fn words_matching<'a>(
+        &'a self,
+        predicate: &'a (dyn Fn(&WordMetadata) -> bool + Sync),
+    ) -> Box<dyn Iterator<Item = &'a [char]> + Send + 'a> {
+        self.full_dict.words_matching(predicate)
+    }
+
     fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
         self.full_dict.words_with_len_iter(len)
     }
@@ -211,10 +783,16 @@ impl Dictionary for FstDictionary {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use fst::automaton::{Automaton, Str, Subsequence};
     use itertools::Itertools;
 
     use crate::CharStringExt;
-    use crate::{spell::seq_to_normalized, Dictionary};
+    use crate::{
+        spell::seq_to_normalized, Dictionary, DictionaryError, FullDictionary, Language,
+        PartOfSpeech,
+    };
 
     use super::FstDictionary;
 
@@ -237,6 +815,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn forwards_parts_of_speech() {
+        let dict = FstDictionary::curated();
+
+        assert!(dict.get_word_metadata_str("dog").is_noun());
+        assert!(dict.get_word_metadata_str("eat").is_verb());
+        assert!(dict.get_word_metadata_str("happy").is_adjective());
+        assert!(dict.get_word_metadata_str("quickly").is_adverb());
+        assert!(dict.get_word_metadata_str("the").is_article());
+        assert!(dict.get_word_metadata_str("in").is_preposition());
+    }
+
+    #[test]
+    fn exposes_word_frequencies() {
+        let dict = FstDictionary::curated();
+        let freq = |word: &str| dict.word_frequency(&word.chars().collect::<Vec<_>>());
+
+        assert!(freq("the") > freq("of"));
+        assert_eq!(freq("The"), freq("the"));
+        assert_eq!(freq("xylophonist"), None);
+    }
+
+    #[test]
+    fn knows_past_participles() {
+        let dict = FstDictionary::curated();
+
+        assert!(dict
+            .get_word_metadata_str("eaten")
+            .is_past_participle_verb());
+        assert!(dict
+            .get_word_metadata_str("walked")
+            .is_past_participle_verb());
+        assert!(!dict.get_word_metadata_str("eat").is_past_participle_verb());
+    }
+
+    #[test]
+    fn lists_parts_of_speech() {
+        let dict = FstDictionary::curated();
+
+        let happy = dict.get_word_metadata_str("happy");
+        assert!(happy.parts_of_speech().contains(&PartOfSpeech::Adjective));
+
+        assert_eq!(
+            dict.get_word_metadata_str("hvllo")
+                .parts_of_speech()
+                .count(),
+            0
+        );
+    }
+
     #[test]
     fn fst_contains_hello() {
         let dict = FstDictionary::curated();
@@ -266,4 +894,374 @@ mod tests {
 
         assert!(is_sorted_by_dist)
     }
+
+    #[test]
+    fn fuzzy_match_skips_absurdly_long_words() {
+        let dict = FstDictionary::curated();
+        let garbage: String = "qxzj".repeat(50);
+        let chars: Vec<_> = garbage.chars().collect();
+
+        assert!(dict.fuzzy_match_str(&garbage, 3, 10).is_empty());
+        assert!(dict.fuzzy_match_iter(&chars, 3).next().is_none());
+        assert!(dict
+            .fuzzy_match_with_casing_variants(&chars, 3, 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_frequent_words() {
+        let dict = FstDictionary::curated();
+
+        let results = dict.fuzzy_match_str("tge", 1, 3);
+
+        assert_eq!(results[0].word, &['t', 'h', 'e']);
+    }
+
+    #[test]
+    fn fuzzy_match_stops_at_max_results() {
+        let dict = FstDictionary::curated();
+        let word: Vec<_> = "hvllo".chars().collect();
+
+        let all = dict.fuzzy_match_with_casing_variants(&word, 3, usize::MAX);
+        let best = dict.fuzzy_match_with_casing_variants(&word, 3, 1);
+
+        assert!(all.len() > 1);
+        assert_eq!(best.len(), 1);
+        assert_eq!(best[0].word, all[0].word);
+        assert_eq!(dict.fuzzy_match(&word, 3, 1).len(), 1);
+    }
+
+    #[test]
+    fn fuzzy_match_reports_edit_distance() {
+        let dict = FstDictionary::curated();
+
+        let results = dict.fuzzy_match_str("tge", 1, 3);
+
+        assert_eq!(results[0].word, &['t', 'h', 'e']);
+        assert_eq!(results[0].edit_distance, 1);
+        assert!(results.iter().all(|r| r.edit_distance <= 1));
+    }
+
+    #[test]
+    fn fuzzy_match_agrees_with_full_dictionary() {
+        let fst_dict = FstDictionary::curated();
+        let full_dict = FullDictionary::curated();
+
+        for word in ["Hvllo", "teh", "Thsi"] {
+            let from_fst = fst_dict
+                .fuzzy_match_str(word, 2, usize::MAX)
+                .into_iter()
+                .map(|r| (r.word, r.edit_distance))
+                .sorted()
+                .collect_vec();
+            let from_full = full_dict
+                .fuzzy_match_str(word, 2, usize::MAX)
+                .into_iter()
+                .map(|r| (r.word, r.edit_distance))
+                .sorted()
+                .collect_vec();
+
+            assert_eq!(from_fst, from_full);
+        }
+    }
+
+    #[test]
+    fn fuzzy_match_iter_agrees_with_fuzzy_match() {
+        let dict = FstDictionary::curated();
+        let word: Vec<_> = "hvllo".chars().collect();
+
+        let eager = dict.fuzzy_match_with_casing_variants(&word, 2, usize::MAX);
+        let lazy = dict.fuzzy_match_iter(&word, 2).collect_vec();
+
+        assert_eq!(eager.len(), lazy.len());
+        assert!(lazy.iter().map(|(w, _, _)| w).all_unique());
+
+        for result in eager {
+            assert!(lazy.contains(&(result.word, result.edit_distance, result.metadata)));
+        }
+    }
+
+    #[test]
+    fn fuzzy_match_iter_can_stop_early() {
+        let dict = FstDictionary::curated();
+        let word: Vec<_> = "hvllo".chars().collect();
+
+        let first = dict
+            .fuzzy_match_iter(&word, 1)
+            .find(|(_, dist, _)| *dist == 1);
+
+        assert!(first.is_some());
+    }
+
+    #[test]
+    fn completes_prefix_by_frequency() {
+        let dict = FstDictionary::curated();
+
+        let completions = dict.complete_prefix("th", 3);
+
+        assert_eq!(completions.len(), 3);
+        assert_eq!(completions[0], &['t', 'h', 'e']);
+        assert!(completions.iter().all(|w| w.starts_with(&['t', 'h'])));
+    }
+
+    #[test]
+    fn completes_capitalized_prefix() {
+        let dict = FstDictionary::curated();
+
+        let completions = dict.complete_prefix("Hell", 100);
+
+        assert!(completions.contains(&['h', 'e', 'l', 'l', 'o'].as_slice()));
+    }
+
+    #[test]
+    fn empty_prefix_completes_nothing() {
+        let dict = FstDictionary::curated();
+
+        assert!(dict.complete_prefix("", 10).is_empty());
+    }
+
+    #[test]
+    fn curated_is_shared_across_threads() {
+        let here = FstDictionary::curated();
+        let there = std::thread::spawn(FstDictionary::curated).join().unwrap();
+
+        assert!(Arc::ptr_eq(&here, &there));
+    }
+
+    #[test]
+    fn try_curated_matches_curated() {
+        let dict = FstDictionary::try_curated().unwrap();
+
+        assert!(Arc::ptr_eq(&dict, &FstDictionary::curated()));
+    }
+
+    #[test]
+    fn round_trips_through_disk() {
+        let full_dict = FullDictionary::curated();
+        let path = std::env::temp_dir().join("harper_round_trips_through_disk.fst");
+
+        FstDictionary::build_from_dictionary(&full_dict, &path).unwrap();
+        let dict = FstDictionary::from_path(&path, full_dict.clone()).unwrap();
+
+        assert_eq!(dict.word_map.len(), full_dict.words_iter().count());
+        assert!(dict.contains_word_str("hello"));
+        assert!(!dict.fuzzy_match_str("hvllo", 1, 10).is_empty());
+    }
+
+    #[test]
+    fn rejects_mismatched_dictionary() {
+        let mut small_dict = FullDictionary::new();
+        small_dict.append_word_str("hello", Default::default());
+        let path = std::env::temp_dir().join("harper_rejects_mismatched_dictionary.fst");
+
+        FstDictionary::build_from_dictionary(&small_dict, &path).unwrap();
+        let result = FstDictionary::from_path(&path, FullDictionary::curated());
+
+        assert!(matches!(
+            result,
+            Err(DictionaryError::LengthMismatch { fst_len: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_corrupt_fst() {
+        let path = std::env::temp_dir().join("harper_rejects_corrupt_fst.fst");
+        std::fs::write(&path, b"definitely not an fst").unwrap();
+
+        let result = FstDictionary::from_path(&path, FullDictionary::curated());
+
+        assert!(matches!(result, Err(DictionaryError::Fst(_))));
+    }
+
+    #[test]
+    fn fuzzy_match_into_collapses_casing_variants() {
+        let dict = FstDictionary::curated();
+
+        for word in ["hvllo", "Polish", "recieve", "Teh"] {
+            let word: Vec<char> = word.chars().collect();
+
+            let mut expected = dict.fuzzy_match_with_casing_variants(&word, 2, usize::MAX);
+            let mut seen = hashbrown::HashSet::new();
+            expected.retain(|m| seen.insert(m.word.to_lower()));
+            expected.truncate(10);
+
+            let mut out = Vec::new();
+            dict.fuzzy_match_into(&word, 2, 10, &mut out);
+
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn fuzzy_match_into_reuses_output() {
+        let dict = FstDictionary::curated();
+        let mut out = Vec::new();
+
+        dict.fuzzy_match_into(&['h', 'v', 'l', 'l', 'o'], 2, 10, &mut out);
+        let first = out.clone();
+
+        dict.fuzzy_match_into(&['t', 'e', 'h'], 2, 10, &mut out);
+        assert!(out.iter().all(|m| !first.contains(m)));
+
+        dict.fuzzy_match_into(&['h', 'v', 'l', 'l', 'o'], 2, 10, &mut out);
+        assert_eq!(out, first);
+    }
+
+    #[test]
+    fn fuzzy_match_into_from_many_threads() {
+        let dict = FstDictionary::curated();
+        let expected = dict.fuzzy_match_str("hvllo", 2, 10);
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..10 {
+                        assert_eq!(dict.fuzzy_match_str("hvllo", 2, 10), expected);
+                    }
+                });
+            }
+        });
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn round_trips_through_mmap() {
+        let full_dict = FullDictionary::curated();
+        let path = std::env::temp_dir().join("harper_round_trips_through_mmap.fst");
+
+        FstDictionary::build_from_dictionary(&full_dict, &path).unwrap();
+        let dict = FstDictionary::from_mmap(&path, full_dict.clone()).unwrap();
+
+        assert_eq!(dict.word_map.len(), full_dict.words_iter().count());
+        assert!(dict.contains_word_str("hello"));
+        assert!(!dict.fuzzy_match_str("hvllo", 1, 10).is_empty());
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_rejects_empty_fst() {
+        let full_dict = FullDictionary::curated();
+        let path = std::env::temp_dir().join("harper_mmap_rejects_empty_fst.fst");
+
+        std::fs::write(&path, []).unwrap();
+
+        let result = FstDictionary::from_mmap(&path, full_dict);
+
+        assert!(matches!(result, Err(DictionaryError::Fst(_))));
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn verified_mmap_rejects_truncated_fst() {
+        let full_dict = FullDictionary::curated();
+        let path = std::env::temp_dir().join("harper_verified_mmap_rejects_truncated_fst.fst");
+
+        FstDictionary::build_from_dictionary(&full_dict, &path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+
+        for len in [0, bytes.len() / 2, bytes.len() - 1] {
+            std::fs::write(&path, &bytes[..len]).unwrap();
+
+            let result = FstDictionary::from_mmap_verified(&path, full_dict.clone());
+
+            assert!(matches!(result, Err(DictionaryError::Fst(_))));
+        }
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn verified_mmap_accepts_intact_fst() {
+        let full_dict = FullDictionary::curated();
+        let path = std::env::temp_dir().join("harper_verified_mmap_accepts_intact_fst.fst");
+
+        FstDictionary::build_from_dictionary(&full_dict, &path).unwrap();
+        let dict = FstDictionary::from_mmap_verified(&path, full_dict).unwrap();
+
+        assert!(dict.contains_word_str("hello"));
+    }
+
+    #[test]
+    fn len_matches_full_dictionary() {
+        assert_eq!(
+            FstDictionary::curated().len(),
+            FullDictionary::curated().len()
+        );
+    }
+
+    #[test]
+    fn memory_footprint_includes_full_dictionary_and_fst() {
+        let dict = FstDictionary::curated();
+        let footprint = dict.memory_footprint();
+
+        assert!(footprint > FullDictionary::curated().memory_footprint());
+        assert!(footprint > dict.word_map.as_fst().as_bytes().len());
+    }
+
+    #[test]
+    fn english_is_the_curated_dictionary() {
+        let english = FstDictionary::for_language(Language::English).unwrap();
+
+        assert!(Arc::ptr_eq(&english, &FstDictionary::curated()));
+    }
+
+    #[cfg(not(feature = "french"))]
+    #[test]
+    fn unbundled_languages_are_errors() {
+        assert!(matches!(
+            FstDictionary::for_language(Language::French),
+            Err(DictionaryError::UnsupportedLanguage(Language::French))
+        ));
+    }
+
+    #[cfg(feature = "french")]
+    #[test]
+    fn french_is_bundled() {
+        let french = FstDictionary::for_language(Language::French).unwrap();
+
+        assert!(Arc::ptr_eq(
+            &french,
+            &FstDictionary::for_language(Language::French).unwrap()
+        ));
+        assert!(french.contains_word_str("être"));
+        assert!(french.contains_word_str("aujourd'hui"));
+        assert!(!french.contains_word_str("the"));
+    }
+
+    #[cfg(feature = "french")]
+    #[test]
+    fn french_suggestions_come_from_french_words() {
+        let french = FstDictionary::for_language(Language::French).unwrap();
+        let suggestions: Vec<String> = french
+            .fuzzy_match_str("maisen", 1, 3)
+            .into_iter()
+            .map(|found| found.word.iter().collect())
+            .collect();
+
+        assert_eq!(suggestions, vec!["maison".to_string()]);
+    }
+
+    #[test]
+    fn search_automaton_resolves_indices() {
+        let dict = FstDictionary::curated();
+        let matches = dict.search_automaton(Str::new("hous").starts_with(), 5);
+
+        assert!(!matches.is_empty() && matches.len() <= 5);
+
+        for (word, index) in matches {
+            assert!(word.starts_with(&['h', 'o', 'u', 's']));
+            assert_eq!(dict.word_at(index as usize).0, word);
+            assert_eq!(dict.word_index(word), Some(index as usize));
+        }
+    }
+
+    #[test]
+    fn search_automaton_supports_subsequences() {
+        let dict = FstDictionary::curated();
+        let matches = dict.search_automaton(Subsequence::new("grmmr"), usize::MAX);
+
+        assert!(matches
+            .iter()
+            .any(|(word, _)| word.iter().collect::<String>() == "grammar"));
+        assert!(matches.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
 }
@@ -1,15 +1,23 @@
 use super::{
-    edit_distance_min_alloc,
+    bloom_filter::BloomFilter,
+    char_to_normalized,
+    diacritics::fold_diacritics,
+    edit_distance, edit_distance_min_alloc,
     hunspell::{parse_default_attribute_list, parse_default_word_list},
+    metaphone::metaphone,
     seq_to_normalized,
+    word_frequencies::parse_default_word_frequencies,
+    DictionaryDiff, DictionaryError,
 };
-use hashbrown::HashMap;
-use itertools::Itertools;
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
+use itertools::{EitherOrBoth, Itertools};
 use lazy_static::lazy_static;
 use smallvec::{SmallVec, ToSmallVec};
-use std::sync::Arc;
+use std::cmp::Reverse;
+use std::sync::{Arc, OnceLock};
 
-use crate::{CharString, CharStringExt, WordMetadata};
+use crate::{CharString, CharStringExt, Document, TokenStringExt, WordMetadata};
 
 use super::dictionary::Dictionary;
 use super::FuzzyMatchResult;
@@ -31,6 +39,23 @@ pub struct FullDictionary {
     word_len_starts: Vec<usize>,
     /// All English words
     word_map: HashMap<CharString, WordMetadata>,
+    /// How often each word occurs, used to rank suggestions.
+    /// Words missing from this table are assumed to be rare.
+    word_frequencies: HashMap<CharString, u32>,
+    /// Words grouped by their Metaphone code.
+    /// Built the first time it is needed, since most users never ask for
+    /// phonetic matches.
+    phonetic_index: OnceLock<HashMap<CharString, Vec<CharString>>>,
+    /// Lowercase copies of every word, for case-insensitive lookup.
+    /// Built the first time it is needed.
+    lowercase_index: OnceLock<HashSet<CharString>>,
+    /// Copies of every word with their diacritics stripped, for
+    /// diacritic-insensitive lookup.
+    /// Built the first time it is needed.
+    folded_index: OnceLock<HashSet<CharString>>,
+    /// Lets lookups of missing words skip [`Self::word_map`].
+    /// Only present if requested with [`Self::with_bloom_filter`].
+    bloom_filter: Option<BloomFilter>,
 }
 
 /// The uncached function that is used to produce the original copy of the
@@ -54,6 +79,11 @@ fn uncached_inner_new() -> Arc<FullDictionary> {
         word_map,
         word_len_starts: FullDictionary::create_len_starts(&words),
         words,
+        word_frequencies: parse_default_word_frequencies(),
+        phonetic_index: OnceLock::new(),
+        lowercase_index: OnceLock::new(),
+        folded_index: OnceLock::new(),
+        bloom_filter: None,
     })
 }
 
@@ -67,9 +97,53 @@ impl FullDictionary {
             words: Vec::new(),
             word_len_starts: Vec::new(),
             word_map: HashMap::new(),
+            word_frequencies: HashMap::new(),
+            phonetic_index: OnceLock::new(),
+            lowercase_index: OnceLock::new(),
+            folded_index: OnceLock::new(),
+            bloom_filter: None,
         }
     }
 
+    /// Create a dictionary from a list of words and how often each occurs.
+    ///
+    /// Words are normalized the same way lookups are, so "don’t" and "don't"
+    /// are the same word. If a word appears more than once, the highest
+    /// frequency is kept. Every word gets empty [`WordMetadata`].
+    ///
+    /// Returns [`DictionaryError::EmptyWord`] if any word is empty.
+    /// To get an [`FstDictionary`](super::FstDictionary) for fast fuzzy
+    /// matching, pass the result to
+    /// [`FstDictionary::try_from_full_dictionary`](super::FstDictionary::try_from_full_dictionary).
+    pub fn from_word_list(
+        entries: impl IntoIterator<Item = (String, u32)>,
+    ) -> Result<Self, DictionaryError> {
+        let mut word_frequencies: HashMap<CharString, u32> = HashMap::new();
+
+        for (index, (word, frequency)) in entries.into_iter().enumerate() {
+            if word.is_empty() {
+                return Err(DictionaryError::EmptyWord { index });
+            }
+
+            let word: CharString = word.chars().map(char_to_normalized).collect();
+
+            word_frequencies
+                .entry(word)
+                .and_modify(|existing| *existing = (*existing).max(frequency))
+                .or_insert(frequency);
+        }
+
+        let mut dict = Self::new();
+        dict.add_words(
+            word_frequencies
+                .keys()
+                .map(|word| (word, WordMetadata::default())),
+        );
+        dict.word_frequencies = word_frequencies;
+
+        Ok(dict)
+    }
+
     /// Create a dictionary from the curated dictionary included
     /// in the Harper binary.
     /// Consider using [`super::FstDictionary::curated()`] instead, as it is more performant for spellchecking.
@@ -77,22 +151,97 @@ impl FullDictionary {
         (*DICT).clone()
     }
 
+    /// Build a bloom filter of every word, so [`Dictionary::contains_word`]
+    /// can reject most misspelled words without searching the full word map.
+    ///
+    /// This never changes the result of a lookup, only how fast it is.
+    /// It speeds up text with many misspellings, but adds a small cost to
+    /// every correctly spelled word, since those still need the full lookup.
+    /// The filter costs roughly two bytes per word, and is kept up to date by
+    /// [`Self::add_words`].
+    pub fn with_bloom_filter(mut self) -> Self {
+        self.bloom_filter = Some(self.build_bloom_filter());
+        self
+    }
+
+    /// The filter holds the lowercase form of every word, so a single check
+    /// covers both the exact and the case-insensitive lookup.
+    fn build_bloom_filter(&self) -> BloomFilter {
+        BloomFilter::from_words(self.words.iter().map(|word| word.to_lower()))
+    }
+
+    /// Returns `false` if no word lowercases to `lowercase`.
+    /// Always `true` without a bloom filter.
+    fn may_contain_lowercase(&self, lowercase: &[char]) -> bool {
+        self.bloom_filter
+            .as_ref()
+            .is_none_or(|filter| filter.may_contain(lowercase))
+    }
+
+    /// Add a single word to the dictionary at runtime.
+    ///
+    /// The word is visible to [`Dictionary::contains_word`],
+    /// [`Dictionary::words_iter`] and [`Dictionary::words_with_len_iter`]
+    /// immediately. If the word is already present, the provided metadata is
+    /// merged into the existing entry.
+    ///
+    /// Each call re-sorts the word list and rebuilds the length index, which
+    /// is `O(n log n)` in the size of the dictionary.
+    /// If you are adding many words, use [`Self::add_words`] instead.
+    ///
+    /// Note that an [`FstDictionary`](super::FstDictionary) built from this
+    /// dictionary will not see the new word during fuzzy matching until it is
+    /// rebuilt.
+    pub fn add_word(&mut self, word: &[char], metadata: WordMetadata) {
+        self.add_words(std::iter::once((word, metadata)))
+    }
+
+    /// Add many words to the dictionary at runtime.
+    ///
+    /// Behaves like [`Self::add_word`], but only rebuilds the lookup index
+    /// once, after all the words have been inserted.
+    pub fn add_words(
+        &mut self,
+        words: impl IntoIterator<Item = (impl AsRef<[char]>, WordMetadata)>,
+    ) {
+        let mut added_any = false;
+
+        for (word, metadata) in words {
+            match self.word_map.entry(word.as_ref().to_smallvec()) {
+                Entry::Occupied(mut entry) => {
+                    entry.get_mut().append(&metadata);
+                }
+                Entry::Vacant(entry) => {
+                    self.words.push(entry.key().clone());
+                    entry.insert(metadata);
+                    added_any = true;
+                }
+            }
+        }
+
+        if added_any {
+            self.words.sort_by_key(|w| w.len());
+            self.word_len_starts = Self::create_len_starts(&self.words);
+            self.phonetic_index = OnceLock::new();
+            self.lowercase_index = OnceLock::new();
+            self.folded_index = OnceLock::new();
+
+            if self.bloom_filter.is_some() {
+                self.bloom_filter = Some(self.build_bloom_filter());
+            }
+        }
+    }
+
     /// Appends words to the dictionary.
     /// It is significantly faster to append many words with one call than many
     /// distinct calls to this function.
+    ///
+    /// See [`Self::add_words`].
     pub fn extend_words(
         &mut self,
         words: impl IntoIterator<Item = (impl AsRef<[char]>, WordMetadata)>,
     ) {
-        let pairs: Vec<_> = words
-            .into_iter()
-            .map(|(v, m)| (v.as_ref().to_smallvec(), m))
-            .collect();
-
-        self.words.extend(pairs.iter().map(|(v, _)| v.clone()));
-        self.words.sort_by_key(|w| w.len());
-        self.word_len_starts = Self::create_len_starts(&self.words);
-        self.word_map.extend(pairs);
+        self.add_words(words)
     }
 
     /// Append a single word to the dictionary.
@@ -100,7 +249,7 @@ impl FullDictionary {
     /// If you are appending many words, consider using [`Self::extend_words`]
     /// instead.
     pub fn append_word(&mut self, word: impl AsRef<[char]>, metadata: WordMetadata) {
-        self.extend_words(std::iter::once((word.as_ref(), metadata)))
+        self.add_word(word.as_ref(), metadata)
     }
 
     /// Append a single string to the dictionary.
@@ -111,6 +260,204 @@ impl FullDictionary {
         self.append_word(word.chars().collect::<Vec<_>>(), metadata)
     }
 
+    /// Count the words in `corpus` and add the counts to the frequencies used
+    /// to rank suggestions, so ranking adapts to a team's own vocabulary.
+    ///
+    /// Each text is parsed as plain English, and every occurrence of a known
+    /// word adds one to its frequency. The curated frequencies are per
+    /// million words, so a large corpus will soon outweigh them.
+    ///
+    /// Only the frequency table changes. No words are added, fuzzy matching
+    /// finds the same words as before, and an
+    /// [`FstDictionary`](super::FstDictionary) built from this dictionary
+    /// earlier keeps its own copy of the old frequencies.
+    ///
+    /// The words the dictionary doesn't know are returned with their counts,
+    /// most frequent first, as candidates for [`Self::add_words`].
+    pub fn update_frequencies<'a>(
+        &mut self,
+        corpus: impl Iterator<Item = &'a str>,
+    ) -> Vec<(String, u32)> {
+        let mut known: HashMap<CharString, u32> = HashMap::new();
+        let mut unknown: HashMap<CharString, u32> = HashMap::new();
+
+        for text in corpus {
+            let document = Document::new_plain_english(text, self);
+
+            for word in document.iter_words() {
+                let normalized = seq_to_normalized(document.get_span_content(word.span));
+                let lowercase: CharString = normalized.to_lower();
+
+                let counts = if self.word_map.contains_key(normalized.as_ref()) {
+                    known.entry(normalized.to_smallvec())
+                } else if self.word_map.contains_key(&lowercase) {
+                    known.entry(lowercase)
+                } else {
+                    unknown.entry(normalized.to_smallvec())
+                };
+
+                *counts.or_default() += 1;
+            }
+        }
+
+        for (word, count) in known {
+            let frequency = self.word_frequencies.entry(word).or_default();
+            *frequency = frequency.saturating_add(count);
+        }
+
+        let mut unknown: Vec<(String, u32)> = unknown
+            .into_iter()
+            .map(|(word, count)| (word.to_string(), count))
+            .collect();
+
+        unknown.sort_unstable_by(|(a_word, a_count), (b_word, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_word.cmp(b_word))
+        });
+
+        unknown
+    }
+
+    /// Find the words that were added, removed or given different metadata
+    /// in `other`, treating `self` as the old dictionary.
+    pub fn diff(&self, other: &FullDictionary) -> DictionaryDiff {
+        let mut diff = DictionaryDiff::default();
+
+        let old = self.words_iter().sorted_unstable();
+        let new = other.words_iter().sorted_unstable();
+
+        for pair in old.merge_join_by(new, |a, b| a.cmp(b)) {
+            match pair {
+                EitherOrBoth::Left(word) => diff.removed.push(word.to_smallvec()),
+                EitherOrBoth::Right(word) => diff.added.push(word.to_smallvec()),
+                EitherOrBoth::Both(word, _) => {
+                    let old_meta = self.word_map[word];
+                    let new_meta = other.word_map[word];
+
+                    if old_meta != new_meta {
+                        diff.changed.push((word.to_smallvec(), old_meta, new_meta));
+                    }
+                }
+            }
+        }
+
+        diff
+    }
+
+    /// Estimate how many bytes of memory the dictionary uses, including any
+    /// lookup indices that have been built so far.
+    ///
+    /// Allocator overhead isn't counted, so the true figure is somewhat
+    /// higher.
+    pub fn memory_footprint(&self) -> usize {
+        let phonetic_index = self.phonetic_index.get().map_or(0, |index| {
+            map_footprint(index)
+                + index
+                    .values()
+                    .map(|words| words.capacity() * size_of::<CharString>())
+                    .sum::<usize>()
+                + index
+                    .iter()
+                    .flat_map(|(code, words)| std::iter::once(code).chain(words))
+                    .map(word_heap_size)
+                    .sum::<usize>()
+        });
+        let set_footprint = |set: &OnceLock<HashSet<CharString>>| {
+            set.get().map_or(0, |set| {
+                set.capacity() * (size_of::<CharString>() + 1)
+                    + set.iter().map(word_heap_size).sum::<usize>()
+            })
+        };
+
+        size_of::<Self>()
+            + self.words.capacity() * size_of::<CharString>()
+            + self.words.iter().map(word_heap_size).sum::<usize>()
+            + self.word_len_starts.capacity() * size_of::<usize>()
+            + map_footprint(&self.word_map)
+            + self.word_map.keys().map(word_heap_size).sum::<usize>()
+            + map_footprint(&self.word_frequencies)
+            + self
+                .word_frequencies
+                .keys()
+                .map(word_heap_size)
+                .sum::<usize>()
+            + phonetic_index
+            + set_footprint(&self.lowercase_index)
+            + set_footprint(&self.folded_index)
+            + self
+                .bloom_filter
+                .as_ref()
+                .map_or(0, BloomFilter::memory_footprint)
+    }
+
+    fn phonetic_index(&self) -> &HashMap<CharString, Vec<CharString>> {
+        self.phonetic_index.get_or_init(|| {
+            let mut index: HashMap<CharString, Vec<CharString>> = HashMap::new();
+
+            for word in &self.words {
+                index.entry(metaphone(word)).or_default().push(word.clone());
+            }
+
+            index
+        })
+    }
+
+    fn lowercase_index(&self) -> &HashSet<CharString> {
+        self.lowercase_index
+            .get_or_init(|| self.words.iter().map(|word| word.to_lower()).collect())
+    }
+
+    fn folded_index(&self) -> &HashSet<CharString> {
+        self.folded_index.get_or_init(|| {
+            self.words
+                .iter()
+                .map(|word| fold_diacritics(word).to_smallvec())
+                .collect()
+        })
+    }
+
+    /// Lazily find the words within `max_distance` of `word`.
+    ///
+    /// Yields each word along with its smallest distance (ignoring the case of
+    /// `word`) and the distance to `word` as typed.
+    fn candidates_within(
+        &self,
+        word: &[char],
+        max_distance: u8,
+    ) -> impl Iterator<Item = (&'_ [char], u8, u8)> + Send + '_ {
+        let misspelled_charslice: CharString = seq_to_normalized(word).to_smallvec();
+        let misspelled_charslice_lower = misspelled_charslice.to_lower();
+
+        let shortest_word_len = if misspelled_charslice.len() <= max_distance as usize {
+            1
+        } else {
+            misspelled_charslice.len() - max_distance as usize
+        };
+        let longest_word_len = misspelled_charslice.len() + max_distance as usize;
+
+        // Get candidate words
+        let words_to_search = (shortest_word_len..=longest_word_len)
+            .rev()
+            .flat_map(move |len| self.words_with_len_iter(len));
+
+        // Pre-allocated vectors for the edit-distance calculation
+        // 53 is the length of the longest word.
+        let mut buf_a = Vec::with_capacity(53);
+        let mut buf_b = Vec::with_capacity(53);
+
+        words_to_search.filter_map(move |word| {
+            let dist = edit_distance_min_alloc(&misspelled_charslice, word, &mut buf_a, &mut buf_b);
+            let lowercase_dist =
+                edit_distance_min_alloc(&misspelled_charslice_lower, word, &mut buf_a, &mut buf_b);
+
+            let smaller_dist = dist.min(lowercase_dist);
+            if smaller_dist <= max_distance {
+                Some((word, smaller_dist, dist))
+            } else {
+                None
+            }
+        })
+    }
+
     /// Create a lookup table for finding words of a specific length in a word
     /// list.
     fn create_len_starts(words: &[CharString]) -> Vec<usize> {
@@ -130,6 +477,23 @@ impl FullDictionary {
     }
 }
 
+/// The memory a word stores outside of itself, which is none unless it is too
+/// long to be stored inline.
+pub(super) fn word_heap_size(word: &CharString) -> usize {
+    if word.spilled() {
+        word.capacity() * size_of::<char>()
+    } else {
+        0
+    }
+}
+
+/// The memory used by a map's table, not including anything its entries
+/// point to.
+fn map_footprint<K, V>(map: &HashMap<K, V>) -> usize {
+    // Each bucket also has a byte of control data.
+    map.capacity() * (size_of::<K>() + size_of::<V>() + 1)
+}
+
 impl Default for FullDictionary {
     fn default() -> Self {
         Self::new()
@@ -137,6 +501,23 @@ impl Default for FullDictionary {
 }
 
 impl Dictionary for FullDictionary {
+    fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    fn word_frequency(&self, word: &[char]) -> Option<u32> {
+        let normalized = seq_to_normalized(word);
+
+        self.word_frequencies
+            .get(normalized.as_ref())
+            .or_else(|| self.word_frequencies.get(&normalized.to_lower()))
+            .copied()
+    }
+
     fn get_word_metadata(&self, word: &[char]) -> WordMetadata {
         let normalized = seq_to_normalized(word);
         let lowercase: CharString = normalized.to_lower();
@@ -152,6 +533,10 @@ impl Dictionary for FullDictionary {
         let normalized = seq_to_normalized(word);
         let lowercase: CharString = normalized.to_lower();
 
+        if !self.may_contain_lowercase(&lowercase) {
+            return false;
+        }
+
         self.word_map.contains_key(normalized.as_ref()) || self.word_map.contains_key(&lowercase)
     }
 
@@ -160,6 +545,57 @@ impl Dictionary for FullDictionary {
         self.contains_word(&chars)
     }
 
+    /// Reuses the same normalization buffers for every word, rather than
+    /// allocating for each one.
+    fn contains_words(&self, words: &[&[char]]) -> Vec<bool> {
+        let mut normalized = CharString::new();
+        let mut lowercase = CharString::new();
+
+        words
+            .iter()
+            .map(|word| {
+                normalized.clear();
+                normalized.extend(word.iter().copied().map(char_to_normalized));
+
+                lowercase.clear();
+                lowercase.extend(normalized.iter().flat_map(|c| c.to_lowercase()));
+
+                self.may_contain_lowercase(&lowercase)
+                    && (self.word_map.contains_key(normalized.as_slice())
+                        || self.word_map.contains_key(lowercase.as_slice()))
+            })
+            .collect()
+    }
+
+    fn contains_word_case_sensitive(&self, word: &[char]) -> bool {
+        let normalized = seq_to_normalized(word);
+
+        self.word_map.contains_key(normalized.as_ref())
+    }
+
+    fn contains_word_ignore_case(&self, word: &[char]) -> bool {
+        let normalized = seq_to_normalized(word);
+
+        if self.word_map.contains_key(normalized.as_ref()) {
+            return true;
+        }
+
+        // Only allocate a lowercase copy if we actually need one.
+        if normalized.iter().any(|c| c.is_uppercase()) {
+            self.lowercase_index().contains(&normalized.to_lower())
+        } else {
+            self.lowercase_index().contains(normalized.as_ref())
+        }
+    }
+
+    fn contains_word_fold_diacritics(&self, word: &[char]) -> bool {
+        let normalized = seq_to_normalized(word);
+        let folded = fold_diacritics(&normalized);
+
+        self.folded_index().contains(folded.as_ref())
+            || self.folded_index().contains(&folded.to_lower())
+    }
+
     fn get_word_metadata_str(&self, word: &str) -> WordMetadata {
         let chars: CharString = word.chars().collect();
         self.get_word_metadata(&chars)
@@ -169,54 +605,26 @@ impl Dictionary for FullDictionary {
     /// `Self::word` is assumed to be quite small (n < 100).
     /// `max_distance` relates to an optimization that allows the search
     /// algorithm to prune large portions of the search.
-    fn fuzzy_match(
+    fn fuzzy_match_with_casing_variants(
         &self,
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
-        let misspelled_charslice = seq_to_normalized(word);
-        let misspelled_charslice_lower = misspelled_charslice.to_lower();
-
-        let shortest_word_len = if misspelled_charslice.len() <= max_distance as usize {
-            1
-        } else {
-            misspelled_charslice.len() - max_distance as usize
-        };
-        let longest_word_len = misspelled_charslice.len() + max_distance as usize;
-
-        // Get candidate words
-        let words_to_search = (shortest_word_len..=longest_word_len)
-            .rev()
-            .flat_map(|len| self.words_with_len_iter(len));
-
-        // Pre-allocated vectors for the edit-distance calculation
-        // 53 is the length of the longest word.
-        let mut buf_a = Vec::with_capacity(53);
-        let mut buf_b = Vec::with_capacity(53);
-
-        // Sort by edit-distance
-        words_to_search
-            .filter_map(|word| {
-                let dist =
-                    edit_distance_min_alloc(&misspelled_charslice, word, &mut buf_a, &mut buf_b);
-                let lowercase_dist = edit_distance_min_alloc(
-                    &misspelled_charslice_lower,
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        self.candidates_within(word, max_distance)
+            // Closer matches first. Ties go to words that keep the original
+            // casing, then to the more frequent word.
+            // Only the best `max_results` candidates are kept as we go.
+            .map(|(word, dist, cased_dist)| {
+                (
+                    dist,
+                    cased_dist,
+                    Reverse(self.word_frequency(word).unwrap_or(0)),
                     word,
-                    &mut buf_a,
-                    &mut buf_b,
-                );
-
-                let smaller_dist = dist.min(lowercase_dist);
-                if smaller_dist <= max_distance {
-                    Some((word, smaller_dist))
-                } else {
-                    None
-                }
+                )
             })
-            .sorted_unstable_by_key(|a| a.1)
-            .take(max_results)
-            .map(|(word, edit_distance)| FuzzyMatchResult {
+            .k_smallest(max_results)
+            .map(|(edit_distance, _, _, word)| FuzzyMatchResult {
                 word,
                 edit_distance,
                 metadata: self.get_word_metadata(word),
@@ -229,15 +637,62 @@ impl Dictionary for FullDictionary {
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
         let word: Vec<_> = word.chars().collect();
         self.fuzzy_match(&word, max_distance, max_results)
     }
 
+    fn fuzzy_match_iter(
+        &self,
+        word: &[char],
+        max_distance: u8,
+    ) -> Box<dyn Iterator<Item = (&'_ [char], u8, WordMetadata)> + Send + '_> {
+        Box::new(
+            self.candidates_within(word, max_distance)
+                .map(|(word, edit_distance, _)| {
+                    (word, edit_distance, self.get_word_metadata(word))
+                }),
+        )
+    }
+
+    /// Matches are ordered by frequency, then by edit distance to `word`.
+    fn phonetic_match(&self, word: &[char], max_results: usize) -> Vec<&'_ [char]> {
+        let Some(bucket) = self.phonetic_index().get(&metaphone(word)) else {
+            return Vec::new();
+        };
+
+        let lowercase = word.to_lower();
+
+        bucket
+            .iter()
+            .map(|w| w.as_slice())
+            .sorted_by_cached_key(|w| {
+                (
+                    Reverse(self.word_frequency(w).unwrap_or(0)),
+                    edit_distance(&lowercase, &w.to_lower()),
+                    *w,
+                )
+            })
+            .take(max_results)
+            .collect()
+    }
+
     fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
         Box::new(self.words.iter().map(|v| v.as_slice()))
     }
 
+    fn words_matching<'a>(
+        &'a self,
+        predicate: &'a (dyn Fn(&WordMetadata) -> bool + Sync),
+    ) -> Box<dyn Iterator<Item = &'a [char]> + Send + 'a> {
+        Box::new(
+            self.words
+                .iter()
+                .filter(move |word| predicate(&self.word_map[*word]))
+                .map(|word| word.as_slice()),
+        )
+    }
+
     fn words_with_len_iter(&self, len: usize) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
         if len == 0 || len >= self.word_len_starts.len() {
             return Box::new(std::iter::empty());
@@ -256,10 +711,13 @@ impl Dictionary for FullDictionary {
 
 #[cfg(test)]
 mod tests {
-    use crate::CharString;
+    use crate::{CharString, CharStringExt};
     use itertools::Itertools;
 
-    use crate::{Dictionary, FullDictionary};
+    use std::sync::Arc;
+
+    use crate::spell::{suggest_correct_spelling_with, RankingStrategy, SuggestionOptions};
+    use crate::{Dictionary, DictionaryError, FstDictionary, FullDictionary, WordMetadata};
 
     #[test]
     fn words_with_len_contains_self() {
@@ -270,6 +728,90 @@ mod tests {
         assert!(words_with_same_len.contains(&&word[..]));
     }
 
+    fn dict_of(words: &[(&str, WordMetadata)]) -> FullDictionary {
+        let mut dict = FullDictionary::new();
+
+        for (word, metadata) in words {
+            dict.append_word_str(word, *metadata);
+        }
+
+        dict
+    }
+
+    #[test]
+    fn diff_finds_added_removed_and_changed() {
+        let common = WordMetadata {
+            common: true,
+            ..Default::default()
+        };
+
+        let old = dict_of(&[
+            ("apple", WordMetadata::default()),
+            ("pear", WordMetadata::default()),
+            ("plum", WordMetadata::default()),
+        ]);
+        let new = dict_of(&[
+            ("apple", WordMetadata::default()),
+            ("kiwi", WordMetadata::default()),
+            ("plum", common),
+        ]);
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.added, vec!["kiwi".chars().collect::<CharString>()]);
+        assert_eq!(diff.removed, vec!["pear".chars().collect::<CharString>()]);
+        assert_eq!(
+            diff.changed,
+            vec![(
+                "plum".chars().collect::<CharString>(),
+                WordMetadata::default(),
+                common
+            )]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "1 added, 1 removed, 1 changed\n+ kiwi\n- pear\n~ plum\n"
+        );
+    }
+
+    #[test]
+    fn diff_with_self_is_empty() {
+        let dict = FullDictionary::curated();
+
+        assert!(dict.diff(&dict).is_empty());
+    }
+
+    #[test]
+    fn words_matching_filters_by_metadata() {
+        let common = WordMetadata {
+            common: true,
+            ..Default::default()
+        };
+        let dict = dict_of(&[
+            ("apple", common),
+            ("pear", WordMetadata::default()),
+            ("plum", common),
+        ]);
+
+        let words: Vec<String> = dict
+            .words_matching(&|metadata| metadata.common)
+            .map(|word| word.to_string())
+            .sorted()
+            .collect();
+
+        assert_eq!(words, vec!["apple", "plum"]);
+    }
+
+    #[test]
+    fn curated_nouns_are_nouns() {
+        let dict = FstDictionary::curated();
+
+        assert!(dict
+            .words_matching(&|metadata| metadata.is_noun())
+            .take(100)
+            .all(|word| dict.get_word_metadata(word).is_noun()));
+    }
+
     #[test]
     fn curated_contains_no_duplicates() {
         let dict = FullDictionary::curated();
@@ -304,6 +846,196 @@ mod tests {
         assert!(dict.get_word_metadata_str("Herself").is_pronoun_noun());
     }
 
+    #[test]
+    fn contains_words_agrees_with_contains_word() {
+        let dict = FullDictionary::curated();
+        let words: Vec<CharString> = ["hello", "Hello", "I’m", "hvllo", "", "HTTP", "iphone"]
+            .iter()
+            .map(|w| w.chars().collect())
+            .collect();
+        let words: Vec<&[char]> = words.iter().map(|w| w.as_slice()).collect();
+
+        let expected: Vec<bool> = words.iter().map(|w| dict.contains_word(w)).collect();
+
+        assert_eq!(dict.contains_words(&words), expected);
+        assert_eq!(expected, vec![true, true, true, false, false, true, false]);
+    }
+
+    #[test]
+    fn case_sensitive_lookup_requires_exact_case() {
+        let mut dict = FullDictionary::new();
+        dict.append_word_str("polish", WordMetadata::default());
+
+        assert!(dict.contains_word_str("Polish"));
+        assert!(dict.contains_word_case_sensitive(&['p', 'o', 'l', 'i', 's', 'h']));
+        assert!(!dict.contains_word_case_sensitive(&['P', 'o', 'l', 'i', 's', 'h']));
+    }
+
+    fn contains_ignore_case(dict: &impl Dictionary, word: &str) -> bool {
+        dict.contains_word_ignore_case(&word.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn ignore_case_finds_iphone() {
+        let dict = FullDictionary::curated();
+
+        assert!(contains_ignore_case(&dict, "iPhone"));
+        assert!(contains_ignore_case(&dict, "iphone"));
+        assert!(contains_ignore_case(&dict, "IPHONE"));
+    }
+
+    #[test]
+    fn ignore_case_finds_http() {
+        let dict = FullDictionary::curated();
+
+        assert!(contains_ignore_case(&dict, "HTTP"));
+        assert!(contains_ignore_case(&dict, "http"));
+        assert!(contains_ignore_case(&dict, "Http"));
+    }
+
+    #[test]
+    fn ignore_case_finds_mcdonald() {
+        let dict = FullDictionary::curated();
+
+        assert!(contains_ignore_case(&dict, "McDonald"));
+        assert!(contains_ignore_case(&dict, "mcdonald"));
+        assert!(contains_ignore_case(&dict, "MCDONALD"));
+        assert!(!contains_ignore_case(&dict, "mcdonnald"));
+    }
+
+    #[test]
+    fn ignore_case_sees_added_words() {
+        let mut dict = FullDictionary::new();
+        assert!(!contains_ignore_case(&dict, "kubeconfig"));
+
+        dict.append_word_str("KubeConfig", WordMetadata::default());
+        assert!(contains_ignore_case(&dict, "kubeconfig"));
+    }
+
+    #[test]
+    fn case_sensitive_lookup_normalizes_apostrophes() {
+        let dict = FullDictionary::curated();
+
+        assert!(dict.contains_word_case_sensitive(&['I', '’', 'm']));
+    }
+
+    fn contains_fold_diacritics(dict: &impl Dictionary, word: &str) -> bool {
+        dict.contains_word_fold_diacritics(&word.chars().collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn fold_diacritics_finds_resume() {
+        let dict = FullDictionary::curated();
+
+        assert!(!dict.contains_word_str("résumé"));
+        assert!(contains_fold_diacritics(&dict, "résumé"));
+        assert!(contains_fold_diacritics(&dict, "Résumé"));
+        assert!(contains_fold_diacritics(&dict, "resume"));
+    }
+
+    #[test]
+    fn fold_diacritics_finds_naive() {
+        let dict = FullDictionary::curated();
+
+        assert!(contains_fold_diacritics(&dict, "naïve"));
+        assert!(contains_fold_diacritics(&dict, "naive"));
+    }
+
+    #[test]
+    fn fold_diacritics_matches_accented_entries() {
+        let mut dict = FullDictionary::new();
+        dict.append_word_str("résumé", WordMetadata::default());
+
+        assert!(!dict.contains_word_str("resume"));
+        assert!(contains_fold_diacritics(&dict, "resume"));
+        assert!(contains_fold_diacritics(&dict, "resumé"));
+        assert!(!contains_fold_diacritics(&dict, "resumes"));
+    }
+
+    fn lemma_str(dict: &impl Dictionary, word: &str) -> Option<String> {
+        let word: CharString = word.chars().collect();
+        dict.lemma(&word).map(|l| l.iter().collect())
+    }
+
+    #[test]
+    fn stems_inflections() {
+        let dict = FullDictionary::curated();
+
+        assert_eq!(
+            dict.stem(&['r', 'u', 'n', 'n', 'i', 'n', 'g']),
+            Some(vec!['r', 'u', 'n'])
+        );
+        assert_eq!(dict.stem(&['c', 'a', 't', 's']), Some(vec!['c', 'a', 't']));
+    }
+
+    #[test]
+    fn finds_lemmas() {
+        let dict = FullDictionary::curated();
+
+        assert_eq!(lemma_str(&dict, "running").as_deref(), Some("run"));
+        assert_eq!(lemma_str(&dict, "happier").as_deref(), Some("happy"));
+        assert_eq!(lemma_str(&dict, "cats").as_deref(), Some("cat"));
+        assert_eq!(lemma_str(&dict, "baked").as_deref(), Some("bake"));
+    }
+
+    #[test]
+    fn lemma_respects_part_of_speech() {
+        let dict = FullDictionary::curated();
+
+        // "corn" isn't an adjective, so "corner" isn't its comparative.
+        assert_eq!(lemma_str(&dict, "corner").as_deref(), Some("corner"));
+        assert_eq!(lemma_str(&dict, "run").as_deref(), Some("run"));
+        assert_eq!(lemma_str(&dict, "hvllo"), None);
+    }
+
+    fn decompose_str(dict: &impl Dictionary, word: &str, min_len: usize) -> Option<Vec<String>> {
+        let word: CharString = word.chars().collect();
+
+        dict.decompose_compound_with_min_len(&word, min_len)
+            .map(|parts| parts.iter().map(|p| p.iter().collect()).collect())
+    }
+
+    #[test]
+    fn decomposes_compounds() {
+        let dict = FullDictionary::curated();
+
+        assert_eq!(
+            decompose_str(&dict, "grammarchecker", 3),
+            Some(vec!["grammar".to_string(), "checker".to_string()])
+        );
+        assert_eq!(
+            decompose_str(&dict, "Catdog", 3),
+            Some(vec!["Cat".to_string(), "dog".to_string()])
+        );
+    }
+
+    #[test]
+    fn decomposes_into_fewest_segments() {
+        let dict = FullDictionary::curated();
+
+        // Could also be "note", "book" and "case".
+        let parts = decompose_str(&dict, "notebookcase", 3).unwrap();
+
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts.concat(), "notebookcase");
+    }
+
+    #[test]
+    fn decomposition_respects_min_segment_len() {
+        let dict = FullDictionary::curated();
+
+        assert_eq!(decompose_str(&dict, "catdog", 4), None);
+    }
+
+    #[test]
+    fn does_not_decompose_known_or_garbage_words() {
+        let dict = FullDictionary::curated();
+
+        assert_eq!(decompose_str(&dict, "spellchecker", 3), None);
+        assert_eq!(decompose_str(&dict, "hvllo", 3), None);
+        assert_eq!(decompose_str(&dict, "", 3), None);
+    }
+
     #[test]
     fn discussion_171() {
         let dict = FullDictionary::curated();
@@ -316,6 +1048,70 @@ mod tests {
         assert!(dict.get_word_metadata_str("I'm").common);
     }
 
+    #[test]
+    fn fuzzy_match_iter_agrees_with_fuzzy_match() {
+        let dict = FullDictionary::curated();
+        let word: CharString = "Hvllo".chars().collect();
+
+        let eager = dict.fuzzy_match_with_casing_variants(&word, 2, usize::MAX);
+        let lazy = dict.fuzzy_match_iter(&word, 2).collect_vec();
+
+        assert_eq!(eager.len(), lazy.len());
+
+        for result in eager {
+            assert!(lazy.contains(&(result.word, result.edit_distance, result.metadata)));
+        }
+    }
+
+    #[test]
+    fn fuzzy_match_collapses_casing_variants() {
+        let mut dict = FullDictionary::new();
+        dict.append_word_str("polish", WordMetadata::default());
+        dict.append_word_str("Polish", WordMetadata::default());
+
+        let distinct = dict.fuzzy_match_str("Polsh", 1, 10);
+        assert_eq!(distinct.len(), 1);
+        assert_eq!(distinct[0].word, &['P', 'o', 'l', 'i', 's', 'h']);
+
+        let word: CharString = "Polsh".chars().collect();
+        assert_eq!(dict.fuzzy_match(&word, 1, 1).len(), 1);
+        assert_eq!(dict.fuzzy_match_with_casing_variants(&word, 1, 10).len(), 2);
+    }
+
+    #[test]
+    fn phonetic_match_finds_homophones() {
+        let dict = FullDictionary::curated();
+
+        assert!(dict
+            .phonetic_match_str("fone", 10)
+            .contains(&"phone".to_string()));
+        assert!(dict
+            .phonetic_match_str("rite", 10)
+            .contains(&"right".to_string()));
+        assert!(dict
+            .phonetic_match_str("nite", 10)
+            .contains(&"night".to_string()));
+    }
+
+    #[test]
+    fn phonetic_match_sees_added_words() {
+        let mut dict = FullDictionary::new();
+        dict.append_word_str("phone", WordMetadata::default());
+        assert_eq!(dict.phonetic_match_str("fone", 10), vec!["phone"]);
+
+        dict.append_word_str("foan", WordMetadata::default());
+        assert_eq!(dict.phonetic_match_str("fone", 10).len(), 2);
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_frequent_words() {
+        let dict = FullDictionary::curated();
+
+        let results = dict.fuzzy_match_str("tge", 1, 3);
+
+        assert_eq!(results[0].word, &['t', 'h', 'e']);
+    }
+
     #[test]
     fn fuzzy_result_sorted_by_edit_distance() {
         let dict = FullDictionary::curated();
@@ -329,4 +1125,237 @@ mod tests {
 
         assert!(is_sorted_by_dist)
     }
+
+    #[test]
+    fn added_word_is_immediately_visible() {
+        let mut dict = FullDictionary::new();
+        let word: CharString = "harperesque".chars().collect();
+
+        dict.add_word(&word, WordMetadata::default());
+
+        assert!(dict.contains_word(&word));
+        assert!(dict.words_iter().contains(&word.as_slice()));
+        assert!(dict
+            .words_with_len_iter(word.len())
+            .contains(&word.as_slice()));
+    }
+
+    #[test]
+    fn add_words_does_not_duplicate() {
+        let mut dict = FullDictionary::new();
+
+        dict.add_words(
+            ["foo", "bar", "foo"]
+                .map(|w| (w.chars().collect::<CharString>(), WordMetadata::default())),
+        );
+        dict.add_word(&['b', 'a', 'r'], WordMetadata::default());
+
+        assert_eq!(dict.words_iter().count(), 2);
+        assert!(dict.words.iter().all_unique());
+    }
+
+    #[test]
+    fn bloom_filter_never_hides_words() {
+        let plain = FullDictionary::curated();
+        let filtered = (*plain).clone().with_bloom_filter();
+
+        for word in plain.words_iter() {
+            assert!(filtered.contains_word(word));
+        }
+
+        for word in ["Hello", "HELLO", "Polish", "tezt", "asdfgh"] {
+            assert_eq!(
+                filtered.contains_word_str(word),
+                plain.contains_word_str(word)
+            );
+        }
+    }
+
+    #[test]
+    fn bloom_filter_sees_added_words() {
+        let mut dict = FullDictionary::new().with_bloom_filter();
+
+        dict.append_word_str("kubeconfig", WordMetadata::default());
+
+        assert!(dict.contains_word_str("kubeconfig"));
+        assert!(dict.contains_words(&[&['k', 'u', 'b', 'e', 'c', 'o', 'n', 'f', 'i', 'g']])[0]);
+        assert!(!dict.contains_word_str("kubecfg"));
+    }
+
+    impl FullDictionary {
+        fn phonetic_match_str(&self, word: &str, max_results: usize) -> Vec<String> {
+            let word: CharString = word.chars().collect();
+
+            self.phonetic_match(&word, max_results)
+                .into_iter()
+                .map(|w| w.to_string())
+                .collect()
+        }
+    }
+
+    #[test]
+    fn len_counts_every_word() {
+        let dict = FullDictionary::curated();
+
+        assert_eq!(dict.len(), dict.words_iter().count());
+        assert!(!dict.is_empty());
+    }
+
+    #[test]
+    fn new_dictionary_is_empty() {
+        let mut dict = FullDictionary::new();
+        assert!(dict.is_empty());
+        assert_eq!(dict.len(), 0);
+
+        dict.append_word_str("myocarditisy", WordMetadata::default());
+        assert_eq!(dict.len(), 1);
+    }
+
+    #[test]
+    fn memory_footprint_grows_with_words() {
+        let mut dict = FullDictionary::new();
+        let empty = dict.memory_footprint();
+
+        dict.append_word_str("antidisestablishmentarianism", WordMetadata::default());
+
+        assert!(dict.memory_footprint() > empty);
+    }
+
+    #[test]
+    fn builds_from_word_list() {
+        let dict = FullDictionary::from_word_list([
+            ("zorblax".to_string(), 40),
+            ("quibbit".to_string(), 7),
+        ])
+        .unwrap();
+
+        assert_eq!(dict.len(), 2);
+        assert!(dict.contains_word_str("zorblax"));
+        assert!(dict.contains_word_str("Quibbit"));
+        assert_eq!(
+            dict.word_frequency(&"zorblax".chars().collect_vec()),
+            Some(40)
+        );
+    }
+
+    #[test]
+    fn word_list_keeps_highest_frequency_of_duplicates() {
+        let dict = FullDictionary::from_word_list([
+            ("zorb’s".to_string(), 3),
+            ("zorb's".to_string(), 9),
+            ("zorb's".to_string(), 5),
+        ])
+        .unwrap();
+
+        assert_eq!(dict.len(), 1);
+        assert_eq!(
+            dict.word_frequency(&"zorb’s".chars().collect_vec()),
+            Some(9)
+        );
+    }
+
+    #[test]
+    fn word_list_rejects_empty_words() {
+        let result = FullDictionary::from_word_list([("zorb".to_string(), 1), (String::new(), 2)]);
+
+        assert!(matches!(
+            result,
+            Err(DictionaryError::EmptyWord { index: 1 })
+        ));
+    }
+
+    #[test]
+    fn word_list_builds_fst_dictionary() {
+        let full = FullDictionary::from_word_list([
+            ("zorblax".to_string(), 40),
+            ("zorblix".to_string(), 7),
+        ])
+        .unwrap();
+        let fst = FstDictionary::try_from_full_dictionary(Arc::new(full)).unwrap();
+
+        let matches = fst.fuzzy_match_str("zorblux", 1, 5);
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn corpus_updates_frequencies() {
+        let mut dict = FullDictionary::from_word_list([
+            ("zorblax".to_string(), 40),
+            ("zorblix".to_string(), 7),
+        ])
+        .unwrap();
+
+        let unknown = dict.update_frequencies(
+            [
+                "Zorblix and zorblix.",
+                "The zorblix is a flimwit, a flimwit!",
+            ]
+            .into_iter(),
+        );
+
+        assert_eq!(
+            dict.word_frequency(&"zorblix".chars().collect_vec()),
+            Some(10)
+        );
+        assert_eq!(
+            dict.word_frequency(&"zorblax".chars().collect_vec()),
+            Some(40)
+        );
+        assert_eq!(dict.len(), 2);
+        assert_eq!(
+            unknown,
+            [
+                ("a".to_string(), 2),
+                ("flimwit".to_string(), 2),
+                ("The".to_string(), 1),
+                ("and".to_string(), 1),
+                ("is".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn corpus_changes_suggestion_ranking() {
+        let mut dict = FullDictionary::from_word_list([
+            ("zorblax".to_string(), 2),
+            ("zorblix".to_string(), 1),
+        ])
+        .unwrap();
+
+        let options = SuggestionOptions::new().with_ranking(RankingStrategy::Frequency);
+        let misspelled = "zorblux".chars().collect_vec();
+
+        assert_eq!(
+            suggest_correct_spelling_with(&misspelled, &options, &dict)[0],
+            "zorblax".chars().collect_vec()
+        );
+
+        dict.update_frequencies(std::iter::once("zorblix zorblix zorblix"));
+
+        assert_eq!(
+            suggest_correct_spelling_with(&misspelled, &options, &dict)[0],
+            "zorblix".chars().collect_vec()
+        );
+    }
+
+    #[test]
+    fn contains_hyphenated_checks_each_part() {
+        let dict = FullDictionary::curated();
+        let chars = |s: &str| s.chars().collect_vec();
+
+        assert!(dict.contains_hyphenated(&chars("well-known")));
+        assert!(dict.contains_hyphenated(&chars("state-of-the-art")));
+        assert!(dict.contains_hyphenated(&chars("well‐known")));
+        assert!(!dict.contains_hyphenated(&chars("well-knwn")));
+    }
+
+    #[test]
+    fn contains_hyphenated_rejects_empty_parts() {
+        let dict = FullDictionary::curated();
+        let chars = |s: &str| s.chars().collect_vec();
+
+        assert!(!dict.contains_hyphenated(&chars("well--known")));
+        assert!(!dict.contains_hyphenated(&chars("-known")));
+        assert!(!dict.contains_hyphenated(&chars("known-")));
+    }
 }
@@ -0,0 +1,142 @@
+use ordered_float::OrderedFloat;
+use serde::{Deserialize, Serialize};
+
+/// The cost of substituting a character for one on a neighboring key.
+const ADJACENT_SUBSTITUTION_COST: f32 = 0.5;
+/// The largest distance (in key widths) between two keys that are still
+/// considered neighbors.
+const ADJACENCY_THRESHOLD: f32 = 1.5;
+
+/// A physical keyboard layout, used to weigh typos by how far apart the
+/// involved keys are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Azerty,
+}
+
+impl KeyboardLayout {
+    fn rows(&self) -> [&'static str; 3] {
+        match self {
+            KeyboardLayout::Qwerty => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            KeyboardLayout::Azerty => ["azertyuiop", "qsdfghjklm", "wxcvbn"],
+        }
+    }
+
+    /// Get the physical position of a key, measured in key widths.
+    fn key_position(&self, c: char) -> Option<(f32, f32)> {
+        // How far each row is shifted to the right of the one above.
+        const ROW_OFFSETS: [f32; 3] = [0.0, 0.25, 0.75];
+
+        let c = c.to_lowercase().next()?;
+
+        self.rows()
+            .iter()
+            .zip(ROW_OFFSETS)
+            .enumerate()
+            .find_map(|(row_idx, (row, offset))| {
+                let col_idx = row.chars().position(|k| k == c)?;
+                Some((col_idx as f32 + offset, row_idx as f32))
+            })
+    }
+
+    /// The cost of typing `b` where `a` was intended.
+    fn substitution_cost(&self, a: char, b: char) -> f32 {
+        if a == b || a.to_lowercase().eq(b.to_lowercase()) {
+            return 0.0;
+        }
+
+        let (Some((ax, ay)), Some((bx, by))) = (self.key_position(a), self.key_position(b)) else {
+            return 1.0;
+        };
+
+        if ((ax - bx).powi(2) + (ay - by).powi(2)).sqrt() <= ADJACENCY_THRESHOLD {
+            ADJACENT_SUBSTITUTION_COST
+        } else {
+            1.0
+        }
+    }
+
+    /// Computes an edit distance between two words where substituting
+    /// neighboring keys is cheaper than substituting distant ones.
+    ///
+    /// Insertions, deletions and transpositions all cost one edit.
+    pub fn weighted_edit_distance(&self, source: &[char], target: &[char]) -> f32 {
+        let row_width = source.len();
+
+        let mut before_previous_row = vec![0.0; row_width + 1];
+        let mut previous_row: Vec<f32> = (0..=row_width).map(|i| i as f32).collect();
+        let mut current_row = vec![0.0; row_width + 1];
+
+        for j in 1..=target.len() {
+            current_row[0] = j as f32;
+
+            for i in 1..=row_width {
+                let cost = self.substitution_cost(target[j - 1], source[i - 1]);
+
+                current_row[i] = (previous_row[i] + 1.0)
+                    .min(current_row[i - 1] + 1.0)
+                    .min(previous_row[i - 1] + cost);
+
+                if i > 1
+                    && j > 1
+                    && source[i - 1] == target[j - 2]
+                    && source[i - 2] == target[j - 1]
+                {
+                    current_row[i] = current_row[i].min(before_previous_row[i - 2] + 1.0);
+                }
+            }
+
+            std::mem::swap(&mut before_previous_row, &mut previous_row);
+            std::mem::swap(&mut previous_row, &mut current_row);
+        }
+
+        previous_row[row_width]
+    }
+
+    /// Same as [`Self::weighted_edit_distance`], but totally ordered so it
+    /// can be used as a sort key.
+    pub(super) fn weighted_edit_distance_key(
+        &self,
+        source: &[char],
+        target: &[char],
+    ) -> OrderedFloat<f32> {
+        OrderedFloat(self.weighted_edit_distance(source, target))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyboardLayout;
+
+    fn dist(layout: KeyboardLayout, source: &str, target: &str) -> f32 {
+        let source: Vec<_> = source.chars().collect();
+        let target: Vec<_> = target.chars().collect();
+
+        layout.weighted_edit_distance(&source, &target)
+    }
+
+    #[test]
+    fn neighboring_keys_are_cheaper() {
+        assert!(
+            dist(KeyboardLayout::Qwerty, "cst", "cat") < dist(KeyboardLayout::Qwerty, "cst", "cut")
+        );
+    }
+
+    #[test]
+    fn layouts_differ() {
+        assert!(
+            dist(KeyboardLayout::Azerty, "cqt", "cat") < dist(KeyboardLayout::Azerty, "cst", "cat")
+        );
+        assert!(dist(KeyboardLayout::Qwerty, "cqt", "cat") < 1.0);
+        assert_eq!(dist(KeyboardLayout::Qwerty, "cpt", "cat"), 1.0);
+    }
+
+    #[test]
+    fn matches_unweighted_distance_for_distant_keys() {
+        assert_eq!(dist(KeyboardLayout::Qwerty, "kitten", "sitting"), 3.0);
+        assert_eq!(dist(KeyboardLayout::Qwerty, "teh", "the"), 1.0);
+        assert_eq!(dist(KeyboardLayout::Qwerty, "hello", "hello"), 0.0);
+    }
+}
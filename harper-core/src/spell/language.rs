@@ -0,0 +1,105 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// A natural language that text can be written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+/// Common English words, chosen to rarely appear in French text.
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "the", "of", "and", "to", "in", "is", "it", "that", "for", "was", "with", "as", "be", "are",
+    "this", "have", "not", "but", "by", "from", "they", "you", "he", "she", "at", "or", "an", "we",
+    "his", "her", "which", "will", "would", "there", "their", "what", "i",
+];
+
+/// Common French words, chosen to rarely appear in English text.
+const FRENCH_STOPWORDS: &[&str] = &[
+    "le", "la", "les", "de", "des", "du", "un", "une", "et", "est", "que", "qui", "dans", "pour",
+    "pas", "sur", "au", "aux", "avec", "ce", "cette", "il", "elle", "ils", "nous", "vous", "je",
+    "ne", "se", "sont", "mais", "ou", "par", "plus", "son", "sa", "ses", "l", "d", "qu", "c",
+];
+
+impl Language {
+    /// Guess which language `text` is written in, by counting how many of
+    /// each language's most common words it contains.
+    ///
+    /// Returns [`None`] if the text has no common words from any language,
+    /// or as many from one as another, so callers can fall back to a default.
+    /// Short texts are easily misjudged.
+    pub fn detect(text: &str) -> Option<Self> {
+        let mut english = 0;
+        let mut french = 0;
+
+        // Splitting on apostrophes separates French elisions like "l'eau".
+        for word in text.split(|c: char| !c.is_alphabetic()) {
+            if word.is_empty() {
+                continue;
+            }
+
+            let word = word.to_lowercase();
+
+            if ENGLISH_STOPWORDS.contains(&word.as_str()) {
+                english += 1;
+            }
+
+            if FRENCH_STOPWORDS.contains(&word.as_str()) {
+                french += 1;
+            }
+        }
+
+        match english.cmp(&french) {
+            std::cmp::Ordering::Greater => Some(Self::English),
+            std::cmp::Ordering::Less => Some(Self::French),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::English => write!(f, "English"),
+            Self::French => write!(f, "French"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Language;
+
+    #[test]
+    fn detects_english() {
+        assert_eq!(
+            Language::detect("The quick brown fox jumps over the lazy dog, and that is it."),
+            Some(Language::English)
+        );
+    }
+
+    #[test]
+    fn detects_french() {
+        assert_eq!(
+            Language::detect("Le chat est sur la table, et il ne veut pas descendre."),
+            Some(Language::French)
+        );
+    }
+
+    #[test]
+    fn handles_elisions() {
+        assert_eq!(
+            Language::detect("L'eau qu'il boit."),
+            Some(Language::French)
+        );
+    }
+
+    #[test]
+    fn gives_up_without_evidence() {
+        assert_eq!(Language::detect(""), None);
+        assert_eq!(Language::detect("Xylophone zebra quartz."), None);
+    }
+}
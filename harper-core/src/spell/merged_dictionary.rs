@@ -1,26 +1,65 @@
+use std::cmp::Reverse;
 use std::sync::Arc;
 
+use hashbrown::HashSet;
 use itertools::Itertools;
+use ordered_float::OrderedFloat;
+use smallvec::ToSmallVec;
 
 use super::{dictionary::Dictionary, FuzzyMatchResult};
 use crate::{CharString, WordMetadata};
 
 /// A simple wrapper over [`Dictionary`] that allows
 /// one to merge multiple dictionaries without copying.
+///
+/// Children are consulted in the order they were added.
+/// When two children produce equally good fuzzy matches, the one added first
+/// wins, so a user's custom dictionary can be prioritized by adding it before
+/// the curated one.
+///
+/// Children can also be given a weight with [`Self::add_weighted`], to
+/// favor their words in fuzzy matches regardless of order.
 #[derive(Clone)]
 pub struct MergedDictionary {
     children: Vec<Arc<dyn Dictionary>>,
+    /// The weight of each child, by index.
+    weights: Vec<f32>,
 }
 
 impl MergedDictionary {
     pub fn new() -> Self {
         Self {
             children: Vec::new(),
+            weights: Vec::new(),
         }
     }
 
+    /// Add a dictionary to the end of the list of children, with a weight of
+    /// `1.0`.
     pub fn add_dictionary(&mut self, dictionary: Arc<dyn Dictionary>) {
+        self.add_weighted(dictionary, 1.0);
+    }
+
+    /// Add a dictionary to the end of the list of children, scaling the
+    /// scores of its fuzzy matches by `weight`.
+    ///
+    /// A match is scored by its confidence (`1 - edit_distance / word_len`)
+    /// times the weight of the child it came from, and matches are ranked by
+    /// that score.
+    /// A medical dictionary with a weight of `2.0` can then outrank a closer
+    /// match from the curated dictionary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weight` isn't a positive, finite number.
+    pub fn add_weighted(&mut self, dictionary: Arc<dyn Dictionary>, weight: f32) {
+        assert!(
+            weight.is_finite() && weight > 0.0,
+            "Dictionary weights must be positive and finite, not {weight}."
+        );
+
         self.children.push(dictionary);
+        self.weights.push(weight);
     }
 }
 
@@ -46,15 +85,60 @@ impl Dictionary for MergedDictionary {
         false
     }
 
+    fn contains_word_case_sensitive(&self, word: &[char]) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.contains_word_case_sensitive(word))
+    }
+
+    fn contains_word_ignore_case(&self, word: &[char]) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.contains_word_ignore_case(word))
+    }
+
+    fn contains_word_fold_diacritics(&self, word: &[char]) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.contains_word_fold_diacritics(word))
+    }
+
     fn get_word_metadata(&self, word: &[char]) -> WordMetadata {
         let mut found_metadata = WordMetadata::default();
-        for child in &self.children {
+        for child in self.children.iter().filter(|c| c.contains_word(word)) {
             found_metadata.append(&child.get_word_metadata(word));
         }
 
         found_metadata
     }
 
+    /// The frequency from the first child that knows one.
+    fn word_frequency(&self, word: &[char]) -> Option<u32> {
+        self.children
+            .iter()
+            .find_map(|child| child.word_frequency(word))
+    }
+
+    fn phonetic_match(&self, word: &[char], max_results: usize) -> Vec<&'_ [char]> {
+        // Earlier children are consulted first, so their matches win.
+        self.children
+            .iter()
+            .flat_map(|child| child.phonetic_match(word, max_results))
+            .unique()
+            .take(max_results)
+            .collect()
+    }
+
+    /// Words in more than one child are counted once for each, just as
+    /// [`Self::words_iter`] yields them once for each.
+    fn len(&self) -> usize {
+        self.children.iter().map(|child| child.len()).sum()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.children.iter().all(|child| child.is_empty())
+    }
+
     fn words_iter(&self) -> Box<dyn Iterator<Item = &'_ [char]> + Send + '_> {
         Box::new(self.children.iter().flat_map(|c| c.words_iter()))
     }
@@ -77,31 +161,205 @@ impl Dictionary for MergedDictionary {
         self.get_word_metadata(&chars)
     }
 
-    fn fuzzy_match(
+    fn fuzzy_match_with_casing_variants(
         &self,
         word: &[char],
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        // `sorted_by_key` is stable, so earlier children win ties.
         self.children
             .iter()
-            .flat_map(|d| d.fuzzy_match(word, max_distance, max_results))
-            .sorted_by_key(|r| r.edit_distance)
+            .zip(&self.weights)
+            .flat_map(|(child, weight)| {
+                child
+                    .fuzzy_match_with_casing_variants(word, max_distance, max_results)
+                    .into_iter()
+                    .map(move |r| (r, *weight))
+            })
+            .sorted_by_key(|(r, weight)| {
+                (
+                    Reverse(OrderedFloat(r.confidence(word.len()) * weight)),
+                    r.edit_distance,
+                )
+            })
+            .map(|(r, _)| r)
+            .unique_by(|r| r.word)
             .take(max_results)
             .collect()
     }
 
+    fn fuzzy_match_iter(
+        &self,
+        word: &[char],
+        max_distance: u8,
+    ) -> Box<dyn Iterator<Item = (&'_ [char], u8, WordMetadata)> + Send + '_> {
+        let word: CharString = word.into();
+        let mut seen: HashSet<CharString> = HashSet::new();
+
+        // Earlier children are consulted first, so their matches win.
+        Box::new(
+            self.children
+                .iter()
+                .flat_map(move |child| child.fuzzy_match_iter(&word, max_distance))
+                .filter(move |(word, _, _)| seen.insert(word.to_smallvec())),
+        )
+    }
+
     fn fuzzy_match_str(
         &self,
         word: &str,
         max_distance: u8,
         max_results: usize,
-    ) -> Vec<FuzzyMatchResult> {
-        self.children
-            .iter()
-            .flat_map(|d| d.fuzzy_match_str(word, max_distance, max_results))
-            .sorted_by_key(|r| r.edit_distance)
-            .take(max_results)
-            .collect()
+    ) -> Vec<FuzzyMatchResult<'_>> {
+        let word: CharString = word.chars().collect();
+        self.fuzzy_match(&word, max_distance, max_results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use itertools::Itertools;
+
+    use super::MergedDictionary;
+    use crate::{Dictionary, FstDictionary, FullDictionary, WordMetadata};
+
+    fn merged_with_custom(word: &str, metadata: WordMetadata) -> MergedDictionary {
+        let mut custom = FullDictionary::new();
+        custom.append_word_str(word, metadata);
+
+        let mut merged = MergedDictionary::new();
+        merged.add_dictionary(Arc::new(custom));
+        merged.add_dictionary(FstDictionary::curated());
+        merged
+    }
+
+    #[test]
+    fn contains_words_from_all_children() {
+        let merged = merged_with_custom("myocarditisy", WordMetadata::default());
+
+        assert!(merged.contains_word_str("myocarditisy"));
+        assert!(merged.contains_word_str("hello"));
+        assert!(!merged.contains_word_str("asdfghjkl"));
+    }
+
+    #[test]
+    fn case_sensitive_lookup_checks_all_children() {
+        let merged = merged_with_custom("Harperly", WordMetadata::default());
+
+        assert!(merged.contains_word_case_sensitive(&['H', 'a', 'r', 'p', 'e', 'r', 'l', 'y']));
+        assert!(!merged.contains_word_case_sensitive(&['h', 'a', 'r', 'p', 'e', 'r', 'l', 'y']));
+        assert!(merged.contains_word_case_sensitive(&['h', 'e', 'l', 'l', 'o']));
+    }
+
+    #[test]
+    fn fuzzy_match_iter_has_no_duplicates() {
+        let merged = merged_with_custom("hello", WordMetadata::default());
+        let word: Vec<_> = "hello".chars().collect();
+
+        let results: Vec<_> = merged.fuzzy_match_iter(&word, 1).collect();
+
+        assert!(results.iter().map(|(w, _, _)| w).all_unique());
+        assert!(results.iter().any(|(w, _, _)| *w == word.as_slice()));
+    }
+
+    #[test]
+    fn merges_metadata() {
+        let merged = merged_with_custom(
+            "hello",
+            WordMetadata {
+                swear: Some(true),
+                ..Default::default()
+            },
+        );
+
+        let metadata = merged.get_word_metadata_str("hello");
+        assert!(metadata.is_swear());
+        assert!(metadata.is_noun());
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_earlier_children() {
+        let merged = merged_with_custom("hellp", WordMetadata::default());
+
+        let results = merged.fuzzy_match_str("hellq", 1, 100);
+
+        assert_eq!(results.first().unwrap().word, &['h', 'e', 'l', 'l', 'p']);
+    }
+
+    fn curated_then_weighted(word: &str, weight: f32) -> MergedDictionary {
+        let mut custom = FullDictionary::new();
+        custom.append_word_str(word, WordMetadata::default());
+
+        let mut merged = MergedDictionary::new();
+        merged.add_dictionary(FstDictionary::curated());
+        merged.add_weighted(Arc::new(custom), weight);
+        merged
+    }
+
+    #[test]
+    fn weight_breaks_ties_regardless_of_order() {
+        let merged = curated_then_weighted("hellp", 1.5);
+
+        let results = merged.fuzzy_match_str("hellq", 1, 100);
+
+        assert_eq!(results.first().unwrap().word, &['h', 'e', 'l', 'l', 'p']);
+    }
+
+    #[test]
+    fn heavy_weight_outranks_closer_matches() {
+        let word = ['h', 'e', 'x', 'l', 'o'];
+
+        let merged = curated_then_weighted("hexlo", 3.0);
+        let results = merged.fuzzy_match_str("hellq", 2, 100);
+        assert_eq!(results.first().unwrap().word, &word);
+
+        let merged = curated_then_weighted("hexlo", 1.0);
+        let results = merged.fuzzy_match_str("hellq", 2, 100);
+        assert_ne!(results.first().unwrap().word, &word);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_negative_weights() {
+        curated_then_weighted("hexlo", -1.0);
+    }
+
+    #[test]
+    fn fuzzy_match_has_no_duplicates() {
+        let merged = merged_with_custom("hello", WordMetadata::default());
+
+        let results = merged.fuzzy_match_str("hello", 2, 100);
+
+        assert_eq!(
+            results
+                .iter()
+                .filter(|r| r.word == ['h', 'e', 'l', 'l', 'o'])
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn word_frequency_comes_from_first_child_that_knows() {
+        let merged = merged_with_custom("the", WordMetadata::default());
+        let the: Vec<char> = "the".chars().collect();
+
+        assert_eq!(
+            merged.word_frequency(&the),
+            FstDictionary::curated().word_frequency(&the)
+        );
+        assert!(merged.word_frequency(&the).is_some());
+    }
+
+    #[test]
+    fn len_sums_children() {
+        let merged = merged_with_custom("myocarditisy", WordMetadata::default());
+
+        assert_eq!(merged.len(), FstDictionary::curated().len() + 1);
+        assert!(!merged.is_empty());
+        assert!(MergedDictionary::new().is_empty());
     }
 }
@@ -0,0 +1,172 @@
+use crate::CharString;
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U')
+}
+
+/// Compute the [Metaphone](https://en.wikipedia.org/wiki/Metaphone) code of a
+/// word, which roughly describes how it sounds.
+///
+/// Words that sound alike, such as "phone" and "fone", share the same code.
+/// Non-alphabetic characters are ignored.
+pub fn metaphone(word: &[char]) -> CharString {
+    let mut letters: Vec<char> = word
+        .iter()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    // Initial letter exceptions
+    match letters.as_slice() {
+        ['A', 'E', ..] | ['G', 'N', ..] | ['K', 'N', ..] | ['P', 'N', ..] | ['W', 'R', ..] => {
+            letters.remove(0);
+        }
+        ['X', ..] => letters[0] = 'S',
+        ['W', 'H', ..] => {
+            letters.remove(1);
+        }
+        _ => (),
+    }
+
+    let at = |i: usize| letters.get(i).copied();
+    let mut code = CharString::new();
+
+    for (i, &c) in letters.iter().enumerate() {
+        // Skip duplicate letters, except for `C`
+        if c != 'C' && i > 0 && at(i - 1) == Some(c) {
+            continue;
+        }
+
+        let prev = i.checked_sub(1).and_then(at);
+        let next = at(i + 1);
+        let after_next = at(i + 2);
+
+        match c {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if i == 0 {
+                    code.push(c);
+                }
+            }
+            'B' => {
+                if !(prev == Some('M') && next.is_none()) {
+                    code.push('B');
+                }
+            }
+            'C' => {
+                if next == Some('I') && after_next == Some('A') {
+                    code.push('X');
+                } else if next == Some('H') {
+                    code.push(if prev == Some('S') { 'K' } else { 'X' });
+                } else if matches!(next, Some('I' | 'E' | 'Y')) {
+                    if prev != Some('S') {
+                        code.push('S');
+                    }
+                } else {
+                    code.push('K');
+                }
+            }
+            'D' => {
+                if next == Some('G') && matches!(after_next, Some('E' | 'I' | 'Y')) {
+                    code.push('J');
+                } else {
+                    code.push('T');
+                }
+            }
+            'G' => {
+                if next == Some('H') && !after_next.is_some_and(is_vowel) {
+                    continue;
+                }
+
+                if next == Some('N') && (after_next.is_none() || letters[i + 2..] == ['E', 'D']) {
+                    continue;
+                }
+
+                if matches!(next, Some('I' | 'E' | 'Y')) && prev != Some('G') {
+                    code.push('J');
+                } else {
+                    code.push('K');
+                }
+            }
+            'H' => {
+                let after_digraph = matches!(prev, Some('C' | 'S' | 'P' | 'T' | 'G'));
+                let silent = prev.is_some_and(is_vowel) && !next.is_some_and(is_vowel);
+
+                if !after_digraph && !silent {
+                    code.push('H');
+                }
+            }
+            'K' => {
+                if prev != Some('C') {
+                    code.push('K');
+                }
+            }
+            'P' => code.push(if next == Some('H') { 'F' } else { 'P' }),
+            'Q' => code.push('K'),
+            'S' => {
+                if next == Some('H') || (next == Some('I') && matches!(after_next, Some('O' | 'A')))
+                {
+                    code.push('X');
+                } else {
+                    code.push('S');
+                }
+            }
+            'T' => {
+                if next == Some('I') && matches!(after_next, Some('O' | 'A')) {
+                    code.push('X');
+                } else if next == Some('H') {
+                    code.push('0');
+                } else if !(next == Some('C') && after_next == Some('H')) {
+                    code.push('T');
+                }
+            }
+            'V' => code.push('F'),
+            'W' | 'Y' => {
+                if next.is_some_and(is_vowel) {
+                    code.push(c);
+                }
+            }
+            'X' => code.extend(['K', 'S']),
+            'Z' => code.push('S'),
+            _ => code.push(c),
+        }
+    }
+
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::metaphone;
+    use crate::CharString;
+
+    fn assert_same_code(a: &str, b: &str) {
+        let a: CharString = a.chars().collect();
+        let b: CharString = b.chars().collect();
+
+        assert_eq!(metaphone(&a), metaphone(&b));
+    }
+
+    #[test]
+    fn ph_sounds_like_f() {
+        assert_same_code("fone", "phone");
+    }
+
+    #[test]
+    fn gh_is_silent() {
+        assert_same_code("rite", "right");
+        assert_same_code("nite", "night");
+    }
+
+    #[test]
+    fn leading_kn_is_silent() {
+        assert_same_code("nife", "knife");
+    }
+
+    #[test]
+    fn different_sounds_differ() {
+        let a: CharString = "cat".chars().collect();
+        let b: CharString = "dog".chars().collect();
+
+        assert_ne!(metaphone(&a), metaphone(&b));
+    }
+}
@@ -1,27 +1,84 @@
 use std::borrow::Cow;
+use std::cmp::Reverse;
 
 use itertools::{Itertools, MinMaxResult};
+use ordered_float::OrderedFloat;
 
 use crate::{CharString, CharStringExt, WordMetadata};
 
+pub use self::bigrams::BigramModel;
+pub use self::cached_dictionary::CachedDictionary;
 pub use self::dictionary::Dictionary;
+pub use self::dictionary_diff::DictionaryDiff;
+pub use self::error::DictionaryError;
 pub use self::fst_dictionary::FstDictionary;
 pub use self::full_dictionary::FullDictionary;
+pub use self::keyboard_layout::KeyboardLayout;
+pub use self::language::Language;
 pub use self::merged_dictionary::MergedDictionary;
+pub(crate) use self::metaphone::metaphone;
 
+mod bigrams;
+mod bloom_filter;
+mod cached_dictionary;
+mod diacritics;
 mod dictionary;
+mod dictionary_diff;
+mod error;
 mod fst_dictionary;
 mod full_dictionary;
 mod hunspell;
+mod keyboard_layout;
+mod language;
 mod merged_dictionary;
+mod metaphone;
+mod stemmer;
+mod word_frequencies;
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FuzzyMatchResult<'a> {
     word: &'a [char],
     edit_distance: u8,
     metadata: WordMetadata,
 }
 
+impl FuzzyMatchResult<'_> {
+    /// How confident we are that this is the intended word, given the length
+    /// of the word that was typed.
+    ///
+    /// Computed as `1 - edit_distance / word_len`, clamped to `0.0..=1.0`, so
+    /// an exact match is `1.0`.
+    fn confidence(&self, word_len: usize) -> f32 {
+        confidence(self.edit_distance, word_len)
+    }
+}
+
+fn confidence(edit_distance: u8, word_len: usize) -> f32 {
+    if word_len == 0 {
+        return 0.0;
+    }
+
+    (1.0 - edit_distance as f32 / word_len as f32).clamp(0.0, 1.0)
+}
+
+/// How confident we are that `suggestion` is what was meant by
+/// `misspelled_word`, on the same scale as
+/// [`Dictionary::fuzzy_match_scored`].
+///
+/// Casing is ignored, as in [`suggestion_edit_distance`].
+pub(crate) fn suggestion_confidence(misspelled_word: &[char], suggestion: &[char]) -> f32 {
+    confidence(
+        suggestion_edit_distance(misspelled_word, suggestion),
+        misspelled_word.len(),
+    )
+}
+
+/// The edit distance between `misspelled_word` and `suggestion`, ignoring
+/// casing, so "Teh" and "the" are a single edit apart.
+pub(crate) fn suggestion_edit_distance(misspelled_word: &[char], suggestion: &[char]) -> u8 {
+    edit_distance(&misspelled_word.to_lower(), &suggestion.to_lower())
+}
+
 impl PartialOrd for FuzzyMatchResult<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         self.edit_distance.partial_cmp(&other.edit_distance)
@@ -29,7 +86,7 @@ impl PartialOrd for FuzzyMatchResult<'_> {
 }
 
 /// Order the suggestions to be shown to the user.
-fn order_suggestions(matches: Vec<FuzzyMatchResult>) -> Vec<&[char]> {
+fn order_suggestions(matches: Vec<FuzzyMatchResult<'_>>) -> Vec<&[char]> {
     let mut found: Vec<&FuzzyMatchResult> = Vec::with_capacity(matches.len());
     // Often the longest and the shortest words are the most helpful, so lets push
     // them first.
@@ -59,25 +116,252 @@ fn order_suggestions(matches: Vec<FuzzyMatchResult>) -> Vec<&[char]> {
         found.swap(0, 2);
     }
 
-    // Make commonality relevant
-    found.sort_by_key(|fmr| if fmr.metadata.common { 0 } else { 1 });
+    // Make commonality relevant, without letting it outrank a closer match.
+    found.sort_by_key(|fmr| (fmr.edit_distance, !fmr.metadata.common));
 
     found.into_iter().map(|fmr| fmr.word).collect()
 }
 
+/// How far from the misspelled word suggestions may be.
+///
+/// A plain `u8` converts to [`DistancePolicy::Fixed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistancePolicy {
+    /// The same maximum edit distance, regardless of the word.
+    Fixed(u8),
+    /// Allow more edits for longer words: 1 for words of up to 4 characters,
+    /// 2 for 5 to 8 characters and 3 for anything longer.
+    ByLength,
+}
+
+impl DistancePolicy {
+    /// The maximum edit distance to use for a word of `word_len` characters.
+    pub fn max_distance(&self, word_len: usize) -> u8 {
+        match self {
+            Self::Fixed(distance) => *distance,
+            Self::ByLength => match word_len {
+                0..=4 => 1,
+                5..=8 => 2,
+                _ => 3,
+            },
+        }
+    }
+}
+
+impl From<u8> for DistancePolicy {
+    fn from(distance: u8) -> Self {
+        Self::Fixed(distance)
+    }
+}
+
+/// How [`suggest_correct_spelling_with`] orders its suggestions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RankingStrategy {
+    /// Closest matches first, preferring common words among equally close
+    /// ones.
+    #[default]
+    EditDistance,
+    /// Most frequent words first, according to
+    /// [`Dictionary::word_frequency`], with closer matches breaking ties.
+    Frequency,
+    /// Weigh how close each match is by how frequent it is, so a very common
+    /// word can beat a rare one that is slightly closer.
+    Combined,
+}
+
+/// Controls how [`suggest_correct_spelling_with`] finds and ranks
+/// suggestions.
+///
+/// The defaults suit inline suggestions: a handful of results, ranked by
+/// edit distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuggestionOptions {
+    max_edit_distance: DistancePolicy,
+    max_results: usize,
+    preserve_case: bool,
+    ranking: RankingStrategy,
+}
+
+impl Default for SuggestionOptions {
+    fn default() -> Self {
+        Self {
+            max_edit_distance: DistancePolicy::ByLength,
+            max_results: 10,
+            preserve_case: false,
+            ranking: RankingStrategy::default(),
+        }
+    }
+}
+
+impl SuggestionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How far from the misspelled word suggestions may be.
+    /// Defaults to [`DistancePolicy::ByLength`].
+    pub fn with_max_edit_distance(mut self, max_edit_distance: impl Into<DistancePolicy>) -> Self {
+        self.max_edit_distance = max_edit_distance.into();
+        self
+    }
+
+    /// Return at most this many suggestions.
+    /// Defaults to `10`.
+    pub fn with_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    /// Copy the case of the misspelled word (UPPER, Title or lower) onto each
+    /// suggestion, so "Teh" suggests "The".
+    /// Off by default.
+    pub fn with_preserve_case(mut self, preserve_case: bool) -> Self {
+        self.preserve_case = preserve_case;
+        self
+    }
+
+    /// Defaults to [`RankingStrategy::EditDistance`].
+    pub fn with_ranking(mut self, ranking: RankingStrategy) -> Self {
+        self.ranking = ranking;
+        self
+    }
+}
+
 /// Get the closest matches in the provided [`Dictionary`] and rank them
 /// Implementation is left up to the underlying dictionary.
+///
+/// `max_edit_dist` may be a plain `u8` or a [`DistancePolicy`], and
+/// `dictionary` may be a trait object, so implementations can be chosen at
+/// runtime.
+/// Use [`suggest_correct_spelling_with`] for more control over the results.
 pub fn suggest_correct_spelling<'a>(
     misspelled_word: &[char],
     result_limit: usize,
-    max_edit_dist: u8,
-    dictionary: &'a impl Dictionary,
+    max_edit_dist: impl Into<DistancePolicy>,
+    dictionary: &'a (impl Dictionary + ?Sized),
 ) -> Vec<&'a [char]> {
-    let matches: Vec<FuzzyMatchResult> = dictionary
-        .fuzzy_match(misspelled_word, max_edit_dist, result_limit)
+    let options = SuggestionOptions::new()
+        .with_max_results(result_limit)
+        .with_max_edit_distance(max_edit_dist);
+
+    rank_suggestions(misspelled_word, &options, dictionary)
+}
+
+/// Get matches in the provided [`Dictionary`], found and ranked according to
+/// `options`.
+pub fn suggest_correct_spelling_with(
+    misspelled_word: &[char],
+    options: &SuggestionOptions,
+    dictionary: &(impl Dictionary + ?Sized),
+) -> Vec<Vec<char>> {
+    rank_suggestions(misspelled_word, options, dictionary)
         .into_iter()
-        .collect();
-    order_suggestions(matches)
+        .map(|word| {
+            if options.preserve_case {
+                word.with_case_of(misspelled_word).to_vec()
+            } else {
+                word.to_vec()
+            }
+        })
+        .collect()
+}
+
+fn rank_suggestions<'a>(
+    misspelled_word: &[char],
+    options: &SuggestionOptions,
+    dictionary: &'a (impl Dictionary + ?Sized),
+) -> Vec<&'a [char]> {
+    let max_edit_dist = options
+        .max_edit_distance
+        .max_distance(misspelled_word.len());
+
+    // Other strategies may promote a match from beyond the closest few, so
+    // they need to see all of them.
+    let candidate_limit = match options.ranking {
+        RankingStrategy::EditDistance => options.max_results,
+        RankingStrategy::Frequency | RankingStrategy::Combined => usize::MAX,
+    };
+
+    let matches = dictionary.fuzzy_match(misspelled_word, max_edit_dist, candidate_limit);
+    let mut suggestions = order_suggestions(matches);
+
+    // Both sorts are stable, so ties keep the edit distance order.
+    match options.ranking {
+        RankingStrategy::EditDistance => (),
+        RankingStrategy::Frequency => {
+            suggestions.sort_by_cached_key(|word| Reverse(dictionary.word_frequency(word)));
+        }
+        RankingStrategy::Combined => {
+            let misspelled_lower = misspelled_word.to_lower();
+
+            suggestions.sort_by_cached_key(|word| {
+                let fmr = FuzzyMatchResult {
+                    word,
+                    edit_distance: edit_distance(&misspelled_lower, &word.to_lower()),
+                    metadata: WordMetadata::default(),
+                };
+                let frequency = dictionary.word_frequency(word).unwrap_or(0) as f32;
+
+                let score = fmr.confidence(misspelled_word.len()) * (1.0 + frequency.ln_1p());
+
+                Reverse(OrderedFloat(score))
+            });
+        }
+    }
+
+    suggestions.truncate(options.max_results);
+    suggestions
+}
+
+/// Like [`suggest_correct_spelling`], but rerank the suggestions by how well
+/// they follow `prev_word`, according to `bigrams`.
+///
+/// Each candidate is scored by its edit-distance confidence multiplied by the
+/// probability of the `(prev_word, candidate)` pair, so "frmo" after "came"
+/// prefers "from" over "form".
+/// Candidates with equal scores keep the order [`suggest_correct_spelling`]
+/// gives them.
+pub fn suggest_correct_spelling_context<'a>(
+    prev_word: Option<&[char]>,
+    misspelled_word: &[char],
+    result_limit: usize,
+    max_edit_dist: impl Into<DistancePolicy>,
+    dictionary: &'a (impl Dictionary + ?Sized),
+    bigrams: &BigramModel,
+) -> Vec<&'a [char]> {
+    let mut suggestions =
+        suggest_correct_spelling(misspelled_word, result_limit, max_edit_dist, dictionary);
+
+    if let Some(prev_word) = prev_word {
+        rerank_by_context(prev_word, misspelled_word, &mut suggestions, bigrams);
+    }
+
+    suggestions
+}
+
+/// Stably sort `candidates` for `misspelled_word` by how likely they are to
+/// follow `prev_word`, weighted by how close they are to what was typed.
+pub(crate) fn rerank_by_context<C: AsRef<[char]>>(
+    prev_word: &[char],
+    misspelled_word: &[char],
+    candidates: &mut [C],
+    bigrams: &BigramModel,
+) {
+    let misspelled_lower = misspelled_word.to_lower();
+
+    candidates.sort_by_cached_key(|candidate| {
+        let candidate = candidate.as_ref();
+        let fmr = FuzzyMatchResult {
+            word: candidate,
+            edit_distance: edit_distance(&misspelled_lower, &candidate.to_lower()),
+            metadata: WordMetadata::default(),
+        };
+
+        let score =
+            fmr.confidence(misspelled_word.len()) * bigrams.probability(prev_word, candidate);
+
+        Reverse(OrderedFloat(score))
+    });
 }
 
 /// Convenience function over [`suggest_correct_spelling`] that does conversions
@@ -85,8 +369,8 @@ pub fn suggest_correct_spelling<'a>(
 pub fn suggest_correct_spelling_str(
     misspelled_word: impl Into<String>,
     result_limit: usize,
-    max_edit_dist: u8,
-    dictionary: &impl Dictionary,
+    max_edit_dist: impl Into<DistancePolicy>,
+    dictionary: &(impl Dictionary + ?Sized),
 ) -> Vec<String> {
     let chars: CharString = misspelled_word.into().chars().collect();
     suggest_correct_spelling(&chars, result_limit, max_edit_dist, dictionary)
@@ -165,8 +449,10 @@ mod tests {
     use crate::spell::FuzzyMatchResult;
 
     use super::{
-        edit_distance, order_suggestions, seq_to_normalized, suggest_correct_spelling_str,
-        Dictionary, FstDictionary, FullDictionary,
+        edit_distance, order_suggestions, seq_to_normalized, suggest_correct_spelling,
+        suggest_correct_spelling_context, suggest_correct_spelling_str,
+        suggest_correct_spelling_with, BigramModel, Dictionary, DistancePolicy, FstDictionary,
+        FullDictionary, KeyboardLayout, RankingStrategy, SuggestionOptions,
     };
 
     const RESULT_LIMIT: usize = 60;
@@ -212,6 +498,119 @@ mod tests {
         assert_eq!(results.iter().unique().count(), results.len())
     }
 
+    #[test]
+    fn accepts_dictionary_trait_objects() {
+        let word: Vec<char> = "speling".chars().collect();
+        let concrete = FstDictionary::curated();
+        let dynamic: &dyn Dictionary = &concrete;
+
+        assert_eq!(
+            suggest_correct_spelling(&word, RESULT_LIMIT, MAX_EDIT_DIST, dynamic),
+            suggest_correct_spelling(&word, RESULT_LIMIT, MAX_EDIT_DIST, &concrete)
+        );
+
+        let boxed: Box<dyn Dictionary> = Box::new(FullDictionary::curated());
+
+        assert!(suggest_correct_spelling_str(
+            "speling",
+            RESULT_LIMIT,
+            MAX_EDIT_DIST,
+            boxed.as_ref()
+        )
+        .contains(&"spelling".to_string()));
+    }
+
+    #[test]
+    fn distance_grows_with_length() {
+        assert_eq!(DistancePolicy::ByLength.max_distance(4), 1);
+        assert_eq!(DistancePolicy::ByLength.max_distance(5), 2);
+        assert_eq!(DistancePolicy::ByLength.max_distance(8), 2);
+        assert_eq!(DistancePolicy::ByLength.max_distance(9), 3);
+        assert_eq!(DistancePolicy::Fixed(1).max_distance(20), 1);
+    }
+
+    fn max_suggestion_distance(word: &str, policy: DistancePolicy) -> u8 {
+        let word: Vec<char> = word.chars().collect();
+        let dict = FstDictionary::curated();
+
+        suggest_correct_spelling(&word, RESULT_LIMIT, policy, &dict)
+            .into_iter()
+            .map(|suggestion| edit_distance(&word, suggestion))
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn recieve_gets_distance_two_by_length() {
+        assert_eq!(
+            max_suggestion_distance("recieve", DistancePolicy::ByLength),
+            2
+        );
+        assert_eq!(
+            max_suggestion_distance("recieve", DistancePolicy::Fixed(1)),
+            1
+        );
+    }
+
+    #[test]
+    fn short_words_stay_close_by_length() {
+        assert_eq!(max_suggestion_distance("teh", DistancePolicy::ByLength), 1);
+    }
+
+    fn context_suggestions(prev_word: Option<&str>, word: &str) -> Vec<String> {
+        let prev_word: Option<Vec<char>> = prev_word.map(|w| w.chars().collect());
+        let word: Vec<char> = word.chars().collect();
+        let dict = FstDictionary::curated();
+
+        suggest_correct_spelling_context(
+            prev_word.as_deref(),
+            &word,
+            RESULT_LIMIT,
+            2,
+            &dict,
+            &BigramModel::curated(),
+        )
+        .into_iter()
+        .map(|w| w.iter().collect())
+        .collect()
+    }
+
+    #[test]
+    fn context_prefers_from_after_came() {
+        assert_eq!(context_suggestions(Some("came"), "frmo")[0], "from");
+    }
+
+    #[test]
+    fn context_prefers_form_after_the() {
+        let suggestions = context_suggestions(Some("the"), "frmo");
+
+        assert_eq!(suggestions[0], "form");
+    }
+
+    #[test]
+    fn context_keeps_candidates() {
+        let mut with_context = context_suggestions(Some("came"), "frmo");
+        let mut without_context = context_suggestions(None, "frmo");
+
+        with_context.sort();
+        without_context.sort();
+
+        assert_eq!(with_context, without_context);
+    }
+
+    #[test]
+    fn custom_bigrams_are_used() {
+        let bigrams = BigramModel::parse("fill\tform\t10\n");
+        let word: Vec<char> = "frmo".chars().collect();
+        let prev: Vec<char> = "fill".chars().collect();
+        let dict = FstDictionary::curated();
+
+        let suggestions =
+            suggest_correct_spelling_context(Some(&prev), &word, RESULT_LIMIT, 2, &dict, &bigrams);
+
+        assert_eq!(suggestions[0], ['f', 'o', 'r', 'm']);
+    }
+
     #[test]
     fn zero_edit_distance() {
         let source: Vec<_> = "hello".chars().collect();
@@ -288,6 +687,139 @@ mod tests {
         assert!(common_first);
     }
 
+    /// Ensures that commonality never outranks a closer match
+    #[test]
+    fn closer_words_before_common_words() {
+        let dict = FstDictionary::curated();
+        let common = dict
+            .words_iter()
+            .find(|word| dict.get_word_metadata(word).common)
+            .unwrap();
+        let uncommon = dict
+            .words_iter()
+            .find(|word| !dict.get_word_metadata(word).common)
+            .unwrap();
+
+        let words = vec![
+            FuzzyMatchResult {
+                word: common,
+                edit_distance: 2,
+                metadata: dict.get_word_metadata(common),
+            },
+            FuzzyMatchResult {
+                word: uncommon,
+                edit_distance: 1,
+                metadata: dict.get_word_metadata(uncommon),
+            },
+        ];
+
+        assert_eq!(order_suggestions(words), vec![uncommon, common]);
+    }
+
+    #[test]
+    fn scored_matches_have_confidence() {
+        let dict = FstDictionary::curated();
+        let word: Vec<_> = "hvllo".chars().collect();
+
+        let results = dict.fuzzy_match_scored(&word, 2, 10);
+        let hello = results
+            .iter()
+            .find(|(w, _, _)| *w == ['h', 'e', 'l', 'l', 'o'])
+            .unwrap();
+
+        assert_eq!(hello.1, 0.8);
+        assert!(results.iter().all(|(_, c, _)| (0.0..=1.0).contains(c)));
+    }
+
+    #[test]
+    fn exact_match_is_fully_confident() {
+        let dict = FullDictionary::curated();
+        let word: Vec<_> = "hello".chars().collect();
+
+        assert_eq!(dict.fuzzy_match_scored(&word, 1, 1)[0].1, 1.0);
+    }
+
+    #[test]
+    fn keyboard_aware_matching_prefers_neighboring_keys() {
+        let dict = FstDictionary::curated();
+        let word: Vec<_> = "cst".chars().collect();
+
+        let results = dict.fuzzy_match_keyboard(&word, 1, 3, KeyboardLayout::Qwerty);
+
+        assert_eq!(results[0].word, &['c', 'a', 't']);
+    }
+
+    fn options_suggestions(word: &str, options: SuggestionOptions) -> Vec<String> {
+        let word: Vec<_> = word.chars().collect();
+
+        suggest_correct_spelling_with(&word, &options, &FstDictionary::curated())
+            .into_iter()
+            .map(|s| s.into_iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn options_agree_with_wrapper() {
+        let dict = FstDictionary::curated();
+        let word: Vec<_> = "hvllo".chars().collect();
+
+        let wrapped: Vec<Vec<char>> = suggest_correct_spelling(&word, 10, 2, &dict)
+            .into_iter()
+            .map(|s| s.to_vec())
+            .collect();
+        let options = SuggestionOptions::new()
+            .with_max_results(10)
+            .with_max_edit_distance(2);
+
+        assert_eq!(
+            suggest_correct_spelling_with(&word, &options, &dict),
+            wrapped
+        );
+    }
+
+    #[test]
+    fn options_limit_results() {
+        let options = SuggestionOptions::new().with_max_results(2);
+
+        assert_eq!(options_suggestions("hvllo", options).len(), 2);
+    }
+
+    #[test]
+    fn options_preserve_case() {
+        let options = SuggestionOptions::new().with_preserve_case(true);
+
+        assert_eq!(options_suggestions("Thw", options)[0], "The");
+        assert_eq!(options_suggestions("THW", options)[0], "THE");
+    }
+
+    #[test]
+    fn frequency_ranking_puts_frequent_words_first() {
+        let dict = FstDictionary::curated();
+        let options = SuggestionOptions::new()
+            .with_max_edit_distance(2)
+            .with_ranking(RankingStrategy::Frequency);
+
+        let frequencies: Vec<_> = options_suggestions("thw", options)
+            .iter()
+            .map(|s| dict.word_frequency(&s.chars().collect::<Vec<_>>()))
+            .collect();
+
+        assert!(frequencies.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn every_ranking_finds_the() {
+        for ranking in [
+            RankingStrategy::EditDistance,
+            RankingStrategy::Frequency,
+            RankingStrategy::Combined,
+        ] {
+            let options = SuggestionOptions::new().with_ranking(ranking);
+
+            assert!(options_suggestions("thw", options).contains(&"the".to_string()));
+        }
+    }
+
     #[test]
     fn this_correction() {
         let results = suggest_correct_spelling_str(
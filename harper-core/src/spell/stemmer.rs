@@ -0,0 +1,362 @@
+use crate::PartOfSpeech;
+
+/// Reduce an English word to its stem with the
+/// [Porter stemming algorithm](https://tartarus.org/martin/PorterStemmer/def.txt),
+/// so "running" becomes "run" and "connections" becomes "connect".
+///
+/// Stems are not necessarily words themselves ("happiness" becomes "happi"),
+/// but related words share the same stem.
+/// Returns [`None`] if `word` is empty or contains anything other than ASCII
+/// letters.
+pub fn porter_stem(word: &[char]) -> Option<Vec<char>> {
+    if word.is_empty() || !word.iter().all(char::is_ascii_alphabetic) {
+        return None;
+    }
+
+    let mut word: Vec<char> = word.iter().map(char::to_ascii_lowercase).collect();
+
+    if word.len() <= 2 {
+        return Some(word);
+    }
+
+    step_1a(&mut word);
+    step_1b(&mut word);
+    step_1c(&mut word);
+    replace_longest(&mut word, STEP_2, |stem| measure(stem) > 0);
+    replace_longest(&mut word, STEP_3, |stem| measure(stem) > 0);
+    step_4(&mut word);
+    step_5(&mut word);
+
+    Some(word)
+}
+
+/// Undo a common English inflection, and the part of speech the base form
+/// must have for the inflection to make sense.
+struct Inflection {
+    suffix: &'static str,
+    replacement: &'static str,
+    /// Whether the final consonant of the base form was doubled, like in
+    /// "running".
+    doubled: bool,
+    base: &'static [PartOfSpeech],
+}
+
+const fn inflection(
+    suffix: &'static str,
+    replacement: &'static str,
+    base: &'static [PartOfSpeech],
+) -> Inflection {
+    Inflection {
+        suffix,
+        replacement,
+        doubled: false,
+        base,
+    }
+}
+
+const fn doubled(suffix: &'static str, base: &'static [PartOfSpeech]) -> Inflection {
+    Inflection {
+        suffix,
+        replacement: "",
+        doubled: true,
+        base,
+    }
+}
+
+const NOUN_OR_VERB: &[PartOfSpeech] = &[PartOfSpeech::Noun, PartOfSpeech::Verb];
+const VERB: &[PartOfSpeech] = &[PartOfSpeech::Verb];
+const ADJECTIVE: &[PartOfSpeech] = &[PartOfSpeech::Adjective];
+
+/// Tried in order, so more specific rules come first.
+const INFLECTIONS: &[Inflection] = &[
+    inflection("ies", "y", NOUN_OR_VERB),
+    inflection("es", "", NOUN_OR_VERB),
+    inflection("s", "", NOUN_OR_VERB),
+    inflection("ied", "y", VERB),
+    doubled("ed", VERB),
+    inflection("ed", "", VERB),
+    inflection("ed", "e", VERB),
+    doubled("ing", VERB),
+    inflection("ing", "", VERB),
+    inflection("ing", "e", VERB),
+    inflection("ier", "y", ADJECTIVE),
+    inflection("iest", "y", ADJECTIVE),
+    doubled("er", ADJECTIVE),
+    doubled("est", ADJECTIVE),
+    inflection("er", "", ADJECTIVE),
+    inflection("est", "", ADJECTIVE),
+    inflection("er", "e", ADJECTIVE),
+    inflection("est", "e", ADJECTIVE),
+];
+
+/// Guess the possible base forms of an inflected word, most likely first,
+/// along with the parts of speech each base form would need to have.
+///
+/// Candidates are lowercase and at least two letters long.
+pub(super) fn base_form_candidates(
+    word: &[char],
+) -> impl Iterator<Item = (Vec<char>, &'static [PartOfSpeech])> + '_ {
+    let lowercase: Vec<char> = word.iter().flat_map(|c| c.to_lowercase()).collect();
+
+    INFLECTIONS.iter().filter_map(move |rule| {
+        if !ends_with(&lowercase, rule.suffix) || ends_with(&lowercase, "ss") {
+            return None;
+        }
+
+        let mut base = lowercase.clone();
+        replace_suffix(&mut base, rule.suffix, rule.replacement);
+
+        if rule.doubled {
+            if !ends_with_double_consonant(&base) {
+                return None;
+            }
+            base.pop();
+        }
+
+        (base.len() >= 2).then_some((base, rule.base))
+    })
+}
+
+const STEP_2: &[(&str, &str)] = &[
+    ("ational", "ate"),
+    ("tional", "tion"),
+    ("enci", "ence"),
+    ("anci", "ance"),
+    ("izer", "ize"),
+    ("abli", "able"),
+    ("alli", "al"),
+    ("entli", "ent"),
+    ("eli", "e"),
+    ("ousli", "ous"),
+    ("ization", "ize"),
+    ("ation", "ate"),
+    ("ator", "ate"),
+    ("alism", "al"),
+    ("iveness", "ive"),
+    ("fulness", "ful"),
+    ("ousness", "ous"),
+    ("aliti", "al"),
+    ("iviti", "ive"),
+    ("biliti", "ble"),
+];
+
+const STEP_3: &[(&str, &str)] = &[
+    ("icate", "ic"),
+    ("ative", ""),
+    ("alize", "al"),
+    ("iciti", "ic"),
+    ("ical", "ic"),
+    ("ful", ""),
+    ("ness", ""),
+];
+
+const STEP_4: &[&str] = &[
+    "al", "ance", "ence", "er", "ic", "able", "ible", "ant", "ement", "ment", "ent", "ion", "ou",
+    "ism", "ate", "iti", "ous", "ive", "ize",
+];
+
+fn is_consonant(word: &[char], i: usize) -> bool {
+    match word[i] {
+        'a' | 'e' | 'i' | 'o' | 'u' => false,
+        'y' => i == 0 || !is_consonant(word, i - 1),
+        _ => true,
+    }
+}
+
+/// The number of vowel-consonant sequences in `stem`, called _m_ in the
+/// original paper.
+fn measure(stem: &[char]) -> usize {
+    (1..stem.len())
+        .filter(|&i| is_consonant(stem, i) && !is_consonant(stem, i - 1))
+        .count()
+}
+
+fn has_vowel(stem: &[char]) -> bool {
+    (0..stem.len()).any(|i| !is_consonant(stem, i))
+}
+
+fn ends_with_double_consonant(stem: &[char]) -> bool {
+    let len = stem.len();
+    len >= 2 && stem[len - 1] == stem[len - 2] && is_consonant(stem, len - 1)
+}
+
+/// Whether `stem` ends consonant-vowel-consonant, where the last consonant
+/// isn't "w", "x" or "y" (e.g. "hop", but not "snow").
+fn ends_cvc(stem: &[char]) -> bool {
+    let len = stem.len();
+    len >= 3
+        && is_consonant(stem, len - 3)
+        && !is_consonant(stem, len - 2)
+        && is_consonant(stem, len - 1)
+        && !matches!(stem[len - 1], 'w' | 'x' | 'y')
+}
+
+fn ends_with(word: &[char], suffix: &str) -> bool {
+    word.len() >= suffix.len()
+        && word[word.len() - suffix.len()..]
+            .iter()
+            .copied()
+            .eq(suffix.chars())
+}
+
+fn replace_suffix(word: &mut Vec<char>, suffix: &str, replacement: &str) {
+    word.truncate(word.len() - suffix.len());
+    word.extend(replacement.chars());
+}
+
+/// Find the longest rule whose suffix `word` ends with, and apply it if the
+/// remaining stem passes `condition`.
+/// Only one rule is ever considered.
+fn replace_longest(
+    word: &mut Vec<char>,
+    rules: &[(&str, &str)],
+    condition: impl Fn(&[char]) -> bool,
+) {
+    let Some((suffix, replacement)) = rules
+        .iter()
+        .filter(|(suffix, _)| ends_with(word, suffix))
+        .max_by_key(|(suffix, _)| suffix.len())
+    else {
+        return;
+    };
+
+    if condition(&word[..word.len() - suffix.len()]) {
+        replace_suffix(word, suffix, replacement);
+    }
+}
+
+fn step_1a(word: &mut Vec<char>) {
+    replace_longest(
+        word,
+        &[("sses", "ss"), ("ies", "i"), ("ss", "ss"), ("s", "")],
+        |_| true,
+    );
+}
+
+fn step_1b(word: &mut Vec<char>) {
+    if ends_with(word, "eed") {
+        if measure(&word[..word.len() - 3]) > 0 {
+            word.pop();
+        }
+        return;
+    }
+
+    let Some(suffix) = ["ed", "ing"]
+        .into_iter()
+        .find(|suffix| ends_with(word, suffix) && has_vowel(&word[..word.len() - suffix.len()]))
+    else {
+        return;
+    };
+
+    replace_suffix(word, suffix, "");
+
+    if ends_with(word, "at") || ends_with(word, "bl") || ends_with(word, "iz") {
+        word.push('e');
+    } else if ends_with_double_consonant(word) && !matches!(word.last(), Some('l' | 's' | 'z')) {
+        word.pop();
+    } else if measure(word) == 1 && ends_cvc(word) {
+        word.push('e');
+    }
+}
+
+fn step_1c(word: &mut [char]) {
+    let len = word.len();
+
+    if word[len - 1] == 'y' && has_vowel(&word[..len - 1]) {
+        word[len - 1] = 'i';
+    }
+}
+
+fn step_4(word: &mut Vec<char>) {
+    let Some(suffix) = STEP_4
+        .iter()
+        .filter(|suffix| ends_with(word, suffix))
+        .max_by_key(|suffix| suffix.len())
+    else {
+        return;
+    };
+
+    let stem = &word[..word.len() - suffix.len()];
+
+    let allowed = if *suffix == "ion" {
+        matches!(stem.last(), Some('s' | 't'))
+    } else {
+        true
+    };
+
+    if allowed && measure(stem) > 1 {
+        replace_suffix(word, suffix, "");
+    }
+}
+
+fn step_5(word: &mut Vec<char>) {
+    if word.last() == Some(&'e') {
+        let stem = &word[..word.len() - 1];
+        let m = measure(stem);
+
+        if m > 1 || (m == 1 && !ends_cvc(stem)) {
+            word.pop();
+        }
+    }
+
+    if measure(word) > 1 && ends_with_double_consonant(word) && word.last() == Some(&'l') {
+        word.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{base_form_candidates, porter_stem};
+
+    #[track_caller]
+    fn assert_stem(word: &str, expected: &str) {
+        let word: Vec<char> = word.chars().collect();
+        let stem: String = porter_stem(&word).unwrap().into_iter().collect();
+
+        assert_eq!(stem, expected);
+    }
+
+    #[test]
+    fn strips_inflections() {
+        assert_stem("running", "run");
+        assert_stem("cats", "cat");
+        assert_stem("caresses", "caress");
+        assert_stem("ponies", "poni");
+        assert_stem("hopping", "hop");
+        assert_stem("filing", "file");
+        assert_stem("agreed", "agre");
+    }
+
+    #[test]
+    fn strips_derivations() {
+        assert_stem("relational", "relat");
+        assert_stem("connections", "connect");
+        assert_stem("hopefulness", "hope");
+        assert_stem("generalization", "gener");
+    }
+
+    #[test]
+    fn related_words_share_a_stem() {
+        assert_stem("connect", "connect");
+        assert_stem("connected", "connect");
+        assert_stem("connecting", "connect");
+    }
+
+    #[test]
+    fn is_case_insensitive() {
+        assert_stem("Running", "run");
+    }
+
+    #[test]
+    fn suggests_doubled_base_forms() {
+        let word: Vec<char> = "running".chars().collect();
+
+        assert!(base_form_candidates(&word).any(|(base, _)| base == ['r', 'u', 'n']));
+    }
+
+    #[test]
+    fn rejects_non_letters() {
+        assert_eq!(porter_stem(&[]), None);
+        assert_eq!(porter_stem(&['I', '\'', 'm']), None);
+    }
+}
@@ -0,0 +1,55 @@
+use hashbrown::HashMap;
+
+use crate::CharString;
+
+/// Parse a list of word frequencies, one `word<TAB>count` pair per line.
+///
+/// Blank lines and lines that fail to parse are skipped.
+pub fn parse_word_frequencies(source: &str) -> HashMap<CharString, u32> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let (word, count) = line.split_once('\t')?;
+            Some((word.chars().collect(), count.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+/// Parse the curated word frequency table included in the Harper binary.
+///
+/// Values are approximate occurrences per million words of English text.
+pub fn parse_default_word_frequencies() -> HashMap<CharString, u32> {
+    parse_word_frequencies(include_str!("../../word_frequencies.tsv"))
+}
+
+/// Parse the French word list included in the Harper binary when the
+/// `french` feature is enabled.
+///
+/// Counts are estimated from each word's rank, so they only order words
+/// roughly.
+#[cfg(feature = "french")]
+pub fn parse_french_word_frequencies() -> HashMap<CharString, u32> {
+    parse_word_frequencies(include_str!("../../french_words.tsv"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_default_word_frequencies, parse_word_frequencies};
+    use crate::CharString;
+
+    #[test]
+    fn parses_simple_list() {
+        let freqs = parse_word_frequencies("the\t100\nof\t50\n\nbroken line\n");
+
+        assert_eq!(freqs.len(), 2);
+        assert_eq!(freqs.get(&"of".chars().collect::<CharString>()), Some(&50));
+    }
+
+    #[test]
+    fn the_is_most_frequent() {
+        let freqs = parse_default_word_frequencies();
+        let the: CharString = "the".chars().collect();
+
+        assert!(freqs.values().all(|v| *v <= freqs[&the]));
+    }
+}
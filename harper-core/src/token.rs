@@ -49,7 +49,7 @@ pub enum TokenKind {
     Number(OrderedFloat<f64>, Option<NumberSuffix>),
     /// A sequence of " " spaces.
     Space(usize),
-    /// A sequence of "\n" newlines
+    /// A sequence of "\n" or "\r\n" newlines, holding how many there are.
     Newline(usize),
     EmailAddress,
     Url,
@@ -113,7 +113,7 @@ impl TokenKind {
         }
     }
 
-    fn is_sentence_terminator(&self) -> bool {
+    pub fn is_sentence_terminator(&self) -> bool {
         match self {
             TokenKind::Punctuation(punct) => [
                 Punctuation::Period,
@@ -2,13 +2,32 @@ use is_macro::Is;
 use paste::paste;
 use serde::{Deserialize, Serialize};
 
+/// What is known about a word, as returned by
+/// [`Dictionary::get_word_metadata`](crate::Dictionary::get_word_metadata).
+///
+/// Every field is optional knowledge: a missing part of speech means "not
+/// known to be", not "known not to be".
+/// Words in the curated dictionary have whichever fields their entry in
+/// `dictionary.dict` and `affixes.json` assigns, and no field is guaranteed to
+/// be set for every curated word.
+/// Words added at runtime (for example from a user's dictionary) only carry
+/// the metadata they were added with, which is usually
+/// [`WordMetadata::default()`], so none of the queries below will match them.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Hash)]
 pub struct WordMetadata {
+    /// Set if the word can be used as a noun (including pronouns).
     pub noun: Option<NounData>,
+    /// Set if the word can be used as a verb.
     pub verb: Option<VerbData>,
+    /// Set if the word can be used as an adjective.
     pub adjective: Option<AdjectiveData>,
+    /// Set if the word can be used as an adverb.
+    /// In the curated dictionary, this is currently only known for words
+    /// derived with "-ly".
     pub adverb: Option<AdverbData>,
+    /// Set if the word can be used as a conjunction.
     pub conjunction: Option<ConjunctionData>,
+    /// Whether the word is a swear, if known.
     pub swear: Option<bool>,
     /// Whether the word is an [article](https://dictionary.cambridge.org/dictionary/english/article).
     #[serde(default = "default_false")]
@@ -103,12 +122,40 @@ impl WordMetadata {
 
     generate_metadata_queries!(
         noun has proper, plural, possessive, pronoun.
-        verb has linking.
+        verb has linking, past_participle.
         conjunction has.
         adjective has.
         adverb has
     );
 
+    /// Checks if the word is definitely an article.
+    pub fn is_article(&self) -> bool {
+        self.article
+    }
+
+    /// Checks if the word is definitely a preposition.
+    pub fn is_preposition(&self) -> bool {
+        self.preposition
+    }
+
+    /// Iterate over every part of speech the word is known to have.
+    ///
+    /// Many words have more than one (e.g. "run" is both a noun and a verb),
+    /// and words without metadata have none.
+    pub fn parts_of_speech(&self) -> impl Iterator<Item = PartOfSpeech> {
+        [
+            (self.is_noun(), PartOfSpeech::Noun),
+            (self.is_verb(), PartOfSpeech::Verb),
+            (self.is_adjective(), PartOfSpeech::Adjective),
+            (self.is_adverb(), PartOfSpeech::Adverb),
+            (self.is_conjunction(), PartOfSpeech::Conjunction),
+            (self.is_article(), PartOfSpeech::Article),
+            (self.is_preposition(), PartOfSpeech::Preposition),
+        ]
+        .into_iter()
+        .filter_map(|(is, pos)| is.then_some(pos))
+    }
+
     /// Checks whether a word is _definitely_ a swear.
     pub fn is_swear(&self) -> bool {
         matches!(self.swear, Some(true))
@@ -121,6 +168,20 @@ impl WordMetadata {
     }
 }
 
+/// A part of speech a word can be used as.
+///
+/// See [`WordMetadata::parts_of_speech`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, Is, Hash)]
+pub enum PartOfSpeech {
+    Noun,
+    Verb,
+    Adjective,
+    Adverb,
+    Conjunction,
+    Article,
+    Preposition,
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Is, Hash)]
 pub enum Tense {
     Past,
@@ -131,6 +192,9 @@ pub enum Tense {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, PartialOrd, Eq, Hash)]
 pub struct VerbData {
     pub is_linking: Option<bool>,
+    /// Whether this form of the verb is a past participle, like "eaten" or
+    /// "walked".
+    pub is_past_participle: Option<bool>,
     pub tense: Option<Tense>,
 }
 
@@ -139,6 +203,7 @@ impl VerbData {
     pub fn or(&self, other: &Self) -> Self {
         Self {
             is_linking: self.is_linking.or(other.is_linking),
+            is_past_participle: self.is_past_participle.or(other.is_past_participle),
             tense: self.tense.or(other.tense),
         }
     }
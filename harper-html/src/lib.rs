@@ -1,5 +1,5 @@
 use harper_core::parsers::{self, Parser, PlainEnglish};
-use harper_core::Token;
+use harper_core::{Punctuation, Span, Token, TokenKind};
 use harper_tree_sitter::TreeSitterMasker;
 use tree_sitter::Node;
 
@@ -11,6 +11,50 @@ impl HtmlParser {
     fn node_condition(n: &Node) -> bool {
         n.kind() == "text"
     }
+
+    /// Get the length of the character reference (like `&amp;` or `&#169;`)
+    /// at the start of `source`, if there is one.
+    fn entity_len(source: &[char]) -> Option<usize> {
+        if source.first() != Some(&'&') {
+            return None;
+        }
+
+        let name_len = source[1..]
+            .iter()
+            .take_while(|c| c.is_ascii_alphanumeric() || **c == '#')
+            .count();
+
+        (name_len > 0 && source.get(name_len + 1) == Some(&';')).then_some(name_len + 2)
+    }
+
+    /// Replace the tokens that make up each character reference with a single
+    /// unlintable token, so they aren't spell checked.
+    fn mask_entities(source: &[char], tokens: Vec<Token>) -> Vec<Token> {
+        let mut masked = Vec::with_capacity(tokens.len());
+        let mut entity_end = 0;
+
+        for token in tokens {
+            if token.span.start < entity_end {
+                continue;
+            }
+
+            if token.kind == TokenKind::Punctuation(Punctuation::Ampersand) {
+                if let Some(len) = Self::entity_len(&source[token.span.start..]) {
+                    entity_end = token.span.start + len;
+
+                    masked.push(Token {
+                        span: Span::new_with_len(token.span.start, len),
+                        kind: TokenKind::Unlintable,
+                    });
+                    continue;
+                }
+            }
+
+            masked.push(token);
+        }
+
+        masked
+    }
 }
 
 impl Default for HtmlParser {
@@ -26,6 +70,8 @@ impl Default for HtmlParser {
 
 impl Parser for HtmlParser {
     fn parse(&mut self, source: &[char]) -> Vec<Token> {
-        self.inner.parse(source)
+        let tokens = self.inner.parse(source);
+
+        Self::mask_entities(source, tokens)
     }
 }
@@ -1,7 +1,8 @@
 use harper_core::linting::{LintGroup, LintGroupConfig, Linter};
 use harper_core::{Document, FstDictionary};
+use harper_html::HtmlParser;
 
-/// Creates a unit test checking that the linting of a Markdown document (in
+/// Creates a unit test checking that the linting of an HTML document (in
 /// `tests_sources`) produces the expected number of lints.
 macro_rules! create_test {
     ($filename:ident.html, $correct_expected:expr) => {
@@ -16,7 +17,7 @@ macro_rules! create_test {
                  );
 
                  let dict = FstDictionary::curated();
-                 let document = Document::new_markdown(&source, &dict);
+                 let document = Document::new(&source, &mut HtmlParser::default(), &dict);
 
                  let mut linter = LintGroup::new(
                      LintGroupConfig::default(),
@@ -37,3 +38,5 @@ macro_rules! create_test {
 }
 
 create_test!(run_on.html, 0);
+create_test!(entities.html, 0);
+create_test!(hidden_content.html, 1);
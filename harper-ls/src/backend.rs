@@ -7,8 +7,8 @@ use harper_comments::CommentParser;
 use harper_core::linting::{LintGroup, Linter};
 use harper_core::parsers::{CollapseIdentifiers, IsolateEnglish, Markdown, Parser, PlainEnglish};
 use harper_core::{
-    Dictionary, Document, FstDictionary, FullDictionary, MergedDictionary, Token, TokenKind,
-    WordMetadata,
+    Dictionary, Document, FstDictionary, FullDictionary, MergedDictionary, Normalizer, Token,
+    TokenKind, WordMetadata,
 };
 use harper_html::HtmlParser;
 use serde_json::Value;
@@ -225,7 +225,14 @@ impl Backend {
                     parser = Box::new(IsolateEnglish::new(parser, doc_state.dict.clone()));
                 }
 
-                doc_state.document = Document::new(text, &mut parser, &doc_state.dict);
+                // Ranges from the client are converted against the document's
+                // source, so it has to stay exactly as the client sent it.
+                doc_state.document = Document::new_with_normalizer(
+                    text,
+                    &mut parser,
+                    &doc_state.dict,
+                    &Normalizer::none(),
+                );
             }
         }
 
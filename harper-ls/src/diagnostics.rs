@@ -63,7 +63,7 @@ pub fn lint_to_code_actions<'a>(
             .map(CodeActionOrCommand::CodeAction),
     );
 
-    if lint.lint_kind.is_spelling() {
+    if lint.lint_kind.is_spelling() || lint.lint_kind.is_unknown_word() {
         let orig = lint.span.get_content_string(source);
 
         results.push(CodeActionOrCommand::Command(Command::new(
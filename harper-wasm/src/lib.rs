@@ -6,7 +6,10 @@ use std::sync::Arc;
 use harper_core::language_detection::is_doc_likely_english;
 use harper_core::linting::{LintGroup, LintGroupConfig, Linter as _};
 use harper_core::parsers::{IsolateEnglish, Markdown, PlainEnglish};
-use harper_core::{remove_overlaps, Document, FstDictionary, FullDictionary, Lrc};
+use harper_core::{
+    suggest_correct_spelling_str, Dictionary, DistancePolicy, Document, FstDictionary,
+    FullDictionary, Lrc,
+};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
@@ -119,9 +122,7 @@ impl Linter {
         let document =
             Document::new_from_vec(source.clone(), &mut Markdown, &FullDictionary::curated());
 
-        let mut lints = self.lint_group.lint(&document);
-
-        remove_overlaps(&mut lints);
+        let lints = self.lint_group.lint(&document);
 
         lints
             .into_iter()
@@ -141,6 +142,45 @@ pub fn to_title_case(text: String) -> String {
     harper_core::make_title_case_str(&text, &mut PlainEnglish, &FstDictionary::curated())
 }
 
+/// The most suggestions [`suggest`] will return.
+const MAX_SUGGESTIONS: usize = 10;
+
+/// Lint Markdown text with the default rules, returning the lints as plain
+/// objects.
+///
+/// This is a shortcut for callers that don't need to configure a [`Linter`].
+#[wasm_bindgen]
+pub fn check(text: &str) -> JsValue {
+    // Important for downstream JSON serialization
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+
+    check_lints(text).serialize(&serializer).unwrap()
+}
+
+fn check_lints(text: &str) -> Vec<harper_core::linting::Lint> {
+    let dictionary = FstDictionary::curated();
+    let document = Document::new_markdown(text, &dictionary);
+
+    let mut lints = LintGroup::new(LintGroupConfig::default(), dictionary).lint(&document);
+    document.normalizations().restore_spans(&mut lints);
+
+    lints
+}
+
+/// Get spelling suggestions for a single word, best first.
+///
+/// Returns nothing if the word is already spelled correctly.
+#[wasm_bindgen]
+pub fn suggest(word: &str) -> Vec<String> {
+    let dictionary = FstDictionary::curated();
+
+    if dictionary.contains_word_str(word) {
+        return Vec::new();
+    }
+
+    suggest_correct_spelling_str(word, MAX_SUGGESTIONS, DistancePolicy::ByLength, &dictionary)
+}
+
 #[wasm_bindgen]
 pub fn apply_suggestion(
     text: String,
@@ -0,0 +1,20 @@
+//! Run in a browser or Node with `wasm-pack test --node harper-wasm`.
+
+use harper_wasm::suggest;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn suggests_corrections() {
+    assert!(suggest("tset").contains(&"test".to_string()));
+    assert!(suggest("test").is_empty());
+}
+
+// `JsValue`s can only be inspected inside a JavaScript runtime.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen_test]
+fn checks_text() {
+    let lints: Vec<serde_json::Value> =
+        serde_wasm_bindgen::from_value(harper_wasm::check("This is a tset.")).unwrap();
+
+    assert_eq!(lints.len(), 1);
+}